@@ -244,7 +244,7 @@ impl<T> Fields<T> {
         }
     }
 
-    pub fn iter(&self) -> slice::Iter<T> {
+    pub fn iter(&self) -> slice::Iter<'_, T> {
         self.fields.iter()
     }
 
@@ -403,7 +403,7 @@ impl From<syn::Fields> for Style {
     }
 }
 
-impl<'a> From<&'a syn::Fields> for Style {
+impl From<&syn::Fields> for Style {
     fn from(vd: &syn::Fields) -> Self {
         match *vd {
             syn::Fields::Named(_) => Style::Struct,
@@ -414,6 +414,7 @@ impl<'a> From<&'a syn::Fields> for Style {
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum NestedMeta {
     Meta(syn::Meta),
     Lit(syn::Lit),
@@ -73,6 +73,26 @@ impl GenericParamExt for syn::TypeParam {
     }
 }
 
+impl GenericParamExt for syn::LifetimeParam {
+    type TypeParam = ();
+    type LifetimeParam = syn::LifetimeParam;
+    type ConstParam = ();
+
+    fn as_lifetime_param(&self) -> Option<&Self::LifetimeParam> {
+        Some(self)
+    }
+}
+
+impl GenericParamExt for syn::ConstParam {
+    type TypeParam = ();
+    type LifetimeParam = ();
+    type ConstParam = syn::ConstParam;
+
+    fn as_const_param(&self) -> Option<&Self::ConstParam> {
+        Some(self)
+    }
+}
+
 /// A mirror of `syn::GenericParam` which is generic over all its contents.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GenericParam<T = syn::TypeParam, L = syn::LifetimeParam, C = syn::ConstParam> {
@@ -143,6 +163,14 @@ impl<P, W> Generics<P, W> {
     pub fn type_params(&self) -> TypeParams<'_, P> {
         TypeParams(self.params.iter())
     }
+
+    pub fn lifetimes(&self) -> LifetimeParams<'_, P> {
+        LifetimeParams(self.params.iter())
+    }
+
+    pub fn const_params(&self) -> ConstParams<'_, P> {
+        ConstParams(self.params.iter())
+    }
 }
 
 impl<P: FromGenericParam> FromGenerics for Generics<P> {
@@ -175,13 +203,70 @@ impl<'a, P: GenericParamExt> Iterator for TypeParams<'a, P> {
     }
 }
 
+pub struct LifetimeParams<'a, P: 'a>(Iter<'a, P>);
+
+impl<'a, P: GenericParamExt> Iterator for LifetimeParams<'a, P> {
+    type Item = &'a <P as GenericParamExt>::LifetimeParam;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().find_map(GenericParamExt::as_lifetime_param)
+    }
+}
+
+pub struct ConstParams<'a, P: 'a>(Iter<'a, P>);
+
+impl<'a, P: GenericParamExt> Iterator for ConstParams<'a, P> {
+    type Item = &'a <P as GenericParamExt>::ConstParam;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().find_map(GenericParamExt::as_const_param)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use syn::parse_quote;
 
-    use super::{GenericParam, Generics};
+    use super::{GenericParam, GenericParamExt, Generics};
     use crate::FromGenerics;
 
+    #[test]
+    fn params_by_kind() {
+        let g: syn::Generics = parse_quote!(<'a, T, const N: usize, 'b, U>);
+        let deified: Generics<GenericParam> = FromGenerics::from_generics(&g).unwrap();
+        assert_eq!(
+            deified.type_params().map(|t| &t.ident).collect::<Vec<_>>(),
+            vec!["T", "U"]
+        );
+        assert_eq!(
+            deified
+                .lifetimes()
+                .map(|l| l.lifetime.ident.to_string())
+                .collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+        assert_eq!(
+            deified.const_params().map(|c| &c.ident).collect::<Vec<_>>(),
+            vec!["N"]
+        );
+    }
+
+    #[test]
+    fn syn_params() {
+        let g: syn::Generics = parse_quote!(<'a, T, const N: usize>);
+        let params = g.params.iter().collect::<Vec<_>>();
+        assert!(params[0].as_lifetime_param().is_some());
+        assert!(params[1].as_type_param().is_some());
+        assert!(params[2].as_const_param().is_some());
+
+        let lifetime = params[0].as_lifetime_param().unwrap();
+        assert_eq!(lifetime.as_lifetime_param(), Some(lifetime));
+        assert!(lifetime.as_type_param().is_none());
+        let konst = params[2].as_const_param().unwrap();
+        assert_eq!(konst.as_const_param(), Some(konst));
+        assert!(konst.as_lifetime_param().is_none());
+    }
+
     #[test]
     fn generics() {
         let g: syn::Generics = parse_quote!(<T>);
@@ -0,0 +1,81 @@
+//! Passing container-level settings down to field and variant receivers.
+
+use crate::ast::{Data, Fields};
+use crate::{Error, Result};
+
+/// Receives settings from the container after the container's own attributes are parsed.
+///
+/// Field and variant receivers are parsed before, and independently of, the `FromDeriveInput`
+/// receiver that holds them. Implementing this trait lets a field or variant consult the parsed
+/// container, for example to apply a container-level `rename_all` to fields without an explicit name.
+///
+/// Adding `#[darling(inherit)]` to a `FromDeriveInput` receiver makes the derived impl call
+/// `inherit` on its `data` field once the receiver has been built, before any container-level
+/// `map` or `and_then` runs. While this happens, the `data` field of `parent` is empty.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromDeriveInput)]
+/// #[darling(attributes(my), inherit)]
+/// struct Receiver {
+///     #[darling(default)]
+///     prefix: Option<String>,
+///     data: ast::Data<(), FieldReceiver>,
+/// }
+///
+/// #[derive(FromField)]
+/// #[darling(attributes(my))]
+/// struct FieldReceiver {
+///     ident: Option<syn::Ident>,
+///     #[darling(default)]
+///     prefix: Option<String>,
+/// }
+///
+/// impl Inherit<Receiver> for FieldReceiver {
+///     fn inherit(&mut self, parent: &Receiver) -> darling::Result<()> {
+///         if self.prefix.is_none() {
+///             self.prefix = parent.prefix.clone();
+///         }
+///
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Inherit<P: ?Sized> {
+    /// Applies settings from `parent`. Errors from every child are accumulated.
+    fn inherit(&mut self, parent: &P) -> Result<()>;
+}
+
+impl<P: ?Sized, T: Inherit<P>> Inherit<P> for Vec<T> {
+    fn inherit(&mut self, parent: &P) -> Result<()> {
+        let mut errors = Error::accumulator();
+        for item in self.iter_mut() {
+            errors.handle(item.inherit(parent));
+        }
+
+        errors.finish()
+    }
+}
+
+impl<P: ?Sized, T: Inherit<P>> Inherit<P> for Fields<T> {
+    fn inherit(&mut self, parent: &P) -> Result<()> {
+        self.fields.inherit(parent)
+    }
+}
+
+impl<P: ?Sized, V: Inherit<P>, F: Inherit<P>> Inherit<P> for Data<V, F> {
+    fn inherit(&mut self, parent: &P) -> Result<()> {
+        match *self {
+            Data::Enum(ref mut variants) => variants.inherit(parent),
+            Data::Struct(ref mut fields) => fields.inherit(parent),
+        }
+    }
+}
+
+/// The placeholder type for a variant or field receiver that isn't used, such as the `()`
+/// in `ast::Data<(), FieldReceiver>`, has nothing to inherit.
+impl<P: ?Sized> Inherit<P> for () {
+    fn inherit(&mut self, _parent: &P) -> Result<()> {
+        Ok(())
+    }
+}
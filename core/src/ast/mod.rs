@@ -2,6 +2,8 @@
 
 mod data;
 mod generics;
+mod inherit;
 
 pub use self::data::*;
 pub use self::generics::{GenericParam, GenericParamExt, Generics};
+pub use self::inherit::Inherit;
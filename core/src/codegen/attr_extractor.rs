@@ -25,6 +25,18 @@ pub trait ExtractAttribute {
         quote!(&#input.attrs)
     }
 
+    /// Whether attributes inside `#[cfg_attr(...)]` should be extracted as though they
+    /// were written directly on the input.
+    fn unwrap_cfg_attr(&self) -> bool {
+        false
+    }
+
+    /// Whether the predicates of unwrapped `cfg_attr`s which options were read from should be
+    /// collected in `__cfg_predicates`.
+    fn records_cfg_predicates(&self) -> bool {
+        false
+    }
+
     /// Gets the core from-meta-item loop that should be used on matching attributes.
     fn core_loop(&self) -> TokenStream;
 
@@ -45,6 +57,44 @@ pub trait ExtractAttribute {
         }
 
         let attrs_accessor = self.attrs_accessor();
+        let records_cfg_predicates = self.unwrap_cfg_attr() && self.records_cfg_predicates();
+        let (unwrap_cfg_attr, loop_head) = if self.unwrap_cfg_attr() {
+            let attr_names = self.attr_names().to_strings();
+            let declare_predicates = if records_cfg_predicates {
+                quote!(let mut __cfg_predicates: ::darling::export::Vec<::darling::export::syn::Meta> = vec![];)
+            } else {
+                quote!()
+            };
+            let cfg = if records_cfg_predicates {
+                quote!(__cfg)
+            } else {
+                quote!(_)
+            };
+
+            (
+                quote!(
+                    let __cfg_attrs = ::darling::util::unwrap_cfg_attr(#attrs_accessor, &[#(#attr_names),*]);
+                    #declare_predicates
+                ),
+                quote!(for (#cfg, __attr) in &__cfg_attrs),
+            )
+        } else {
+            (quote!(), quote!(for __attr in #attrs_accessor))
+        };
+
+        // Options read from inside a `cfg_attr` only apply when its predicate holds, which
+        // can't be known here, so the predicate is handed to the receiver.
+        let record_cfg_predicate = if records_cfg_predicates {
+            quote! {
+                if let ::darling::export::Some(__cfg) = __cfg {
+                    if !__cfg_predicates.contains(__cfg) {
+                        __cfg_predicates.push(__cfg.clone());
+                    }
+                }
+            }
+        } else {
+            quote!()
+        };
 
         // The block for parsing attributes whose names have been claimed by the target
         // struct. If no attributes were claimed, this is a pass-through.
@@ -53,6 +103,7 @@ pub trait ExtractAttribute {
             let core_loop = self.core_loop();
             quote!(
                 #(#attr_names)|* => {
+                    #record_cfg_predicate
                     match ::darling::util::parse_attribute_to_meta_list(__attr) {
                         ::darling::export::Ok(__data) => {
                             match ::darling::export::NestedMeta::parse_meta_list(__data.tokens) {
@@ -92,12 +143,13 @@ pub trait ExtractAttribute {
 
         quote!(
             #declarations
-            use ::darling::ToTokens;
             let mut __fwd_attrs: ::darling::export::Vec<::darling::export::syn::Attribute> = vec![];
+            #unwrap_cfg_attr
 
-            for __attr in #attrs_accessor {
-                // Filter attributes based on name
-                match ::darling::export::ToString::to_string(&__attr.path().clone().into_token_stream()).as_str() {
+            #loop_head {
+                // Filter attributes based on name. This uses the same formatting as the names
+                // generated above, so multi-segment paths like `myfw::options` match too.
+                match ::darling::util::path_to_string(__attr.path()).as_str() {
                     #parse_handled
                     #forward_unhandled
                 }
@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
-use syn::{spanned::Spanned, Ident, Path, Type};
+use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
+use syn::{spanned::Spanned, Expr, Ident, Type};
 
 use crate::codegen::{DefaultExpression, PostfixTransform};
+use crate::options::NumericRange;
 use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
 
 /// Properties needed to generate code for a field in all the contexts
@@ -13,7 +14,13 @@ use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
 pub struct Field<'a> {
     /// The name presented to the user of the library. This will appear
     /// in error messages and will be looked when parsing names.
-    pub name_in_attr: Cow<'a, String>,
+    pub name_in_attr: Cow<'a, str>,
+
+    /// Alternate names that are also accepted when parsing. Errors always use `name_in_attr`.
+    pub aliases: Vec<&'a str>,
+
+    /// Alternate names that are accepted, but record a warning in the struct's warnings field.
+    pub deprecated_aliases: Vec<&'a str>,
 
     /// The name presented to the author of the library. This will appear
     /// in the setters or temporary variables which contain the values.
@@ -22,10 +29,43 @@ pub struct Field<'a> {
     /// The type of the field in the input.
     pub ty: &'a Type,
     pub default_expression: Option<DefaultExpression<'a>>,
-    pub with_path: Cow<'a, Path>,
+    /// The function or closure that converts a `syn::Meta` into the field's value.
+    /// Closures are coerced to a function pointer in the generated code, so they
+    /// cannot capture anything from the generated function's scope.
+    pub with_callable: Cow<'a, Expr>,
     pub post_transform: Option<&'a PostfixTransform>,
     pub skip: bool,
     pub multiple: bool,
+    /// The message of the error to emit if the field is absent, instead of the standard
+    /// "missing field" error.
+    pub missing_message: Option<&'a str>,
+    /// If set, this field is parsed from the meta items that no other field claims,
+    /// rather than from an item of its own.
+    pub flatten: bool,
+    /// If set, this field receives every item no other field claims, including literals.
+    pub rest: bool,
+    /// If set, this field isn't read from the input, and instead receives the warnings
+    /// recorded while parsing the other fields.
+    pub warnings: bool,
+    /// Fields, by identifier, which must also be present if this field is.
+    pub requires: &'a [Ident],
+    /// Fields, by identifier, which must not be present if this field is.
+    pub conflicts_with: &'a [Ident],
+    /// If set, the path this field was set with is kept, for use in errors about
+    /// container-level field groups.
+    pub track_path: bool,
+    /// Inclusive bounds which each parsed value must fall within.
+    pub range: Option<&'a NumericRange>,
+    /// The values which the parsed value must be equal to one of, if not empty.
+    pub one_of: &'a [String],
+    /// A regular expression which the parsed value must match.
+    pub matches: Option<&'a str>,
+    /// If set, environment variable placeholders in string values are expanded before parsing.
+    pub expand_env: bool,
+    /// If set, the field's item is passed to `util::FromRawMeta` unparsed.
+    pub raw: bool,
+    /// The lines of the field's doc comments.
+    pub docs: &'a [String],
 }
 
 impl<'a> Field<'a> {
@@ -33,16 +73,74 @@ impl<'a> Field<'a> {
         &self.name_in_attr
     }
 
+    /// Returns `true` if this field is read from an input item bearing its name.
+    pub fn has_match_arm(&self) -> bool {
+        !self.skip && !self.takes_leftovers() && !self.warnings
+    }
+
+    /// Returns `true` if this field declares constraints on the presence of other fields.
+    pub fn has_constraints(&self) -> bool {
+        !self.requires.is_empty() || !self.conflicts_with.is_empty()
+    }
+
+    /// Returns `true` if generated code uses the field type's `FromMeta` impl. Fields with a
+    /// custom `with` parser or which are `raw` only do so for `FromMeta::from_none` when they
+    /// have no default, and fields that aren't read from the input never do.
+    pub fn uses_from_meta(&self) -> bool {
+        // An owned callable is the default `FromMeta::from_meta`, unless the field is raw;
+        // `with` borrows the user's.
+        let from_meta = matches!(self.with_callable, Cow::Owned(_)) && !self.raw;
+        let from_none = !self.multiple && self.default_expression.is_none();
+        !self.skip && !self.warnings && (from_meta || from_none)
+    }
+
+    /// Returns `true` if the path this field was set with is kept for use in errors.
+    pub fn tracks_path(&self) -> bool {
+        self.track_path || self.has_constraints()
+    }
+
+    /// Gets the local variable which holds the path this field was set with, if the field
+    /// tracks it. This lets errors about constraints and groups point at the input.
+    pub(in crate::codegen) fn path_local(&self) -> Ident {
+        format_ident!("__path_{}", self.ident)
+    }
+
+    /// Gets an expression which is `true` if the caller supplied this field.
+    pub(in crate::codegen) fn presence_check(&self) -> TokenStream {
+        let ident = self.ident;
+        if self.multiple {
+            quote!(!#ident.is_empty())
+        } else {
+            quote!(#ident.0)
+        }
+    }
+
+    /// Returns `true` if this field is built from the items other fields don't claim.
+    pub fn takes_leftovers(&self) -> bool {
+        self.flatten || self.rest
+    }
+
     pub fn as_declaration(&'a self) -> Declaration<'a> {
         Declaration(self)
     }
 
-    pub fn as_match(&'a self) -> MatchArm<'a> {
-        MatchArm(self)
+    /// Get the match arm for this field. If `case_insensitive` is set, the arm matches
+    /// the lowercased name, and the caller is responsible for lowercasing the input.
+    pub fn as_match(&'a self, case_insensitive: bool) -> MatchArm<'a> {
+        MatchArm {
+            field: self,
+            case_insensitive,
+        }
     }
 
     pub fn as_initializer(&'a self) -> Initializer<'a> {
-        Initializer(self)
+        Initializer(self, false)
+    }
+
+    /// Get an initializer which falls back to `Default::default()` if the field has no value
+    /// and no default, rather than assuming a missing field was already reported.
+    pub fn as_lossy_initializer(&'a self) -> Initializer<'a> {
+        Initializer(self, true)
     }
 
     pub fn as_presence_check(&'a self) -> CheckMissing<'a> {
@@ -69,25 +167,103 @@ impl<'a> ToTokens for Declaration<'a> {
         let ident = field.ident;
         let ty = field.ty;
 
+        // Warnings are collected in a local with a fixed name, so that the match arms of
+        // other fields can add to it. There is at most one warnings field per struct.
+        if field.warnings {
+            tokens.append_all(quote! {
+                #[allow(unused_mut)]
+                let mut __warnings: #ty = ::darling::export::Default::default();
+            });
+            return;
+        }
+
         tokens.append_all(if field.multiple {
             // This is NOT mutable, as it will be declared mutable only temporarily.
             quote!(let mut #ident: #ty = ::darling::export::Default::default();)
         } else {
             quote!(let mut #ident: (bool, ::darling::export::Option<#ty>) = (false, None);)
         });
+
+        if field.tracks_path() {
+            let path_local = field.path_local();
+            tokens.append_all(quote! {
+                #[allow(unused_mut)]
+                let mut #path_local: ::darling::export::Option<::darling::export::syn::Path> = None;
+            });
+        }
+
+        // Items that no other field claims are collected here during the core loop.
+        // There is at most one flattened or rest field per struct, so the name can't collide.
+        if field.takes_leftovers() {
+            tokens.append_all(quote! {
+                #[allow(unused_mut)]
+                let mut __leftovers: ::darling::export::Vec<::darling::export::NestedMeta> = vec![];
+            });
+        }
     }
 }
 
 /// Represents an individual field in the match.
-pub struct MatchArm<'a>(&'a Field<'a>);
+pub struct MatchArm<'a> {
+    field: &'a Field<'a>,
+    case_insensitive: bool,
+}
 
 impl<'a> ToTokens for MatchArm<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let field: &Field = self.0;
-        if !field.skip {
+        let field: &Field = self.field;
+        if field.has_match_arm() {
             let name_str = &field.name_in_attr;
+
+            // The patterns are what the input is compared against; `name_str` stays as written
+            // so that errors use the canonical name.
+            let to_pattern = |name: &'a str| {
+                if self.case_insensitive {
+                    Cow::Owned(name.to_lowercase())
+                } else {
+                    Cow::Borrowed(name)
+                }
+            };
+            let mut patterns = std::iter::once(&**name_str)
+                .chain(field.aliases.iter().copied())
+                .map(to_pattern)
+                .collect::<Vec<_>>();
+
+            // Deprecated aliases match like any other, but the key that matched is bound so
+            // that a warning can be recorded when it's one of them.
+            let deprecated = field
+                .deprecated_aliases
+                .iter()
+                .map(|alias| (to_pattern(alias), *alias))
+                .collect::<Vec<_>>();
+            let warn_deprecated = deprecated.iter().map(|(pattern, alias)| {
+                quote! {
+                    if __key == #pattern {
+                        __warnings.deprecated_alias(__inner.path(), #alias, #name_str);
+                    }
+                }
+            });
+            let warn_deprecated = quote!(#(#warn_deprecated)*);
+
+            // Remember where the field was first set, so that constraint errors have a span.
+            let record_path = if field.tracks_path() {
+                let path_local = field.path_local();
+                quote! {
+                    if #path_local.is_none() {
+                        #path_local = ::darling::export::Some(__inner.path().clone());
+                    }
+                }
+            } else {
+                quote!()
+            };
+            patterns.extend(deprecated.iter().map(|(pattern, _)| pattern.clone()));
+            let patterns = if deprecated.is_empty() {
+                quote!(#(#patterns)|*)
+            } else {
+                quote!(__key @ (#(#patterns)|*))
+            };
             let ident = field.ident;
-            let with_path = &field.with_path;
+            let with_callable = &field.with_callable;
             let post_transform = field.post_transform.as_ref();
 
             // Errors include the location of the bad input, so we compute that here.
@@ -102,7 +278,18 @@ impl<'a> ToTokens for MatchArm<'a> {
                 quote!(#name_str)
             };
 
-            // Give darling's generated code the span of the `with_path` so that if the target
+            // Closures get an explicit signature so that their parameter types can be inferred.
+            let with_callable = if let Expr::Closure(_) = **with_callable {
+                quote_spanned!(with_callable.span()=>
+                    ::darling::export::identity::<
+                        fn(&::darling::export::syn::Meta) -> ::darling::Result<_>
+                    >(#with_callable)
+                )
+            } else {
+                quote!(#with_callable)
+            };
+
+            // Give darling's generated code the span of the `with_callable` so that if the target
             // type doesn't impl FromMeta, darling's immediate user gets a properly-spanned error.
             //
             // Within the generated code, add the span immediately on extraction failure, so that it's
@@ -110,11 +297,27 @@ impl<'a> ToTokens for MatchArm<'a> {
             // The behavior of `with_span` makes this safe to do; if the child applied an
             // even-more-specific span, our attempt here will not overwrite that and will only cost
             // us one `if` check.
-            let extractor = quote_spanned!(with_path.span()=>#with_path(__inner)#post_transform.map_err(|e| e.with_span(&__inner).at(#location)));
+            let range_check = field.range.map(range_check);
+            let one_of_check = if field.one_of.is_empty() {
+                None
+            } else {
+                Some(one_of_check(field.one_of))
+            };
+            let matches_check = field.matches.map(matches_check);
+            let parse = if field.expand_env {
+                quote_spanned!(with_callable.span()=>
+                    ::darling::util::expand_env(__inner, &mut __warnings).and_then(|__expanded| #with_callable(&__expanded))
+                )
+            } else {
+                quote_spanned!(with_callable.span()=>#with_callable(__inner))
+            };
+            let extractor = quote_spanned!(with_callable.span()=>#parse #range_check #one_of_check #matches_check #post_transform.map_err(|e| e.with_span(&__inner).at(#location)));
 
             tokens.append_all(if field.multiple {
                 quote!(
-                    #name_str => {
+                    #patterns => {
+                        #warn_deprecated
+                        #record_path
                         // Store the index of the name we're assessing in case we need
                         // it for error reporting.
                         let __len = #ident.len();
@@ -125,7 +328,9 @@ impl<'a> ToTokens for MatchArm<'a> {
                 )
             } else {
                 quote!(
-                    #name_str => {
+                    #patterns => {
+                        #warn_deprecated
+                        #record_path
                         if !#ident.0 {
                             #ident = (true, __errors.handle(#extractor));
                         } else {
@@ -138,14 +343,90 @@ impl<'a> ToTokens for MatchArm<'a> {
     }
 }
 
+/// Generate a postfix `and_then` which rejects parsed values outside `range`.
+fn range_check(range: &NumericRange) -> TokenStream {
+    let min = range
+        .min
+        .as_ref()
+        .map(|min| quote!(::darling::export::PartialOrd::lt(&__v, &(#min))));
+    let max = range
+        .max
+        .as_ref()
+        .map(|max| quote!(::darling::export::PartialOrd::gt(&__v, &(#max))));
+    let out_of_range = min.into_iter().chain(max);
+
+    value_check(
+        quote!(#(#out_of_range)||*),
+        "Expected {}, but found {}",
+        &range.describe(),
+    )
+}
+
+/// Generate a postfix `and_then` which rejects parsed values that aren't in `allowed`.
+fn one_of_check(allowed: &[String]) -> TokenStream {
+    let expected = allowed
+        .iter()
+        .map(|value| format!("`{}`", value))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    value_check(
+        quote!(#(!::darling::export::PartialEq::eq(&__v, #allowed))&&*),
+        "Expected one of {}, but found `{}`",
+        &expected,
+    )
+}
+
+/// Generate a postfix `and_then` which rejects parsed values that don't match `pattern`.
+/// The pattern is compiled the first time it's needed, rather than for every value.
+fn matches_check(pattern: &str) -> TokenStream {
+    value_check(
+        quote!({
+            ::darling::export::thread_local! {
+                static __PATTERN: ::darling::export::regex::Regex =
+                    ::darling::export::regex::Regex::new(#pattern)
+                        .expect("Pattern was validated when parsing options");
+            }
+
+            !__PATTERN
+                .with(|__pattern| __pattern.is_match(::darling::export::AsRef::<str>::as_ref(&__v)))
+        }),
+        "Expected a value matching `{}`, but found `{}`",
+        pattern,
+    )
+}
+
+/// Generate a postfix `and_then` which fails when `rejected` is true of the parsed value `__v`.
+/// The error message is `message` formatted with `expected` and then the value; `expected`
+/// comes from the user's options, so it's passed as an argument rather than spliced into the
+/// format string. The error points at the value in the input, rather than at the whole meta
+/// item.
+fn value_check(rejected: TokenStream, message: &str, expected: &str) -> TokenStream {
+    quote! {
+        .and_then(|__v| {
+            if #rejected {
+                let __err = ::darling::Error::custom(format!(#message, #expected, __v));
+                ::darling::export::Err(match __inner {
+                    ::darling::export::syn::Meta::NameValue(__nv) => __err.with_span(&__nv.value),
+                    _ => __err,
+                })
+            } else {
+                ::darling::export::Ok(__v)
+            }
+        })
+    }
+}
+
 /// Wrapper to generate initialization code for a field.
-pub struct Initializer<'a>(&'a Field<'a>);
+pub struct Initializer<'a>(&'a Field<'a>, bool);
 
 impl<'a> ToTokens for Initializer<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let field: &Field = self.0;
         let ident = field.ident;
-        tokens.append_all(if field.multiple {
+        tokens.append_all(if field.warnings {
+            quote!(#ident: __warnings)
+        } else if field.multiple {
             if let Some(ref expr) = field.default_expression {
                 quote_spanned!(expr.span()=> #ident: if !#ident.is_empty() {
                     #ident
@@ -156,11 +437,20 @@ impl<'a> ToTokens for Initializer<'a> {
                 quote!(#ident: #ident)
             }
         } else if let Some(ref expr) = field.default_expression {
-            quote_spanned!(expr.span()=> #ident: if let Some(__val) = #ident.1 {
+            // The default expression carries its own span, so type errors still point at the
+            // user's `default` value; the surrounding `if let` stays at the call site so that
+            // lints on the caller's crate don't mistake it for handwritten code.
+            quote!(#ident: if let Some(__val) = #ident.1 {
                 __val
             } else {
                 #expr
             })
+        } else if self.1 {
+            quote!(#ident: if let Some(__val) = #ident.1 {
+                __val
+            } else {
+                ::darling::export::Default::default()
+            })
         } else {
             quote!(#ident: #ident.1.expect("Uninitialized fields without defaults were already checked"))
         });
@@ -168,14 +458,41 @@ impl<'a> ToTokens for Initializer<'a> {
 }
 
 /// Creates an error if a field has no value and no default.
+///
+/// Flattened and rest fields are parsed here instead, since their value can only be built
+/// once every item in the input has been seen.
 pub struct CheckMissing<'a>(&'a Field<'a>);
 
 impl<'a> ToTokens for CheckMissing<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        if !self.0.multiple && self.0.default_expression.is_none() {
+        if self.0.takes_leftovers() {
+            let ident = self.0.ident;
+            let ty = self.0.ty;
+            let post_transform = self.0.post_transform.as_ref();
+
+            let from_list_call = quote_spanned!(ty.span()=>
+                <#ty as ::darling::FromMeta>::from_list(&__leftovers)
+            );
+
+            let parse = quote! {
+                #ident = (true, __errors.handle(#from_list_call #post_transform));
+            };
+
+            // With a default, an input that doesn't mention any of the flattened
+            // field's items falls back to the default rather than to `from_list(&[])`.
+            tokens.append_all(if self.0.default_expression.is_some() {
+                quote!(if !__leftovers.is_empty() { #parse })
+            } else {
+                parse
+            });
+        } else if !self.0.multiple && !self.0.warnings && self.0.default_expression.is_none() {
             let ident = self.0.ident;
             let ty = self.0.ty;
             let name_in_attr = &self.0.name_in_attr;
+            let missing_error = match self.0.missing_message {
+                Some(message) => quote!(::darling::Error::custom(#message)),
+                None => quote!(::darling::Error::missing_field(#name_in_attr)),
+            };
 
             // If `ty` does not impl FromMeta, the compiler error should point
             // at the offending type rather than at the derive-macro call site.
@@ -189,7 +506,7 @@ impl<'a> ToTokens for CheckMissing<'a> {
                             #ident.1 = ::darling::export::Some(__type_fallback);
                         }
                         ::darling::export::None => {
-                            __errors.push(::darling::Error::missing_field(#name_in_attr))
+                            __errors.push(#missing_error)
                         }
                     }
                 }
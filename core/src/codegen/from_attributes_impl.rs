@@ -36,6 +36,8 @@ impl ToTokens for FromAttributesImpl<'_> {
             }
         }
 
+        let passed_docs = self.base.docs_initializer(self.attrs_accessor());
+        let passed_cfg_predicates = self.base.cfg_predicates_initializer();
         let inits = self.base.initializers();
         let default = self.base.fallback_decl();
 
@@ -59,6 +61,8 @@ impl ToTokens for FromAttributesImpl<'_> {
                     #default
 
                     ::darling::export::Ok(#ty_ident {
+                        #passed_docs
+                        #passed_cfg_predicates
                         #inits
                     }) #post_transform
                 }
@@ -81,6 +85,14 @@ impl<'a> ExtractAttribute for FromAttributesImpl<'a> {
         None
     }
 
+    fn unwrap_cfg_attr(&self) -> bool {
+        self.base.unwrap_cfg_attr
+    }
+
+    fn records_cfg_predicates(&self) -> bool {
+        self.base.cfg_predicates.is_some()
+    }
+
     fn param_name(&self) -> TokenStream {
         quote!(__di)
     }
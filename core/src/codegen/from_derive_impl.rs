@@ -20,6 +20,10 @@ pub struct FromDeriveInputImpl<'a> {
     pub forward_attrs: Option<&'a ForwardAttrs>,
     pub from_ident: bool,
     pub supports: Option<&'a DeriveInputShapeSet>,
+    /// Whether to call `ast::Inherit::inherit` on `data` once the receiver is built.
+    pub inherit: bool,
+    /// Whether to generate `from_derive_input_lossy`, which builds the receiver despite errors.
+    pub lossy: bool,
 }
 
 impl<'a> ToTokens for FromDeriveInputImpl<'a> {
@@ -55,6 +59,8 @@ impl<'a> ToTokens for FromDeriveInputImpl<'a> {
             .as_ref()
             .map(|i| quote!(#i: ::darling::FromGenerics::from_generics(&#input.generics)?,));
         let passed_attrs = self.attrs.as_ref().map(|i| quote!(#i: __fwd_attrs,));
+        let passed_docs = self.base.docs_initializer(self.attrs_accessor());
+        let passed_cfg_predicates = self.base.cfg_predicates_initializer();
         let passed_body = self
             .data
             .as_ref()
@@ -67,7 +73,6 @@ impl<'a> ToTokens for FromDeriveInputImpl<'a> {
             }
         });
 
-        let inits = self.base.initializers();
         let default = if self.from_ident {
             quote!(let __default: Self = ::darling::export::From::from(#input.ident.clone());)
         } else {
@@ -80,6 +85,69 @@ impl<'a> ToTokens for FromDeriveInputImpl<'a> {
         let require_fields = self.base.require_fields();
         let check_errors = self.base.check_errors();
 
+        let result = |inits: TokenStream| {
+            let construct = quote! {
+                #ty_ident {
+                    #passed_ident
+                    #passed_generics
+                    #passed_vis
+                    #passed_attrs
+                    #passed_docs
+                    #passed_cfg_predicates
+                    #passed_body
+                    #inits
+                }
+            };
+
+            // The body is moved out while it inherits, so that the rest of the receiver can be
+            // borrowed as the parent.
+            match self.data {
+                Some(data) if self.inherit => quote! {
+                    let mut __receiver = #construct;
+                    let mut __data = ::darling::export::mem::replace(
+                        &mut __receiver.#data,
+                        ::darling::ast::Data::Struct(::darling::ast::Fields::from(::darling::ast::Style::Unit)),
+                    );
+                    let __inherited = ::darling::ast::Inherit::inherit(&mut __data, &__receiver);
+                    __receiver.#data = __data;
+                    __inherited.map(|_| __receiver)
+                },
+                _ => quote!(::darling::export::Ok(#construct)),
+            }
+        };
+
+        // The lossy variant keeps going after errors, filling fields which have no value with
+        // their defaults. Errors from the magic fields, `map`, `and_then`, and validation still
+        // prevent the receiver from being built, so those steps run in a closure whose error
+        // joins the others.
+        let lossy = if self.lossy {
+            let lossy_result = result(self.base.lossy_initializers());
+            Some(quote! {
+                fn from_derive_input_lossy(
+                    #input: &::darling::export::syn::DeriveInput,
+                ) -> (::darling::export::Option<Self>, ::darling::export::Option<::darling::Error>) {
+                    #declare_errors
+
+                    #grab_attrs
+
+                    #supports
+
+                    #require_fields
+
+                    #default
+
+                    let __value = __errors.handle((move || -> ::darling::Result<Self> {
+                        #lossy_result #post_transform
+                    })());
+                    (__value, __errors.finish().err())
+                }
+            })
+        } else {
+            None
+        };
+
+        let result = result(self.base.initializers());
+
         self.wrap(
             quote! {
                 fn from_derive_input(#input: &::darling::export::syn::DeriveInput) -> ::darling::Result<Self> {
@@ -95,15 +163,10 @@ impl<'a> ToTokens for FromDeriveInputImpl<'a> {
 
                     #default
 
-                    ::darling::export::Ok(#ty_ident {
-                        #passed_ident
-                        #passed_generics
-                        #passed_vis
-                        #passed_attrs
-                        #passed_body
-                        #inits
-                    }) #post_transform
+                    #result #post_transform
                 }
+
+                #lossy
             },
             tokens,
         );
@@ -119,6 +182,14 @@ impl<'a> ExtractAttribute for FromDeriveInputImpl<'a> {
         self.forward_attrs
     }
 
+    fn unwrap_cfg_attr(&self) -> bool {
+        self.base.unwrap_cfg_attr
+    }
+
+    fn records_cfg_predicates(&self) -> bool {
+        self.base.cfg_predicates.is_some()
+    }
+
     fn param_name(&self) -> TokenStream {
         quote!(__di)
     }
@@ -44,6 +44,8 @@ impl<'a> ToTokens for FromFieldImpl<'a> {
         let passed_vis = self.vis.as_ref().map(|i| quote!(#i: #input.vis.clone(),));
         let passed_ty = self.ty.as_ref().map(|i| quote!(#i: #input.ty.clone(),));
         let passed_attrs = self.attrs.as_ref().map(|i| quote!(#i: __fwd_attrs,));
+        let passed_docs = self.base.docs_initializer(self.attrs_accessor());
+        let passed_cfg_predicates = self.base.cfg_predicates_initializer();
 
         // Determine which attributes to forward (if any).
         let grab_attrs = self.extractor();
@@ -67,6 +69,8 @@ impl<'a> ToTokens for FromFieldImpl<'a> {
                         #passed_ty
                         #passed_vis
                         #passed_attrs
+                        #passed_docs
+                        #passed_cfg_predicates
                         #initializers
                     }) #post_transform
 
@@ -86,6 +90,14 @@ impl<'a> ExtractAttribute for FromFieldImpl<'a> {
         self.forward_attrs
     }
 
+    fn unwrap_cfg_attr(&self) -> bool {
+        self.base.unwrap_cfg_attr
+    }
+
+    fn records_cfg_predicates(&self) -> bool {
+        self.base.cfg_predicates.is_some()
+    }
+
     fn param_name(&self) -> TokenStream {
         quote!(__field)
     }
@@ -11,6 +11,7 @@ pub struct FromMetaImpl<'a> {
 impl<'a> ToTokens for FromMetaImpl<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let base = &self.base;
+        let post_transform = base.post_transform_call();
 
         let impl_block = match base.data {
             // Unit structs allow empty bodies only.
@@ -18,7 +19,7 @@ impl<'a> ToTokens for FromMetaImpl<'a> {
                 let ty_ident = base.ident;
                 quote!(
                     fn from_word() -> ::darling::Result<Self> {
-                        ::darling::export::Ok(#ty_ident)
+                        ::darling::export::Ok(#ty_ident) #post_transform
                     }
                 )
             }
@@ -35,6 +36,7 @@ impl<'a> ToTokens for FromMetaImpl<'a> {
                         ::darling::FromMeta::from_meta(__item)
                             .map_err(|e| e.with_span(&__item))
                             .map(#ty_ident)
+                            #post_transform
                     }
                 )
             }
@@ -52,7 +54,6 @@ impl<'a> ToTokens for FromMetaImpl<'a> {
                 let decls = base.local_declarations();
                 let core_loop = base.core_loop();
                 let default = base.fallback_decl();
-                let post_transform = base.post_transform_call();
 
                 quote!(
                     fn from_list(__items: &[::darling::export::NestedMeta]) -> ::darling::Result<Self> {
@@ -79,14 +80,31 @@ impl<'a> ToTokens for FromMetaImpl<'a> {
                 let unit_arms = variants.iter().map(Variant::as_unit_match_arm);
                 let struct_arms = variants.iter().map(Variant::as_data_match_arm);
 
-                let unknown_variant_err = if !variants.is_empty() {
-                    let names = variants.iter().map(Variant::as_name);
+                // Skipped variants can't be produced from the input, so don't suggest them.
+                let names = variants
+                    .iter()
+                    .filter(|v| !v.skip)
+                    .map(Variant::as_name)
+                    .collect::<Vec<_>>();
+
+                // When matching case-insensitively, the name is lowercased before it's compared;
+                // errors still report the name as the caller actually wrote it.
+                let (match_name, match_lit) = if base.case_insensitive {
+                    (
+                        quote!(__name.to_lowercase().as_str()),
+                        quote!(lit.to_lowercase().as_str()),
+                    )
+                } else {
+                    (quote!(__name.as_str()), quote!(lit))
+                };
+
+                let unknown_variant_err = if !names.is_empty() {
                     quote! {
-                        unknown_field_with_alts(__other, &[#(#names),*])
+                        unknown_field_with_alts(&__name, &[#(#names),*])
                     }
                 } else {
                     quote! {
-                        unknown_field(__other)
+                        unknown_field(&__name)
                     }
                 };
 
@@ -94,27 +112,28 @@ impl<'a> ToTokens for FromMetaImpl<'a> {
                     fn from_list(__outer: &[::darling::export::NestedMeta]) -> ::darling::Result<Self> {
                         // An enum must have exactly one value inside the parentheses if it's not a unit
                         // match arm
-                        match __outer.len() {
+                        (match __outer.len() {
                             0 => ::darling::export::Err(::darling::Error::too_few_items(1)),
                             1 => {
                                 if let ::darling::export::NestedMeta::Meta(ref __nested) = __outer[0] {
-                                    match ::darling::util::path_to_string(__nested.path()).as_ref() {
+                                    let __name = ::darling::util::path_to_string(__nested.path());
+                                    match #match_name {
                                         #(#struct_arms)*
-                                        __other => ::darling::export::Err(::darling::Error::#unknown_variant_err.with_span(__nested))
+                                        _ => ::darling::export::Err(::darling::Error::#unknown_variant_err.with_span(__nested))
                                     }
                                 } else {
                                     ::darling::export::Err(::darling::Error::unsupported_format("literal"))
                                 }
                             }
                             _ => ::darling::export::Err(::darling::Error::too_many_items(1)),
-                        }
+                        }) #post_transform
                     }
 
                     fn from_string(lit: &str) -> ::darling::Result<Self> {
-                        match lit {
+                        (match #match_lit {
                             #(#unit_arms)*
-                            __other => ::darling::export::Err(::darling::Error::unknown_value(__other))
-                        }
+                            _ => ::darling::export::Err(::darling::Error::unknown_value(lit))
+                        }) #post_transform
                     }
                 )
             }
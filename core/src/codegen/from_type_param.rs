@@ -37,6 +37,8 @@ impl<'a> ToTokens for FromTypeParamImpl<'a> {
             .as_ref()
             .map(|i| quote!(#i: #input.ident.clone(),));
         let passed_attrs = self.attrs.as_ref().map(|i| quote!(#i: __fwd_attrs,));
+        let passed_docs = self.base.docs_initializer(self.attrs_accessor());
+        let passed_cfg_predicates = self.base.cfg_predicates_initializer();
         let passed_bounds = self
             .bounds
             .as_ref()
@@ -67,6 +69,8 @@ impl<'a> ToTokens for FromTypeParamImpl<'a> {
                         #passed_bounds
                         #passed_default
                         #passed_attrs
+                        #passed_docs
+                        #passed_cfg_predicates
                         #initializers
                     }) #post_transform
                 }
@@ -85,6 +89,14 @@ impl<'a> ExtractAttribute for FromTypeParamImpl<'a> {
         self.forward_attrs
     }
 
+    fn unwrap_cfg_attr(&self) -> bool {
+        self.base.unwrap_cfg_attr
+    }
+
+    fn records_cfg_predicates(&self) -> bool {
+        self.base.cfg_predicates.is_some()
+    }
+
     fn param_name(&self) -> TokenStream {
         quote!(__type_param)
     }
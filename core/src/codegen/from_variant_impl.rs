@@ -49,6 +49,8 @@ impl<'a> ToTokens for FromVariantImpl<'a> {
             .as_ref()
             .map(|i| quote!(#i: #input.discriminant.as_ref().map(|(_, expr)| expr.clone()),));
         let passed_attrs = self.attrs.as_ref().map(|i| quote!(#i: __fwd_attrs,));
+        let passed_docs = self.base.docs_initializer(self.attrs_accessor());
+        let passed_cfg_predicates = self.base.cfg_predicates_initializer();
         let passed_fields = self
             .fields
             .as_ref()
@@ -92,6 +94,8 @@ impl<'a> ToTokens for FromVariantImpl<'a> {
                         #passed_ident
                         #passed_discriminant
                         #passed_attrs
+                        #passed_docs
+                        #passed_cfg_predicates
                         #passed_fields
                         #inits
                     }) #post_transform
@@ -115,6 +119,14 @@ impl<'a> ExtractAttribute for FromVariantImpl<'a> {
         self.forward_attrs
     }
 
+    fn unwrap_cfg_attr(&self) -> bool {
+        self.base.unwrap_cfg_attr
+    }
+
+    fn records_cfg_predicates(&self) -> bool {
+        self.base.cfg_predicates.is_some()
+    }
+
     fn param_name(&self) -> TokenStream {
         quote!(__variant)
     }
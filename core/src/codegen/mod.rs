@@ -10,6 +10,7 @@ mod from_type_param;
 mod from_variant_impl;
 mod outer_from_impl;
 mod postfix_transform;
+mod to_meta_item_impl;
 mod trait_impl;
 mod variant;
 mod variant_data;
@@ -25,6 +26,7 @@ pub use self::from_type_param::FromTypeParamImpl;
 pub use self::from_variant_impl::FromVariantImpl;
 pub use self::outer_from_impl::OuterFromImpl;
 pub use self::postfix_transform::PostfixTransform;
+pub use self::to_meta_item_impl::ToMetaItemImpl;
 pub use self::trait_impl::TraitImpl;
 pub use self::variant::Variant;
 pub use self::variant_data::FieldsGen;
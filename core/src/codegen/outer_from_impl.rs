@@ -1,6 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens, TokenStreamExt};
-use syn::{GenericParam, Generics, Path, TraitBound, TraitBoundModifier, TypeParamBound};
+use syn::{
+    GenericParam, Generics, Path, TraitBound, TraitBoundModifier, TypeParamBound, WherePredicate,
+};
 
 use crate::codegen::TraitImpl;
 use crate::usage::IdentSet;
@@ -16,14 +18,29 @@ pub trait OuterFromImpl<'a> {
         self.trait_path()
     }
 
+    /// Whether the `FIELDS` constant requested by `#[darling(reflect)]` is emitted alongside
+    /// this impl. Derives which are always paired with another derive opt out, so the
+    /// constant isn't declared twice.
+    fn emits_reflection(&self) -> bool {
+        true
+    }
+
     fn wrap<T: ToTokens>(&'a self, body: T, tokens: &mut TokenStream) {
         let base = self.base();
         let trayt = self.trait_path();
         let ty_ident = base.ident;
-        // The type parameters used in non-skipped, non-magic fields.
+        // The type parameters used in fields whose `FromMeta` impl is called.
         // These must impl `FromMeta` unless they have custom bounds.
-        let used = base.used_type_params();
-        let generics = compute_impl_bounds(self.trait_bound(), base.generics.clone(), &used);
+        let generics = match base.bound {
+            Some(bound) => with_where_predicates(base.generics.clone(), bound),
+            None => {
+                let used = base.used_type_params();
+                let generics =
+                    compute_impl_bounds(self.trait_bound(), base.generics.clone(), &used);
+                let generics = with_where_predicates(generics, &base.default_bounds());
+                with_where_predicates(generics, base.extend_bound)
+            }
+        };
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         tokens.append_all(quote!(
@@ -33,9 +50,25 @@ pub trait OuterFromImpl<'a> {
                 #body
             }
         ));
+
+        if self.emits_reflection() {
+            tokens.append_all(base.reflection());
+        }
     }
 }
 
+/// Append caller-supplied predicates to the where-clause of the generated impl.
+fn with_where_predicates(mut generics: Generics, predicates: &[WherePredicate]) -> Generics {
+    if !predicates.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(predicates.iter().cloned());
+    }
+
+    generics
+}
+
 fn compute_impl_bounds(bound: Path, mut generics: Generics, applies_to: &IdentSet) -> Generics {
     if generics.params.is_empty() {
         return generics;
@@ -1,17 +1,20 @@
 use quote::{quote, ToTokens, TokenStreamExt};
-use syn::{Ident, Path};
+use syn::Ident;
+
+use crate::util::Callable;
 
 /// A method invocation applied to a value.
 ///
-/// This is used for `map` and `and_then` transforms in derivations.
+/// This is used for `map` and `and_then` transforms in derivations. The function
+/// can be given as a path or as an inline closure.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PostfixTransform {
     pub(crate) transformer: Ident,
-    pub(crate) function: Path,
+    pub(crate) function: Callable,
 }
 
 impl PostfixTransform {
-    pub fn new(transformer: Ident, function: Path) -> Self {
+    pub fn new(transformer: Ident, function: Callable) -> Self {
         Self {
             transformer,
             function,
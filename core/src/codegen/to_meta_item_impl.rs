@@ -0,0 +1,200 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::parse::Parser;
+use syn::Ident;
+
+use crate::ast::{Data, Fields, Style};
+use crate::codegen::{Field, OuterFromImpl, TraitImpl, Variant};
+
+/// Generator for the `ToMetaItem` impl of a type which also derives `FromMeta`. Each field
+/// is rendered under the name it's parsed from.
+pub struct ToMetaItemImpl<'a> {
+    pub base: TraitImpl<'a>,
+}
+
+impl<'a> ToTokens for ToMetaItemImpl<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let base = &self.base;
+
+        let body = match base.data {
+            Data::Struct(ref vd) if vd.style.is_unit() => quote! {
+                ::darling::export::Some(::darling::export::syn::Meta::Path(__path.clone()))
+            },
+            Data::Struct(Fields {
+                style: Style::Tuple,
+                ref fields,
+                ..
+            }) if fields.len() == 1 => quote! {
+                ::darling::ToMetaItem::to_meta_item(&self.0, __path)
+            },
+            Data::Struct(Fields {
+                style: Style::Tuple,
+                ..
+            }) => {
+                unreachable!("Multi-field tuples are rejected by ToMetaItemOptions")
+            }
+            Data::Struct(ref data) => {
+                let items = items_of(data, |ident| quote!(&self.#ident));
+                quote! {
+                    #items
+                    ::darling::export::Some(::darling::util::meta_list(__path, __items))
+                }
+            }
+            Data::Enum(ref variants) => {
+                let arms = variants.iter().map(variant_arm);
+                quote! {
+                    match self {
+                        #(#arms)*
+                        #[allow(unreachable_patterns)]
+                        _ => ::darling::export::None,
+                    }
+                }
+            }
+        };
+
+        self.wrap(
+            quote! {
+                fn to_meta_item(
+                    &self,
+                    __path: &::darling::export::syn::Path,
+                ) -> ::darling::export::Option<::darling::export::syn::Meta> {
+                    #body
+                }
+            },
+            tokens,
+        );
+    }
+}
+
+impl<'a> OuterFromImpl<'a> for ToMetaItemImpl<'a> {
+    fn trait_path(&self) -> syn::Path {
+        path!(::darling::ToMetaItem)
+    }
+
+    fn base(&'a self) -> &'a TraitImpl<'a> {
+        &self.base
+    }
+
+    // `ToMetaItem` is derived alongside `FromMeta`, which emits the constant.
+    fn emits_reflection(&self) -> bool {
+        false
+    }
+}
+
+/// Generate the path of an item named `name`, or a compile error if the name can't be
+/// written as a path. The segments are given the span of the path of the item being rendered.
+fn item_path(name: &str) -> TokenStream {
+    let segments: Vec<&str> = name.split("::").collect();
+    let valid = segments
+        .iter()
+        .all(|segment| Ident::parse_any.parse_str(segment).is_ok());
+    if !valid {
+        let msg = format!("`{}` can't be rendered as a meta item name", name);
+        return quote!(compile_error!(#msg));
+    }
+
+    quote! {
+        &::darling::export::syn::Path {
+            leading_colon: ::darling::export::None,
+            segments: [#(#segments),*]
+                .iter()
+                .map(|__segment| {
+                    ::darling::export::syn::PathSegment::from(::darling::export::syn::Ident::new(
+                        __segment,
+                        ::darling::export::syn::spanned::Spanned::span(__path),
+                    ))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Generate a declaration of `__items`, a `Vec<NestedMeta>` of the rendered `fields`.
+/// `access` produces a reference to the value of the field with the given identifier.
+fn items_of<'a>(
+    fields: &'a Fields<Field<'a>>,
+    access: impl Fn(&Ident) -> TokenStream,
+) -> TokenStream {
+    let pushes = fields.iter().map(|field| {
+        let value = access(field.ident);
+        if field.skip || field.warnings {
+            quote!()
+        } else if field.flatten || field.rest {
+            quote! {
+                __items.extend(::darling::ToMetaItem::to_nested_items(#value));
+            }
+        } else if field.multiple {
+            let path = item_path(&field.name_in_attr);
+            quote! {
+                for __value in #value {
+                    __items.extend(
+                        ::darling::ToMetaItem::to_meta_item(__value, #path)
+                            .map(::darling::export::NestedMeta::Meta),
+                    );
+                }
+            }
+        } else {
+            let path = item_path(&field.name_in_attr);
+            quote! {
+                __items.extend(
+                    ::darling::ToMetaItem::to_meta_item(#value, #path)
+                        .map(::darling::export::NestedMeta::Meta),
+                );
+            }
+        }
+    });
+
+    quote! {
+        #[allow(unused_mut)]
+        let mut __items: ::darling::export::Vec<::darling::export::NestedMeta> =
+            ::darling::export::Vec::new();
+        #(#pushes)*
+    }
+}
+
+/// Generate the match arm which renders one variant of an enum. Skipped variants and
+/// variants which `FromMeta` can't parse fall through to the catch-all arm, which renders
+/// nothing.
+fn variant_arm(variant: &Variant) -> TokenStream {
+    if variant.skip {
+        return quote!();
+    }
+
+    let name = &variant.name_in_attr;
+    let variant_ident = variant.variant_ident;
+    let variant_path = item_path(name);
+
+    match variant.data.style {
+        Style::Unit => quote! {
+            Self::#variant_ident => ::darling::ToMetaItem::to_meta_item(#name, __path),
+        },
+        Style::Tuple if variant.data.is_newtype() => quote! {
+            Self::#variant_ident(__value) => {
+                let __variant_path = #variant_path;
+                let __variant = ::darling::ToMetaItem::to_meta_item(__value, __variant_path)
+                    .unwrap_or_else(|| ::darling::export::syn::Meta::Path(__variant_path.clone()));
+                ::darling::export::Some(::darling::util::meta_list(
+                    __path,
+                    ::darling::export::Vec::from([::darling::export::NestedMeta::Meta(__variant)]),
+                ))
+            }
+        },
+        Style::Tuple => quote!(),
+        Style::Struct => {
+            let idents = variant.data.iter().map(|field| field.ident);
+            let items = items_of(&variant.data, |ident| quote!(#ident));
+            quote! {
+                #[allow(unused_variables)]
+                Self::#variant_ident { #(#idents),* } => {
+                    #items
+                    let __variant = ::darling::util::meta_list(#variant_path, __items);
+                    ::darling::export::Some(::darling::util::meta_list(
+                        __path,
+                        ::darling::export::Vec::from([::darling::export::NestedMeta::Meta(__variant)]),
+                    ))
+                }
+            }
+        }
+    }
+}
@@ -1,13 +1,14 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Generics, Ident, WherePredicate};
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, WherePredicate};
 
 use crate::ast::{Data, Fields};
 use crate::codegen::{
     error::{ErrorCheck, ErrorDeclaration},
     DefaultExpression, Field, FieldsGen, PostfixTransform, Variant,
 };
-use crate::usage::{CollectTypeParams, IdentSet, Purpose};
+use crate::usage::{CollectTypeParams, IdentSet, Purpose, UsesTypeParams};
+use crate::util::PathList;
 
 #[derive(Debug)]
 pub struct TraitImpl<'a> {
@@ -16,8 +17,29 @@ pub struct TraitImpl<'a> {
     pub data: Data<Variant<'a>, Field<'a>>,
     pub default: Option<DefaultExpression<'a>>,
     pub post_transform: Option<&'a PostfixTransform>,
+    /// Predicates which replace the inferred bounds of the generated impl.
     pub bound: Option<&'a [WherePredicate]>,
+    /// Predicates which are added to the inferred bounds of the generated impl.
+    pub extend_bound: &'a [WherePredicate],
     pub allow_unknown_fields: bool,
+    pub case_insensitive: bool,
+    /// Groups of fields of which at most one may be set by the caller.
+    pub exclusive: &'a [PathList],
+    /// Groups of fields of which at least one must be set by the caller.
+    pub require_any: &'a [PathList],
+    /// Whether `darling::Validate::validate` is called on each successfully-parsed instance.
+    pub validate: bool,
+    /// The name of the option which names a file to read more options from.
+    pub from_file: Option<&'a str>,
+    /// Whether to emit a `FIELDS` constant describing the fields read from the input.
+    pub reflect: bool,
+    /// Whether attributes inside `#[cfg_attr(...)]` are read as though they were written
+    /// directly on the input.
+    pub unwrap_cfg_attr: bool,
+    /// The field which receives the doc comments of the input, if any.
+    pub docs: Option<&'a Ident>,
+    /// The field which receives the predicates of unwrapped `cfg_attr`s, if any.
+    pub cfg_predicates: Option<&'a Ident>,
 }
 
 impl<'a> TraitImpl<'a> {
@@ -29,11 +51,36 @@ impl<'a> TraitImpl<'a> {
             .collect()
     }
 
-    /// Get the type parameters which are used by non-skipped, non-magic fields.
+    /// Get the type parameters which are used by fields whose type's `FromMeta` impl is called.
     /// These type parameters will have a `FromMeta` bound applied to them in emitted
     /// code.
     pub fn used_type_params(&self) -> IdentSet {
-        self.type_params_matching(|f| !f.skip, |v| !v.skip)
+        self.type_params_matching(|f| f.uses_from_meta(), |v| !v.skip)
+    }
+
+    /// Get `Default` bounds for the types of fields which use type parameters and fall back to
+    /// `Default::default()` when absent, so that generic receivers don't need to declare them.
+    pub fn default_bounds(&self) -> Vec<WherePredicate> {
+        let declared = self.declared_type_params();
+        let options = Purpose::BoundImpl.into();
+        let fields: Vec<&Field> = match self.data {
+            Data::Struct(ref v) => v.iter().collect(),
+            Data::Enum(ref v) => v
+                .iter()
+                .filter(|v| !v.skip)
+                .flat_map(|v| v.data.iter())
+                .collect(),
+        };
+
+        fields
+            .into_iter()
+            .filter(|f| matches!(f.default_expression, Some(DefaultExpression::Trait { .. })))
+            .filter(|f| !f.uses_type_params(&options, &declared).is_empty())
+            .map(|f| {
+                let ty = f.ty;
+                parse_quote!(#ty: ::darling::export::Default)
+            })
+            .collect()
     }
 
     fn type_params_matching<F, V>(&self, field_filter: F, variant_filter: V) -> IdentSet
@@ -83,7 +130,7 @@ impl<'a> TraitImpl<'a> {
     }
 
     /// Gets the check which performs an early return if errors occurred during parsing.
-    pub fn check_errors(&self) -> ErrorCheck {
+    pub fn check_errors(&self) -> ErrorCheck<'_> {
         ErrorCheck::default()
     }
 
@@ -92,14 +139,30 @@ impl<'a> TraitImpl<'a> {
         if let Data::Struct(ref vd) = self.data {
             let vdr = vd.as_ref().map(Field::as_declaration);
             let decls = vdr.fields.as_slice();
-            quote!(#(#decls)*)
+            let warnings = self.make_field_ctx().discarded_warnings();
+            quote!(#(#decls)* #warnings)
         } else {
             quote!()
         }
     }
 
+    /// Generate the calls which run on a successfully-parsed instance: the container's `map` or
+    /// `and_then`, followed by validation if the container opted in to it.
     pub(in crate::codegen) fn post_transform_call(&self) -> Option<TokenStream> {
-        self.post_transform.map(|pt| quote!(#pt))
+        let validate = if self.validate {
+            Some(quote! {
+                .and_then(|__v| ::darling::Validate::validate(&__v).map(|()| __v))
+            })
+        } else {
+            None
+        };
+
+        if self.post_transform.is_none() && validate.is_none() {
+            return None;
+        }
+
+        let post_transform = self.post_transform;
+        Some(quote!(#post_transform #validate))
     }
 
     /// Generate local variable declaration and initialization for instance from which missing fields will be taken.
@@ -112,7 +175,16 @@ impl<'a> TraitImpl<'a> {
         if let Data::Struct(ref vd) = self.data {
             let check_nones = vd.as_ref().map(Field::as_presence_check);
             let checks = check_nones.fields.as_slice();
-            quote!(#(#checks)*)
+            let constraints = self.make_field_ctx().constraint_checks();
+            let exclusive = self
+                .exclusive
+                .iter()
+                .map(|group| exclusive_check(&group_fields(vd, group)));
+            let require_any = self
+                .require_any
+                .iter()
+                .map(|group| require_any_check(&group_fields(vd, group)));
+            quote!(#(#checks)* #constraints #(#exclusive)* #(#require_any)*)
         } else {
             quote!()
         }
@@ -122,6 +194,98 @@ impl<'a> TraitImpl<'a> {
         self.make_field_ctx().initializers()
     }
 
+    pub(in crate::codegen) fn lossy_initializers(&self) -> TokenStream {
+        self.make_field_ctx().lossy_initializers()
+    }
+
+    /// Generate the inherent impl which holds the `FIELDS` constant, if the container opted in
+    /// to it with `#[darling(reflect)]`.
+    pub(in crate::codegen) fn reflection(&self) -> Option<TokenStream> {
+        let fields = match self.data {
+            Data::Struct(ref fields) if self.reflect => fields,
+            _ => return None,
+        };
+
+        let infos = fields.iter().filter(|f| f.has_match_arm()).map(|f| {
+            let name = &f.name_in_attr;
+            let aliases = f
+                .aliases
+                .iter()
+                .copied()
+                .chain(f.deprecated_aliases.iter().copied());
+            let ty = tidy_tokens(f.ty.to_token_stream());
+            let default = match self.default_description(f) {
+                Some(default) => quote!(::darling::export::Some(#default)),
+                None => quote!(::darling::export::None),
+            };
+            let docs = f.docs.join("\n");
+            let multiple = f.multiple;
+            let required = !multiple
+                && f.default_expression.is_none()
+                && self.default.is_none()
+                && !is_optional_type(f.ty);
+            quote! {
+                ::darling::util::FieldInfo {
+                    name: #name,
+                    aliases: &[#(#aliases),*],
+                    ty: #ty,
+                    required: #required,
+                    multiple: #multiple,
+                    default: #default,
+                    docs: #docs,
+                }
+            }
+        });
+
+        let ident = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The fields read from the input by name, in declaration order.
+                pub const FIELDS: &'static [::darling::util::FieldInfo] = &[#(#infos),*];
+            }
+        })
+    }
+
+    /// Describe the expression a field falls back to when it's absent, as it would be written
+    /// in source.
+    fn default_description(&self, field: &Field) -> Option<String> {
+        let call = |expr: &DefaultExpression| match *expr {
+            DefaultExpression::Explicit(path) => {
+                Some(format!("{}()", tidy_tokens(path.to_token_stream())))
+            }
+            DefaultExpression::Trait { .. } => Some("Default::default()".to_string()),
+            DefaultExpression::Inherit(_) => None,
+        };
+
+        match field.default_expression.as_ref()? {
+            DefaultExpression::Inherit(ident) => {
+                let container = match self.default.as_ref() {
+                    Some(DefaultExpression::Trait { .. }) => "Self::default()".to_string(),
+                    Some(expr) => call(expr)?,
+                    None => return None,
+                };
+                Some(format!("{}.{}", container, ident))
+            }
+            expr => call(expr),
+        }
+    }
+
+    /// Generate the initializer of the field which receives the input's doc comments, if there
+    /// is one. `attrs` is an expression for the input's attributes.
+    pub fn docs_initializer(&self, attrs: TokenStream) -> Option<TokenStream> {
+        self.docs.map(|i| {
+            quote!(#i: ::darling::util::FromDocs::from_docs(::darling::util::doc_lines(#attrs)),)
+        })
+    }
+
+    /// Generate the initializer of the field which receives the predicates of unwrapped
+    /// `cfg_attr`s, if there is one. The predicates are collected by the attribute extractor.
+    pub fn cfg_predicates_initializer(&self) -> Option<TokenStream> {
+        self.cfg_predicates.map(|i| quote!(#i: __cfg_predicates,))
+    }
+
     /// Generate the loop which walks meta items looking for property matches.
     pub(in crate::codegen) fn core_loop(&self) -> TokenStream {
         self.make_field_ctx().core_loop()
@@ -130,7 +294,113 @@ impl<'a> TraitImpl<'a> {
     fn make_field_ctx(&'a self) -> FieldsGen<'a> {
         match self.data {
             Data::Enum(_) => panic!("Core loop on enums isn't supported"),
-            Data::Struct(ref data) => FieldsGen::new(data, self.allow_unknown_fields),
+            Data::Struct(ref data) => {
+                FieldsGen::new(data, self.allow_unknown_fields, self.case_insensitive)
+                    .with_file_key(self.from_file)
+            }
+        }
+    }
+}
+
+/// Resolve the identifiers in a field group to the fields they name.
+fn group_fields<'f, 'a>(fields: &'f Fields<Field<'a>>, group: &PathList) -> Vec<&'f Field<'a>> {
+    group
+        .iter()
+        .map(|path| {
+            fields
+                .iter()
+                .find(|f| path.is_ident(f.ident))
+                .expect("Field groups were validated when parsing options")
+        })
+        .collect()
+}
+
+/// List the names of a group's fields as they appear in the input.
+fn group_names(group: &[&Field]) -> String {
+    group
+        .iter()
+        .map(|f| format!("`{}`", f.name_in_attr))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Generate the check that at most one field of an `exclusive` group was set. The error
+/// names every field of the group that the caller supplied, and points at the second one.
+fn exclusive_check(group: &[&Field]) -> TokenStream {
+    let members = group.iter().map(|field| {
+        let name = &field.name_in_attr;
+        let path_local = field.path_local();
+        quote!((#name, #path_local.as_ref()))
+    });
+
+    let message = format!("At most one of {} may be set", group_names(group));
+
+    quote! {
+        {
+            let __found = [#(#members),*]
+                .iter()
+                .filter_map(|(__name, __path)| __path.map(|__path| (*__name, __path)))
+                .collect::<::darling::export::Vec<_>>();
+
+            if __found.len() > 1 {
+                let __names = __found
+                    .iter()
+                    .map(|(__name, _)| format!("`{}`", __name))
+                    .collect::<::darling::export::Vec<_>>()
+                    .join(", ");
+                __errors.push(
+                    ::darling::Error::custom(format!("{}, but found {}", #message, __names))
+                        .with_span(__found[1].1),
+                );
+            }
+        }
+    }
+}
+
+/// Generate the check that at least one field of a `require_any` group was set. Nothing
+/// in the input corresponds to the absent group, so the error lists the acceptable keys.
+fn require_any_check(group: &[&Field]) -> TokenStream {
+    let path_locals = group.iter().map(|field| field.path_local());
+    let message = format!("Expected at least one of {}", group_names(group));
+
+    quote! {
+        if #(#path_locals.is_none())&&* {
+            __errors.push(::darling::Error::custom(#message));
         }
     }
 }
+
+/// Write a type or path the way it appears in source, without the spaces that
+/// `TokenStream`'s `Display` impl puts between every token.
+fn tidy_tokens(tokens: TokenStream) -> String {
+    let mut out = tokens.to_string();
+    for (from, to) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" < ", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        (" ;", ";"),
+        ("& ", "&"),
+    ] {
+        out = out.replace(from, to);
+    }
+    out
+}
+
+/// Whether a field of this type can be omitted without a default, because its `FromMeta`
+/// impl handles absence.
+///
+/// `FIELDS` is a constant, so this can't call `FromMeta::from_none`; it goes by the name of
+/// the type instead. See `FieldInfo::required` for the cases this gets wrong.
+fn is_optional_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map_or(false, |seg| seg.ident == "Option" || seg.ident == "Flag"),
+        _ => false,
+    }
+}
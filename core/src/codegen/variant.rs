@@ -13,7 +13,7 @@ use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
 #[derive(Debug, Clone)]
 pub struct Variant<'a> {
     /// The name which will appear in code passed to the `FromMeta` input.
-    pub name_in_attr: Cow<'a, String>,
+    pub name_in_attr: Cow<'a, str>,
 
     /// The name of the variant which will be returned for a given `name_in_attr`.
     pub variant_ident: &'a Ident,
@@ -27,6 +27,10 @@ pub struct Variant<'a> {
     pub skip: bool,
 
     pub allow_unknown_fields: bool,
+
+    /// Whether the variant name, and field names in this variant, are matched without regard
+    /// to case.
+    pub case_insensitive: bool,
 }
 
 impl<'a> Variant<'a> {
@@ -34,6 +38,16 @@ impl<'a> Variant<'a> {
         &self.name_in_attr
     }
 
+    /// The pattern the input is compared against; lowercased if matching is case-insensitive,
+    /// while `name_in_attr` stays as written so that errors use the canonical name.
+    fn as_pattern(&self) -> Cow<'_, str> {
+        if self.case_insensitive {
+            Cow::Owned(self.name_in_attr.to_lowercase())
+        } else {
+            Cow::Borrowed(&self.name_in_attr)
+        }
+    }
+
     pub fn as_unit_match_arm(&'a self) -> UnitMatchArm<'a> {
         UnitMatchArm(self)
     }
@@ -66,18 +80,18 @@ impl<'a> ToTokens for UnitMatchArm<'a> {
             return;
         }
 
-        let name_in_attr = &val.name_in_attr;
+        let pattern = val.as_pattern();
 
         if val.data.is_unit() {
             let variant_ident = val.variant_ident;
             let ty_ident = val.ty_ident;
 
             tokens.append_all(quote!(
-                #name_in_attr => ::darling::export::Ok(#ty_ident::#variant_ident),
+                #pattern => ::darling::export::Ok(#ty_ident::#variant_ident),
             ));
         } else {
             tokens.append_all(quote!(
-                #name_in_attr => ::darling::export::Err(::darling::Error::unsupported_format("literal")),
+                #pattern => ::darling::export::Err(::darling::Error::unsupported_format("literal")),
             ));
         }
     }
@@ -85,7 +99,8 @@ impl<'a> ToTokens for UnitMatchArm<'a> {
 
 /// Code generator for an enum variant in a data-carrying match position.
 /// This is placed in generated `from_list` calls for the parent enum.
-/// Unit variants wrapped in this type will emit code to produce an "unsupported format" error.
+/// Unit variants wrapped in this type accept a bare word, e.g. `format(json)`, and emit code
+/// to produce an "unsupported format" error for anything else.
 pub struct DataMatchArm<'a>(&'a Variant<'a>);
 
 impl<'a> ToTokens for DataMatchArm<'a> {
@@ -97,18 +112,25 @@ impl<'a> ToTokens for DataMatchArm<'a> {
         }
 
         let name_in_attr = &val.name_in_attr;
+        let pattern = val.as_pattern();
         let variant_ident = val.variant_ident;
         let ty_ident = val.ty_ident;
 
         if val.data.is_unit() {
             tokens.append_all(quote!(
-                #name_in_attr => ::darling::export::Err(::darling::Error::unsupported_format("list")),
+                #pattern => {
+                    if let ::darling::export::syn::Meta::Path(_) = *__nested {
+                        ::darling::export::Ok(#ty_ident::#variant_ident)
+                    } else {
+                        ::darling::export::Err(::darling::Error::unsupported_format("list"))
+                    }
+                }
             ));
 
             return;
         }
 
-        let vdg = FieldsGen::new(&val.data, val.allow_unknown_fields);
+        let vdg = FieldsGen::new(&val.data, val.allow_unknown_fields, val.case_insensitive);
 
         if val.data.is_struct() {
             let declare_errors = ErrorDeclaration::default();
@@ -119,7 +141,7 @@ impl<'a> ToTokens for DataMatchArm<'a> {
             let inits = vdg.initializers();
 
             tokens.append_all(quote!(
-                #name_in_attr => {
+                #pattern => {
                     if let ::darling::export::syn::Meta::List(ref __data) = *__nested {
                         let __items = ::darling::export::NestedMeta::parse_meta_list(__data.tokens.clone())?;
                         let __items = &__items;
@@ -144,7 +166,7 @@ impl<'a> ToTokens for DataMatchArm<'a> {
             ));
         } else if val.data.is_newtype() {
             tokens.append_all(quote!(
-                #name_in_attr => {
+                #pattern => {
                     ::darling::export::Ok(
                         #ty_ident::#variant_ident(
                             ::darling::FromMeta::from_meta(__nested)
@@ -7,16 +7,30 @@ use crate::codegen::Field;
 pub struct FieldsGen<'a> {
     fields: &'a Fields<Field<'a>>,
     allow_unknown_fields: bool,
+    case_insensitive: bool,
+    file_key: Option<&'a str>,
 }
 
 impl<'a> FieldsGen<'a> {
-    pub fn new(fields: &'a Fields<Field<'a>>, allow_unknown_fields: bool) -> Self {
+    pub fn new(
+        fields: &'a Fields<Field<'a>>,
+        allow_unknown_fields: bool,
+        case_insensitive: bool,
+    ) -> Self {
         Self {
             fields,
             allow_unknown_fields,
+            case_insensitive,
+            file_key: None,
         }
     }
 
+    /// Read more items from the file named by the option `key`, if set.
+    pub fn with_file_key(mut self, key: Option<&'a str>) -> Self {
+        self.file_key = key;
+        self
+    }
+
     /// Create declarations for all the fields in the struct.
     pub(in crate::codegen) fn declarations(&self) -> TokenStream {
         match *self.fields {
@@ -26,29 +40,68 @@ impl<'a> FieldsGen<'a> {
                 ..
             } => {
                 let vdr = fields.iter().map(Field::as_declaration);
-                quote!(#(#vdr)*)
+                let warnings = self.discarded_warnings();
+                quote!(#(#vdr)* #warnings)
             }
             _ => panic!("FieldsGen doesn't support tuples yet"),
         }
     }
 
+    /// Declare a `__warnings` local for the generated code to record into when the struct reads
+    /// a file or the environment, but has no warnings field to keep the records in.
+    pub(in crate::codegen) fn discarded_warnings(&self) -> TokenStream {
+        let records = self.file_key.is_some() || self.fields.iter().any(|f| f.expand_env);
+        if records && !self.fields.iter().any(|f| f.warnings) {
+            quote! {
+                let mut __warnings = ::darling::util::Warnings::default();
+            }
+        } else {
+            quote!()
+        }
+    }
+
     /// Generate the loop which walks meta items looking for property matches.
     pub(in crate::codegen) fn core_loop(&self) -> TokenStream {
-        let arms = self.fields.as_ref().map(Field::as_match);
+        let case_insensitive = self.case_insensitive;
+        let arms = self
+            .fields
+            .as_ref()
+            .map(|field| field.as_match(case_insensitive));
 
         // If we're allowing unknown fields, then handling one is a no-op.
         // Otherwise, we're going to push a new spanned error pointing at the field.
-        let handle_unknown = if self.allow_unknown_fields {
+        // A flattened or rest field claims everything the other fields don't, so there are
+        // no unknown fields to report at this level.
+        let handle_unknown = if self.fields.iter().any(Field::takes_leftovers) {
+            quote! {
+                __leftovers.push(::darling::export::NestedMeta::Meta(__inner.clone()));
+            }
+        } else if self.allow_unknown_fields {
             quote!()
         } else {
+            // Skipped fields are never read from the input, so they shouldn't be suggested
+            // as alternatives for a misspelled name. Aliases are, but deprecated ones aren't.
+            let names = self
+                .fields
+                .iter()
+                .filter(|f| f.has_match_arm())
+                .flat_map(|f| std::iter::once(f.as_name()).chain(f.aliases.iter().copied()))
+                .collect::<Vec<_>>();
+
             // We can't call `unknown_field_with_alts` with an empty slice, or else it fails to
             // infer the type of the slice item.
-            let err_fn = if arms.is_empty() {
-                quote!(unknown_field(__other))
+            // When matching case-insensitively, `__other` has been lowercased, so report the
+            // name as the caller actually wrote it.
+            let unknown = if self.case_insensitive {
+                quote!(&__name)
             } else {
-                let names = self.fields.as_ref().map(Field::as_name);
-                let names = names.iter();
-                quote!(unknown_field_with_alts(__other, &[#(#names),*]))
+                quote!(__other)
+            };
+
+            let err_fn = if names.is_empty() {
+                quote!(unknown_field(#unknown))
+            } else {
+                quote!(unknown_field_with_alts(#unknown, &[#(#names),*]))
             };
 
             quote! {
@@ -57,20 +110,48 @@ impl<'a> FieldsGen<'a> {
         };
         let arms = arms.iter();
 
+        let match_name = if self.case_insensitive {
+            quote!(__name.to_lowercase().as_str())
+        } else {
+            quote!(__name.as_str())
+        };
+
+        // Only a rest field accepts literals; everywhere else they're an error.
+        let handle_lit = if self.fields.iter().any(|f| f.rest) {
+            quote! {
+                __leftovers.push(::darling::export::NestedMeta::Lit(__inner.clone()));
+            }
+        } else {
+            quote! {
+                __errors.push(::darling::Error::unsupported_format("literal")
+                    .with_span(__inner));
+            }
+        };
+
+        // The file is recorded in the warnings, so that cargo can track it.
+        let load_file = self.file_key.map(|key| {
+            quote! {
+                let __items = &::darling::util::items_with_file(
+                    __items,
+                    #key,
+                    &mut __errors,
+                    &mut __warnings,
+                );
+            }
+        });
+
         quote!(
+            #load_file
             for __item in __items {
                 match *__item {
                     ::darling::export::NestedMeta::Meta(ref __inner) => {
                         let __name = ::darling::util::path_to_string(__inner.path());
-                        match __name.as_str() {
+                        match #match_name {
                             #(#arms)*
                             __other => { #handle_unknown }
                         }
                     }
-                    ::darling::export::NestedMeta::Lit(ref __inner) => {
-                        __errors.push(::darling::Error::unsupported_format("literal")
-                            .with_span(__inner));
-                    }
+                    ::darling::export::NestedMeta::Lit(ref __inner) => { #handle_lit }
                 }
             }
         )
@@ -84,16 +165,75 @@ impl<'a> FieldsGen<'a> {
                 ..
             } => {
                 let checks = fields.iter().map(Field::as_presence_check);
-                quote!(#(#checks)*)
+                let constraints = self.constraint_checks();
+                quote!(#(#checks)* #constraints)
             }
             _ => panic!("FieldsGen doesn't support tuples for requirement checks"),
         }
     }
 
+    /// Generate the checks for `requires` and `conflicts_with` constraints between fields.
+    /// These only consider values supplied by the caller, not defaults.
+    pub(in crate::codegen) fn constraint_checks(&self) -> TokenStream {
+        let find = |ident: &syn::Ident| {
+            self.fields
+                .iter()
+                .find(|f| f.ident == ident)
+                .expect("Constraints were validated when parsing options")
+        };
+
+        let checks = self
+            .fields
+            .iter()
+            .filter(|f| f.has_constraints())
+            .map(|field| {
+                let path_local = field.path_local();
+                let name = &field.name_in_attr;
+
+                let requires = field.requires.iter().map(find).map(|other| {
+                    let present = other.presence_check();
+                    let message = format!("`{}` requires `{}`", name, other.name_in_attr);
+                    quote! {
+                        if !(#present) {
+                            __errors.push(::darling::Error::custom(#message).with_span(__path));
+                        }
+                    }
+                });
+
+                let conflicts = field.conflicts_with.iter().map(find).map(|other| {
+                    let present = other.presence_check();
+                    let message = format!("`{}` conflicts with `{}`", name, other.name_in_attr);
+                    quote! {
+                        if #present {
+                            __errors.push(::darling::Error::custom(#message).with_span(__path));
+                        }
+                    }
+                });
+
+                quote! {
+                    if let ::darling::export::Some(ref __path) = #path_local {
+                        #(#requires)*
+                        #(#conflicts)*
+                    }
+                }
+            });
+
+        quote!(#(#checks)*)
+    }
+
     pub(in crate::codegen) fn initializers(&self) -> TokenStream {
         let inits = self.fields.as_ref().map(Field::as_initializer);
         let inits = inits.iter();
 
         quote!(#(#inits),*)
     }
+
+    /// Generate initializers which fall back to `Default::default()` for fields which have no
+    /// value, for building a receiver despite errors.
+    pub(in crate::codegen) fn lossy_initializers(&self) -> TokenStream {
+        let inits = self.fields.as_ref().map(Field::as_lossy_initializer);
+        let inits = inits.iter();
+
+        quote!(#(#inits),*)
+    }
 }
@@ -59,3 +59,10 @@ pub fn from_type_param(input: &DeriveInput) -> TokenStream {
 pub fn from_variant(input: &DeriveInput) -> TokenStream {
     emit_impl_or_error!(options::FromVariantOptions::new(input))
 }
+
+/// Create tokens for a `darling::ToMetaItem` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn to_meta_item(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::ToMetaItemOptions::new(input))
+}
@@ -149,6 +149,9 @@ impl Error {
         Error::new(ErrorKind::UnexpectedType(ty.into()))
     }
 
+    /// Creates a new error for a field which has an unexpected expression type. This will
+    /// automatically extract the expression type name from the passed-in `Expr` and set the
+    /// span to encompass the expression. The message is the same as for `unexpected_type`.
     pub fn unexpected_expr_type(expr: &Expr) -> Self {
         Error::unexpected_type(match *expr {
             Expr::Array(_) => "array",
@@ -1,6 +1,6 @@
 use syn::DeriveInput;
 
-use crate::Result;
+use crate::{Error, Result};
 
 /// Creates an instance by parsing an entire proc-macro `derive` input,
 /// including the, identity, generics, and visibility of the type.
@@ -11,6 +11,23 @@ use crate::Result;
 pub trait FromDeriveInput: Sized {
     /// Create an instance from `syn::DeriveInput`, or return an error.
     fn from_derive_input(input: &DeriveInput) -> Result<Self>;
+
+    /// Create an instance on a best-effort basis, returning it along with any errors, so a
+    /// macro can still emit stub output that keeps IDE analysis working while it reports
+    /// problems.
+    ///
+    /// By default this returns the result of `from_derive_input`, so there's no instance if
+    /// there are errors. Receivers which derive this trait with `#[darling(lossy)]` build an
+    /// instance despite errors in their fields: a field which is missing or fails to parse
+    /// falls back to its default, or to `Default::default()` if it has none. Errors which
+    /// prevent the instance from being built, such as those from `map`, `and_then`, or
+    /// validation, still result in `None`.
+    fn from_derive_input_lossy(input: &DeriveInput) -> (Option<Self>, Option<Error>) {
+        match Self::from_derive_input(input) {
+            Ok(value) => (Some(value), None),
+            Err(error) => (None, Some(error)),
+        }
+    }
 }
 
 impl FromDeriveInput for () {
@@ -1,16 +1,20 @@
 use std::borrow::Cow;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::hash_map::HashMap;
-use std::collections::HashSet;
-use std::hash::BuildHasher;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque};
+use std::convert::TryFrom;
+use std::hash::{BuildHasher, Hash};
+use std::ops::{Range, RangeInclusive};
 use std::rc::Rc;
 use std::sync::atomic::AtomicBool;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, RwLock};
 
+use proc_macro2::Span;
+use quote::ToTokens;
 use syn::{Expr, Lit, Meta};
 
 use crate::ast::NestedMeta;
-use crate::util::path_to_string;
+use crate::util::{path_to_string, IdentString, Number};
 use crate::{Error, Result};
 
 /// Create an instance from an item in an attribute declaration.
@@ -34,9 +38,81 @@ use crate::{Error, Result};
 /// * As a string literal, e.g. `foo = "hello"`.
 /// * As a raw string literal, e.g. `foo = r#"hello "world""#`.
 ///
+/// `Cow<'static, str>`, `Box<str>`, `Rc<str>`, and `Arc<str>` accept the same formats.
+///
 /// ## Number
 /// * As a string literal, e.g. `foo = "-25"`.
-/// * As an unquoted positive value, e.g. `foo = 404`. Negative numbers must be in quotation marks.
+/// * As an unquoted value, e.g. `foo = 404` or `foo = -4`.
+///
+/// The `std::num::NonZero*` types accept the same formats and reject zero.
+///
+/// ## `PathBuf` and `Box<Path>`
+/// * As a string literal, e.g. `foo = "queries/a.sql"`. The path is not checked against the file system.
+///
+/// ## `IpAddr`, `Ipv4Addr`, `Ipv6Addr`, and `SocketAddr`
+/// * As a string literal, e.g. `foo = "127.0.0.1:8080"`.
+///
+/// ## `uuid::Uuid`
+/// * As a string literal in any format `Uuid::parse_str` accepts, e.g. `foo = "67e55044-10b1-426f-9247-bb680e5fe0c8"`.
+///   This requires the `uuid` feature.
+///
+/// ## `semver::Version` and `semver::VersionReq`
+/// * As a string literal, e.g. `since = "1.2.0"` or `compatible = ">=1, <2"`. This requires the `semver` feature.
+///
+/// ## `url::Url`
+/// * As a string literal containing an absolute URL, e.g. `endpoint = "https://example.com/api"`.
+///   This requires the `url` feature.
+///
+/// ## `regex::Regex`
+/// * As a string literal containing the pattern, e.g. `pattern = "^[a-z]+$"`. The pattern is
+///   compiled during expansion, and `Regex::as_str` returns it as written so it can be emitted
+///   into generated code. This requires the `regex` feature.
+///
+/// ## `chrono::NaiveDate` and `chrono::DateTime<chrono::FixedOffset>`
+/// * As a string literal in RFC 3339 format, e.g. `since = "2023-06-01"` for a date or
+///   `built = "2023-06-01T12:00:00+02:00"` for a date and time. This requires the `chrono` feature.
+///
+/// ## `time::OffsetDateTime`
+/// * As a string literal in RFC 3339 format, e.g. `built = "2023-06-01T12:00:00Z"`.
+///   This requires the `time` feature.
+///
+/// ## `Duration`
+/// * As an integer literal, which is a number of milliseconds, e.g. `foo = 1500`.
+/// * As an integer literal with a unit suffix, e.g. `foo = 5s`.
+/// * As a string literal, e.g. `foo = "300ms"` or `foo = "2 min"`.
+///
+/// The units are `ns`, `us`, `ms`, `s`, `min`, and `h`.
+///
+/// ## `Vec<T>`
+/// * As a list, e.g. `foo(a, b, c)`, with each item parsed by `T`.
+/// * As an array, e.g. `foo = [1, 2, 3]`.
+/// * As a byte string literal, e.g. `foo = b"\x7fELF"`, if `T` accepts integers.
+/// * As a string containing an array, e.g. `foo = "[1, 2, 3]"`.
+///
+/// A single value, e.g. `foo = "a"`, is an error; use [`util::OneOrMany`](crate::util::OneOrMany)
+/// to accept it as a one-element list.
+///
+/// `Cow<'static, [u8]>` accepts byte strings and arrays of bytes.
+///
+/// ## `(A, B)` and `(A, B, C)`
+/// * As a list with one item per element, e.g. `foo(1, "a")`.
+/// * As a tuple, e.g. `foo = (1, "a")`.
+///
+/// `VecDeque<T>`, `LinkedList<T>`, and `BinaryHeap<T>` accept the same formats.
+///
+/// ## `[T; N]`
+/// * The same formats as `Vec<T>`, with exactly `N` items.
+///
+/// ## `Range<T>` and `RangeInclusive<T>`
+/// * As a range with both ends, e.g. `foo = 1..10` or `foo = 1..=10`.
+/// * As a string literal containing a range, e.g. `foo = "1..10"`.
+///
+/// ## `proc_macro2::TokenStream`
+/// * Any format, captured without parsing: the tokens inside a list, the tokens after `=`,
+///   or nothing for a word.
+///
+/// ## `HashSet<T>` and `BTreeSet<T>`
+/// * The same formats as `Vec<T>`. A value that appears more than once is an error.
 ///
 /// ## ()
 /// * Word with no value specified, e.g. `foo`. This is best used with `Option`.
@@ -114,6 +190,9 @@ pub trait FromMeta: Sized {
             Lit::Bool(ref b) => Self::from_bool(b.value),
             Lit::Str(ref s) => Self::from_string(&s.value()),
             Lit::Char(ref ch) => Self::from_char(ch.value()),
+            Lit::ByteStr(ref bytes) => Self::from_byte_str(&bytes.value()),
+            Lit::Int(ref int) => Self::from_int(int),
+            Lit::Float(ref float) => Self::from_float(float),
             _ => Err(Error::unexpected_lit_type(value)),
         })
         .map_err(|e| e.with_span(value))
@@ -145,6 +224,30 @@ pub trait FromMeta: Sized {
     fn from_bool(value: bool) -> Result<Self> {
         Err(Error::unexpected_type("bool"))
     }
+
+    /// Create an instance from a byte string literal in a value position.
+    #[allow(unused_variables)]
+    fn from_byte_str(value: &[u8]) -> Result<Self> {
+        Err(Error::unexpected_type("byte string"))
+    }
+
+    /// Create an instance from an integer literal in a value position.
+    ///
+    /// The literal is passed through unparsed so that implementers can choose the
+    /// target type with [`syn::LitInt::base10_parse`] and inspect its suffix.
+    #[allow(unused_variables)]
+    fn from_int(value: &syn::LitInt) -> Result<Self> {
+        Err(Error::unexpected_type("int"))
+    }
+
+    /// Create an instance from a float literal in a value position.
+    ///
+    /// The literal is passed through unparsed so that implementers can choose the
+    /// target type with [`syn::LitFloat::base10_parse`] and inspect its suffix.
+    #[allow(unused_variables)]
+    fn from_float(value: &syn::LitFloat) -> Result<Self> {
+        Err(Error::unexpected_type("float"))
+    }
 }
 
 // FromMeta impls for std and syn types.
@@ -192,7 +295,7 @@ impl FromMeta for char {
         if let (Some(char), None) = (char1, char2) {
             Ok(char)
         } else {
-            Err(Error::unexpected_type("string"))
+            Err(Error::unknown_value(s))
         }
     }
 }
@@ -203,12 +306,150 @@ impl FromMeta for String {
     }
 }
 
+/// Generate an impl of `FromMeta` for a string-like smart pointer, which accepts the
+/// same values as `String`.
+macro_rules! from_meta_str_ptr {
+    ($ty:ty) => {
+        impl FromMeta for $ty {
+            fn from_string(s: &str) -> Result<Self> {
+                Ok(s.to_string().into())
+            }
+        }
+    };
+}
+
+from_meta_str_ptr!(Cow<'static, str>);
+from_meta_str_ptr!(Box<str>);
+from_meta_str_ptr!(Rc<str>);
+from_meta_str_ptr!(Arc<str>);
+
 impl FromMeta for std::path::PathBuf {
     fn from_string(s: &str) -> Result<Self> {
         Ok(s.into())
     }
 }
 
+impl FromMeta for Box<std::path::Path> {
+    fn from_string(s: &str) -> Result<Self> {
+        Ok(std::path::PathBuf::from(s).into_boxed_path())
+    }
+}
+
+/// Generate an impl of `FromMeta` that parses a string literal with `FromStr`, naming
+/// the expected kind of value when the string is rejected.
+macro_rules! from_meta_str_parse {
+    ($ty:path, $expected:literal) => {
+        impl FromMeta for $ty {
+            fn from_string(s: &str) -> Result<Self> {
+                s.parse()
+                    .map_err(|_| Error::custom(format!("`{}` is not {}", s, $expected)))
+            }
+        }
+    };
+}
+
+from_meta_str_parse!(std::net::IpAddr, "an IP address");
+from_meta_str_parse!(std::net::Ipv4Addr, "an IPv4 address");
+from_meta_str_parse!(std::net::Ipv6Addr, "an IPv6 address");
+from_meta_str_parse!(std::net::SocketAddr, "a socket address");
+#[cfg(feature = "uuid")]
+from_meta_str_parse!(uuid::Uuid, "a UUID");
+#[cfg(feature = "semver")]
+from_meta_str_parse!(semver::Version, "a semantic version");
+#[cfg(feature = "semver")]
+from_meta_str_parse!(semver::VersionReq, "a version requirement");
+
+/// Parses absolute URLs. The error names what `url` found wrong with the string.
+#[cfg(feature = "url")]
+impl FromMeta for url::Url {
+    fn from_string(s: &str) -> Result<Self> {
+        url::Url::parse(s).map_err(|e| Error::custom(format!("`{}` is not a valid URL: {}", s, e)))
+    }
+}
+
+#[cfg(feature = "regex")]
+impl FromMeta for regex::Regex {
+    fn from_string(s: &str) -> Result<Self> {
+        regex::Regex::new(s)
+            .map_err(|e| Error::custom(format!("Invalid regular expression: {}", e)))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromMeta for chrono::NaiveDate {
+    fn from_string(s: &str) -> Result<Self> {
+        chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .map_err(|e| Error::custom(format!("`{}` is not a date: {}", s, e)))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromMeta for chrono::DateTime<chrono::FixedOffset> {
+    fn from_string(s: &str) -> Result<Self> {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .map_err(|e| Error::custom(format!("`{}` is not an RFC 3339 date and time: {}", s, e)))
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromMeta for time::OffsetDateTime {
+    fn from_string(s: &str) -> Result<Self> {
+        time::OffsetDateTime::parse(s, &time::format_description::well_known::Rfc3339)
+            .map_err(|e| Error::custom(format!("`{}` is not an RFC 3339 date and time: {}", s, e)))
+    }
+}
+
+/// Parses durations written as an amount and a unit, such as `"5s"` or `"300ms"`.
+/// An integer literal without a suffix is a number of milliseconds.
+impl FromMeta for std::time::Duration {
+    fn from_string(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (amount, unit) = s.split_at(split);
+        amount
+            .parse()
+            .ok()
+            .and_then(|amount| duration_from_parts(amount, unit.trim_start()))
+            .ok_or_else(|| Error::unknown_value(s))
+    }
+
+    fn from_int(value: &syn::LitInt) -> Result<Self> {
+        let amount = value.base10_parse()?;
+        duration_from_parts(amount, value.suffix())
+            .ok_or_else(|| Error::unknown_value(&value.to_string()))
+    }
+}
+
+/// Build a `Duration` from a whole number of `unit`s, where an empty unit means milliseconds.
+fn duration_from_parts(amount: u64, unit: &str) -> Option<std::time::Duration> {
+    use std::time::Duration;
+
+    Some(match unit {
+        "ns" => Duration::from_nanos(amount),
+        "us" => Duration::from_micros(amount),
+        "" | "ms" => Duration::from_millis(amount),
+        "s" => Duration::from_secs(amount),
+        "min" => Duration::from_secs(amount.checked_mul(60)?),
+        "h" => Duration::from_secs(amount.checked_mul(60 * 60)?),
+        _ => return None,
+    })
+}
+
+/// Shared `from_expr` for the numeric impls, which also accepts a negated number such as
+/// `-4`. Unsigned types reject negated numbers when parsing them.
+fn number_from_expr<T: FromMeta>(expr: &Expr) -> Result<T> {
+    match expr {
+        Expr::Lit(lit) => T::from_value(&lit.lit),
+        Expr::Group(group) => number_from_expr(&group.expr),
+        Expr::Unary(_) => match Number::from_expr(expr)? {
+            Number::Int(int) => T::from_int(&int),
+            Number::Float(float) => T::from_float(&float),
+        },
+        _ => Err(Error::unexpected_expr_type(expr)),
+    }
+    .map_err(|e| e.with_span(expr))
+}
+
 /// Generate an impl of `FromMeta` that will accept strings which parse to numbers or
 /// integer literals.
 macro_rules! from_meta_num {
@@ -218,13 +459,12 @@ macro_rules! from_meta_num {
                 s.parse().map_err(|_| Error::unknown_value(s))
             }
 
-            fn from_value(value: &Lit) -> Result<Self> {
-                (match *value {
-                    Lit::Str(ref s) => Self::from_string(&s.value()),
-                    Lit::Int(ref s) => Ok(s.base10_parse::<$ty>().unwrap()),
-                    _ => Err(Error::unexpected_lit_type(value)),
-                })
-                .map_err(|e| e.with_span(value))
+            fn from_int(value: &syn::LitInt) -> Result<Self> {
+                value.base10_parse::<$ty>().map_err(Error::from)
+            }
+
+            fn from_expr(expr: &Expr) -> Result<Self> {
+                number_from_expr(expr)
             }
         }
     };
@@ -243,8 +483,45 @@ from_meta_num!(i64);
 from_meta_num!(i128);
 from_meta_num!(isize);
 
-/// Generate an impl of `FromMeta` that will accept strings which parse to floats or
-/// float literals.
+/// Generate an impl of `FromMeta` for a `NonZero*` type which parses its primitive
+/// counterpart and rejects zero.
+macro_rules! from_meta_nonzero {
+    ($ty:ident, $prim:ident) => {
+        impl FromMeta for std::num::$ty {
+            fn from_string(s: &str) -> Result<Self> {
+                Self::new($prim::from_string(s)?).ok_or_else(nonzero_error)
+            }
+
+            fn from_int(value: &syn::LitInt) -> Result<Self> {
+                Self::new($prim::from_int(value)?).ok_or_else(nonzero_error)
+            }
+
+            fn from_expr(expr: &Expr) -> Result<Self> {
+                number_from_expr(expr)
+            }
+        }
+    };
+}
+
+fn nonzero_error() -> Error {
+    Error::custom("Expected a non-zero value")
+}
+
+from_meta_nonzero!(NonZeroU8, u8);
+from_meta_nonzero!(NonZeroU16, u16);
+from_meta_nonzero!(NonZeroU32, u32);
+from_meta_nonzero!(NonZeroU64, u64);
+from_meta_nonzero!(NonZeroU128, u128);
+from_meta_nonzero!(NonZeroUsize, usize);
+from_meta_nonzero!(NonZeroI8, i8);
+from_meta_nonzero!(NonZeroI16, i16);
+from_meta_nonzero!(NonZeroI32, i32);
+from_meta_nonzero!(NonZeroI64, i64);
+from_meta_nonzero!(NonZeroI128, i128);
+from_meta_nonzero!(NonZeroIsize, isize);
+
+/// Generate an impl of `FromMeta` that will accept strings which parse to floats,
+/// float literals, or integer literals.
 macro_rules! from_meta_float {
     ($ty:ident) => {
         impl FromMeta for $ty {
@@ -252,13 +529,16 @@ macro_rules! from_meta_float {
                 s.parse().map_err(|_| Error::unknown_value(s))
             }
 
-            fn from_value(value: &Lit) -> Result<Self> {
-                (match *value {
-                    Lit::Str(ref s) => Self::from_string(&s.value()),
-                    Lit::Float(ref s) => Ok(s.base10_parse::<$ty>().unwrap()),
-                    _ => Err(Error::unexpected_lit_type(value)),
-                })
-                .map_err(|e| e.with_span(value))
+            fn from_int(value: &syn::LitInt) -> Result<Self> {
+                value.base10_parse::<$ty>().map_err(Error::from)
+            }
+
+            fn from_float(value: &syn::LitFloat) -> Result<Self> {
+                value.base10_parse::<$ty>().map_err(Error::from)
+            }
+
+            fn from_expr(expr: &Expr) -> Result<Self> {
+                number_from_expr(expr)
             }
         }
     };
@@ -317,6 +597,17 @@ impl FromMeta for syn::Expr {
 
 /// Parser for paths that supports both quote-wrapped and bare values.
 impl FromMeta for syn::Path {
+    /// Accepts a bare path in a list, e.g. `traits(Debug, serde::Serialize)`, as well as
+    /// the formats accepted after `=`.
+    fn from_nested_meta(item: &NestedMeta) -> Result<Self> {
+        match item {
+            NestedMeta::Meta(Meta::Path(path)) => Ok(path.clone()),
+            NestedMeta::Meta(meta) => Self::from_meta(meta),
+            NestedMeta::Lit(lit) => Self::from_value(lit),
+        }
+        .map_err(|e| e.with_span(item))
+    }
+
     fn from_string(value: &str) -> Result<Self> {
         syn::parse_str(value).map_err(|_| Error::unknown_value(value))
     }
@@ -332,6 +623,11 @@ impl FromMeta for syn::Path {
     fn from_expr(expr: &Expr) -> Result<Self> {
         match expr {
             Expr::Lit(lit) => Self::from_value(&lit.lit),
+            // A `syn::Path` can't hold the `<T as Trait>` part of a qualified path.
+            Expr::Path(path) if path.qself.is_some() => Err(Error::custom(
+                "Qualified paths are not supported here; expected a path such as `my_crate::handle`",
+            )
+            .with_span(expr)),
             Expr::Path(path) => Ok(path.path.clone()),
             _ => Err(Error::unexpected_expr_type(expr)),
         }
@@ -339,6 +635,26 @@ impl FromMeta for syn::Path {
 }
 
 impl FromMeta for syn::Ident {
+    /// Accepts a bare word in a list, e.g. `derives(Debug, Clone)`, as well as the formats
+    /// accepted after `=`.
+    fn from_nested_meta(item: &NestedMeta) -> Result<Self> {
+        match item {
+            NestedMeta::Meta(Meta::Path(path)) => path
+                .get_ident()
+                .cloned()
+                .ok_or_else(|| Error::custom("Expected an identifier")),
+            NestedMeta::Meta(meta) => Self::from_meta(meta),
+            NestedMeta::Lit(lit) => Self::from_value(lit),
+        }
+        .map_err(|e| e.with_span(item))
+    }
+
+    /// Accepts a list with a single item, e.g. `format(json)`.
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        check_item_count(items.len(), 1)?;
+        Self::from_nested_meta(&items[0])
+    }
+
     fn from_string(value: &str) -> Result<Self> {
         syn::parse_str(value).map_err(|_| Error::unknown_value(value))
     }
@@ -403,15 +719,24 @@ macro_rules! from_syn_expr_type {
 
 from_syn_expr_type!(syn::ExprArray, Array);
 from_syn_expr_type!(syn::ExprPath, Path);
+from_syn_expr_type!(syn::ExprClosure, Closure);
 
 /// Adapter from `syn::parse::Parse` to `FromMeta` for items that cannot
 /// be expressed in a [`syn::MetaNameValue`].
 ///
 /// This cannot be a blanket impl, due to the `syn::Lit` family's need to handle non-string values.
 /// Therefore, we use a macro and a lot of impls.
+///
+/// Types which can be written as a path also accept an unquoted path, such as `ty = String`
+/// or `ty = Vec::<u8>`; other types must be quoted, e.g. `ty = "Arc<Mutex<T>>"`.
 macro_rules! from_syn_parse {
     ($ty:path) => {
+        from_syn_parse!($ty, {});
+    };
+    ($ty:path, { $($extra:tt)* }) => {
         impl FromMeta for $ty {
+            $($extra)*
+
             fn from_string(value: &str) -> Result<Self> {
                 syn::parse_str(value).map_err(|_| Error::unknown_value(value))
             }
@@ -428,7 +753,16 @@ macro_rules! from_syn_parse {
     };
 }
 
-from_syn_parse!(syn::Type);
+from_syn_parse!(syn::Type, {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        syn::TypePath::from_expr(expr)
+            .map(syn::Type::Path)
+            .or_else(|e| match expr {
+                Expr::Lit(lit) => Self::from_value(&lit.lit),
+                _ => Err(e),
+            })
+    }
+});
 from_syn_parse!(syn::TypeArray);
 from_syn_parse!(syn::TypeBareFn);
 from_syn_parse!(syn::TypeGroup);
@@ -438,168 +772,561 @@ from_syn_parse!(syn::TypeMacro);
 from_syn_parse!(syn::TypeNever);
 from_syn_parse!(syn::TypeParam);
 from_syn_parse!(syn::TypeParen);
-from_syn_parse!(syn::TypePath);
+from_syn_parse!(syn::TypePath, {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Path(path) if path.attrs.is_empty() => Ok(syn::TypePath {
+                qself: path.qself.clone(),
+                path: path.path.clone(),
+            }),
+            Expr::Lit(lit) => Self::from_value(&lit.lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+});
 from_syn_parse!(syn::TypePtr);
 from_syn_parse!(syn::TypeReference);
 from_syn_parse!(syn::TypeSlice);
 from_syn_parse!(syn::TypeTraitObject);
 from_syn_parse!(syn::TypeTuple);
 from_syn_parse!(syn::Visibility);
-from_syn_parse!(syn::WhereClause);
-
-macro_rules! from_numeric_array {
-    ($ty:ident) => {
-        /// Parsing an unsigned integer array, i.e. `example = "[1, 2, 3, 4]"`.
-        impl FromMeta for Vec<$ty> {
-            fn from_expr(expr: &syn::Expr) -> Result<Self> {
-                if let syn::Expr::Array(expr_array) = expr {
-                    let v = expr_array
-                        .elems
-                        .iter()
-                        .map(|expr| match expr {
-                            Expr::Lit(lit) => $ty::from_value(&lit.lit),
-                            _ => Err(Error::custom("Expected array of unsigned integers")
-                                .with_span(expr)),
-                        })
-                        .collect::<Result<Vec<$ty>>>();
-                    v
-                } else if let syn::Expr::Lit(expr_lit) = expr {
-                    Self::from_value(&expr_lit.lit)
-                } else {
-                    Err(Error::unexpected_expr_type(expr))
-                }
-            }
-
-            fn from_value(value: &Lit) -> Result<Self> {
-                let expr_array = syn::ExprArray::from_value(value)?;
-                Self::from_expr(&syn::Expr::Array(expr_array))
+from_syn_parse!(syn::Generics);
+from_syn_parse!(syn::Block, {
+    /// Accepts an unquoted block, e.g. `on_error = { return; }`, as well as a quoted one.
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Block(block) if block.attrs.is_empty() && block.label.is_none() => {
+                Ok(block.block.clone())
             }
+            Expr::Lit(lit) => Self::from_value(&lit.lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Err(Error::unexpected_expr_type(expr)),
         }
-    };
-}
-
-from_numeric_array!(u8);
-from_numeric_array!(u16);
-from_numeric_array!(u32);
-from_numeric_array!(u64);
-from_numeric_array!(usize);
+        .map_err(|e| e.with_span(expr))
+    }
+});
+from_syn_parse!(syn::WhereClause);
 
-impl FromMeta for syn::Lit {
-    fn from_value(value: &Lit) -> Result<Self> {
-        Ok(value.clone())
+/// Parsing a pattern from a string, e.g. `skip_if = "None | Some(0)"`. Alternatives
+/// separated by `|` are allowed, as in a `match` arm.
+impl FromMeta for syn::Pat {
+    fn from_string(value: &str) -> Result<Self> {
+        syn::parse::Parser::parse_str(syn::Pat::parse_multi_with_leading_vert, value)
+            .map_err(|_| Error::unknown_value(value))
     }
-}
 
-macro_rules! from_meta_lit {
-    ($impl_ty:path, $lit_variant:path) => {
-        impl FromMeta for $impl_ty {
-            fn from_value(value: &Lit) -> Result<Self> {
-                if let $lit_variant(ref value) = *value {
-                    Ok(value.clone())
-                } else {
-                    Err(Error::unexpected_lit_type(value))
-                }
-            }
+    fn from_value(value: &Lit) -> Result<Self> {
+        if let Lit::Str(ref v) = *value {
+            v.parse_with(syn::Pat::parse_multi_with_leading_vert)
+                .map_err(|_| Error::unknown_lit_str_value(v))
+        } else {
+            Err(Error::unexpected_lit_type(value))
         }
-    };
-}
-
-from_meta_lit!(syn::LitInt, Lit::Int);
-from_meta_lit!(syn::LitFloat, Lit::Float);
-from_meta_lit!(syn::LitStr, Lit::Str);
-from_meta_lit!(syn::LitByte, Lit::Byte);
-from_meta_lit!(syn::LitByteStr, Lit::ByteStr);
-from_meta_lit!(syn::LitChar, Lit::Char);
-from_meta_lit!(syn::LitBool, Lit::Bool);
-from_meta_lit!(proc_macro2::Literal, Lit::Verbatim);
-
-impl FromMeta for syn::Meta {
-    fn from_meta(value: &syn::Meta) -> Result<Self> {
-        Ok(value.clone())
     }
 }
 
-impl FromMeta for Vec<syn::WherePredicate> {
+/// Parsing a lifetime from a string, e.g. `lifetime = "'de"`.
+impl FromMeta for syn::Lifetime {
     fn from_string(value: &str) -> Result<Self> {
-        syn::WhereClause::from_string(&format!("where {}", value))
-            .map(|c| c.predicates.into_iter().collect())
+        syn::parse_str(value).map_err(|_| lifetime_error(value))
     }
 
     fn from_value(value: &Lit) -> Result<Self> {
-        if let syn::Lit::Str(s) = value {
-            syn::WhereClause::from_value(&syn::Lit::Str(syn::LitStr::new(
-                &format!("where {}", s.value()),
-                value.span(),
-            )))
-            .map(|c| c.predicates.into_iter().collect())
+        if let Lit::Str(ref v) = *value {
+            v.parse()
+                .map_err(|_| lifetime_error(&v.value()).with_span(v))
         } else {
             Err(Error::unexpected_lit_type(value))
         }
     }
 }
 
-impl FromMeta for ident_case::RenameRule {
-    fn from_string(value: &str) -> Result<Self> {
-        value.parse().map_err(|_| Error::unknown_value(value))
-    }
+fn lifetime_error(value: &str) -> Error {
+    Error::custom(format!(
+        "`{}` is not a lifetime; expected a name with a leading apostrophe, such as `'de`",
+        value
+    ))
 }
 
-impl<T: FromMeta> FromMeta for Option<T> {
-    fn from_none() -> Option<Self> {
-        Some(None)
-    }
+/// Parsing a sequence of values, each of which is parsed by `T`:
+///
+/// * As a list, e.g. `example(a, b = 1, "c")`, where each item is parsed with `T::from_nested_meta`.
+/// * As an array, e.g. `example = [1, 2, 3]`, or a string containing one, e.g. `example = "[1, 2, 3]"`.
+/// * As a byte string, e.g. `example = b"\x7fELF"`, if `T` accepts integer literals.
+impl<T: FromMeta> FromMeta for Vec<T> {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        let mut errors = Error::accumulator();
+        let values = items
+            .iter()
+            .filter_map(|item| errors.handle(T::from_nested_meta(item)))
+            .collect();
 
-    fn from_meta(item: &Meta) -> Result<Self> {
-        FromMeta::from_meta(item).map(Some)
+        errors.finish_with(values)
     }
-}
 
-impl<T: FromMeta> FromMeta for Box<T> {
-    fn from_none() -> Option<Self> {
-        T::from_none().map(Box::new)
-    }
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Array(array) => {
+                let mut errors = Error::accumulator();
+                let values = array
+                    .elems
+                    .iter()
+                    .filter_map(|elem| errors.handle(T::from_expr(elem)))
+                    .collect();
 
-    fn from_meta(item: &Meta) -> Result<Self> {
-        FromMeta::from_meta(item).map(Box::new)
+                errors.finish_with(values)
+            }
+            Expr::Lit(lit) => Self::from_value(&lit.lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
     }
-}
 
-impl<T: FromMeta> FromMeta for Result<T> {
-    fn from_none() -> Option<Self> {
-        T::from_none().map(Ok)
+    fn from_value(value: &Lit) -> Result<Self> {
+        if let Lit::ByteStr(ref bytes) = *value {
+            return Self::from_byte_str(&bytes.value()).map_err(|e| e.with_span(value));
+        }
+
+        let expr_array = syn::ExprArray::from_value(value)?;
+        Self::from_expr(&Expr::Array(expr_array))
     }
 
-    fn from_meta(item: &Meta) -> Result<Self> {
-        Ok(FromMeta::from_meta(item))
+    fn from_byte_str(value: &[u8]) -> Result<Self> {
+        value
+            .iter()
+            .map(|byte| T::from_int(&syn::LitInt::new(&byte.to_string(), Span::call_site())))
+            .collect()
     }
 }
 
-/// Parses the meta-item, and in case of error preserves a copy of the input for
-/// later analysis.
-impl<T: FromMeta> FromMeta for ::std::result::Result<T, Meta> {
-    fn from_meta(item: &Meta) -> Result<Self> {
-        T::from_meta(item)
-            .map(Ok)
-            .or_else(|_| Ok(Err(item.clone())))
-    }
+/// Generate an impl of `FromMeta` for a sequence collection which accepts the same
+/// formats as `Vec<T>` and is built from the parsed `Vec`.
+macro_rules! from_meta_seq {
+    ($ty:ident<T: $($bound:path),+>) => {
+        impl<T: $($bound+)+> FromMeta for $ty<T> {
+            fn from_list(items: &[NestedMeta]) -> Result<Self> {
+                Vec::<T>::from_list(items).map(|values| values.into_iter().collect())
+            }
+
+            fn from_expr(expr: &Expr) -> Result<Self> {
+                Vec::<T>::from_expr(expr).map(|values| values.into_iter().collect())
+            }
+
+            fn from_value(value: &Lit) -> Result<Self> {
+                Vec::<T>::from_value(value).map(|values| values.into_iter().collect())
+            }
+        }
+    };
 }
 
-impl<T: FromMeta> FromMeta for Rc<T> {
-    fn from_none() -> Option<Self> {
-        T::from_none().map(Rc::new)
+from_meta_seq!(VecDeque<T: FromMeta>);
+from_meta_seq!(LinkedList<T: FromMeta>);
+from_meta_seq!(BinaryHeap<T: FromMeta, Ord>);
+
+/// Parsing a range with both ends, e.g. `example = 1..10` or `example = "1..10"`.
+impl<T: FromMeta> FromMeta for Range<T> {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        let (start, end) = range_bounds(expr, false)?;
+        Ok(start..end)
     }
 
-    fn from_meta(item: &Meta) -> Result<Self> {
-        FromMeta::from_meta(item).map(Rc::new)
+    fn from_string(value: &str) -> Result<Self> {
+        Self::from_expr(&parse_range_str(value)?)
     }
 }
 
-impl<T: FromMeta> FromMeta for Arc<T> {
-    fn from_none() -> Option<Self> {
-        T::from_none().map(Arc::new)
+/// Parsing an inclusive range with both ends, e.g. `example = 1..=10` or `example = "1..=10"`.
+impl<T: FromMeta> FromMeta for RangeInclusive<T> {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        let (start, end) = range_bounds(expr, true)?;
+        Ok(start..=end)
     }
 
-    fn from_meta(item: &Meta) -> Result<Self> {
+    fn from_string(value: &str) -> Result<Self> {
+        Self::from_expr(&parse_range_str(value)?)
+    }
+}
+
+fn parse_range_str(value: &str) -> Result<Expr> {
+    syn::parse_str::<syn::ExprRange>(value)
+        .map(Expr::Range)
+        .map_err(|_| Error::unknown_value(value))
+}
+
+/// Parse both ends of a range expression, which must use `..=` if `inclusive` is set
+/// and `..` otherwise. String literals are parsed as ranges.
+fn range_bounds<T: FromMeta>(expr: &Expr, inclusive: bool) -> Result<(T, T)> {
+    let range = match expr {
+        Expr::Range(range) => range,
+        Expr::Lit(lit) => {
+            return match &lit.lit {
+                Lit::Str(s) => range_bounds(&parse_range_str(&s.value())?, inclusive),
+                _ => Err(Error::unexpected_lit_type(&lit.lit)),
+            }
+            .map_err(|e| e.with_span(expr))
+        }
+        Expr::Group(group) => return range_bounds(&group.expr, inclusive),
+        _ => return Err(Error::unexpected_expr_type(expr).with_span(expr)),
+    };
+
+    let expected = if inclusive {
+        "`start..=end`"
+    } else {
+        "`start..end`"
+    };
+    let (start, end) = match (&range.start, &range.end) {
+        (Some(start), Some(end))
+            if inclusive == matches!(range.limits, syn::RangeLimits::Closed(_)) =>
+        {
+            (start, end)
+        }
+        _ => {
+            return Err(
+                Error::custom(format!("Expected a range written as {}", expected)).with_span(expr),
+            )
+        }
+    };
+
+    let mut errors = Error::accumulator();
+    let start = errors.handle(T::from_expr(start));
+    let end = errors.handle(T::from_expr(end));
+    errors.finish()?;
+    // Both ends were parsed successfully, or `finish` would have returned an error.
+    Ok((start.unwrap(), end.unwrap()))
+}
+
+/// Generate an impl of `FromMeta` for a tuple whose items are parsed positionally,
+/// from either a list, e.g. `example(1, "a")`, or a tuple, e.g. `example = (1, "a")`.
+macro_rules! from_meta_tuple {
+    ($len:literal; $($name:ident $idx:tt),+) => {
+        impl<$($name: FromMeta),+> FromMeta for ($($name,)+) {
+            fn from_list(items: &[NestedMeta]) -> Result<Self> {
+                check_item_count(items.len(), $len)?;
+                let mut errors = Error::accumulator();
+                let values = ($(errors.handle($name::from_nested_meta(&items[$idx])),)+);
+                errors.finish()?;
+                // Every item was parsed successfully, or `finish` would have returned an error.
+                Ok(($(values.$idx.unwrap(),)+))
+            }
+
+            fn from_expr(expr: &Expr) -> Result<Self> {
+                if let Expr::Tuple(tuple) = expr {
+                    check_item_count(tuple.elems.len(), $len).map_err(|e| e.with_span(expr))?;
+                    let mut errors = Error::accumulator();
+                    let values = ($(errors.handle($name::from_expr(&tuple.elems[$idx])),)+);
+                    errors.finish()?;
+                    Ok(($(values.$idx.unwrap(),)+))
+                } else {
+                    Err(Error::unexpected_expr_type(expr).with_span(expr))
+                }
+            }
+        }
+    };
+}
+
+from_meta_tuple!(2; A 0, B 1);
+from_meta_tuple!(3; A 0, B 1, C 2);
+
+/// Parsing a fixed number of values with the same formats as `Vec<T>`, e.g. `example(1, 2, 3, 4)`
+/// or `example = [1, 2, 3, 4]`. Any other number of items is an error.
+impl<T: FromMeta, const N: usize> FromMeta for [T; N] {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        check_item_count(items.len(), N)?;
+        Vec::<T>::from_list(items).and_then(vec_into_array)
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        Vec::<T>::from_expr(expr)
+            .and_then(vec_into_array)
+            .map_err(|e| e.with_span(expr))
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        Vec::<T>::from_value(value)
+            .and_then(vec_into_array)
+            .map_err(|e| e.with_span(value))
+    }
+}
+
+fn vec_into_array<T, const N: usize>(values: Vec<T>) -> Result<[T; N]> {
+    <[T; N]>::try_from(values).map_err(|values| {
+        check_item_count(values.len(), N).expect_err("Conversion only fails on a length mismatch")
+    })
+}
+
+/// Check that a fixed-length list or tuple has exactly `expected` items.
+fn check_item_count(len: usize, expected: usize) -> Result<()> {
+    if len < expected {
+        Err(Error::too_few_items(expected))
+    } else if len > expected {
+        Err(Error::too_many_items(expected))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parsing a set of values from a list or an array, like `Vec<T>`. Values which
+/// appear more than once are reported as errors rather than silently dropped.
+impl<T: FromMeta + Hash + Eq, S: BuildHasher + Default> FromMeta for HashSet<T, S> {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        collect_set(
+            items.iter().map(|item| (item, T::from_nested_meta(item))),
+            HashSet::insert,
+        )
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Array(array) => collect_set(
+                array.elems.iter().map(|elem| (elem, T::from_expr(elem))),
+                HashSet::insert,
+            ),
+            _ => Vec::<T>::from_expr(expr).and_then(|values| {
+                collect_set(values.into_iter().map(|v| (expr, Ok(v))), HashSet::insert)
+            }),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+/// Parsing a set of values from a list or an array; see `HashSet<T>`.
+impl<T: FromMeta + Ord> FromMeta for BTreeSet<T> {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        collect_set(
+            items.iter().map(|item| (item, T::from_nested_meta(item))),
+            BTreeSet::insert,
+        )
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Array(array) => collect_set(
+                array.elems.iter().map(|elem| (elem, T::from_expr(elem))),
+                BTreeSet::insert,
+            ),
+            _ => Vec::<T>::from_expr(expr).and_then(|values| {
+                collect_set(values.into_iter().map(|v| (expr, Ok(v))), BTreeSet::insert)
+            }),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+/// Insert each parsed value into a new set, accumulating parse errors and an error
+/// spanned to the source of each value that `insert` reports as already present.
+fn collect_set<'a, S, T, I, E>(items: I, insert: fn(&mut S, T) -> bool) -> Result<S>
+where
+    S: Default,
+    I: IntoIterator<Item = (&'a E, Result<T>)>,
+    E: ToTokens + 'a,
+{
+    let mut errors = Error::accumulator();
+    let mut set = S::default();
+    for (source, value) in items {
+        if let Some(value) = errors.handle(value) {
+            if !insert(&mut set, value) {
+                errors.push(
+                    Error::custom(format!("Duplicate value `{}`", source.to_token_stream()))
+                        .with_span(source),
+                );
+            }
+        }
+    }
+
+    errors.finish_with(set)
+}
+
+/// Parsing a byte string or an array of bytes; see `Vec<T>`.
+impl FromMeta for Cow<'static, [u8]> {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        Vec::<u8>::from_expr(expr).map(Cow::Owned)
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        Vec::<u8>::from_value(value).map(Cow::Owned)
+    }
+}
+
+/// Keeps whatever literal was written, so it can be spliced back into generated code.
+/// A negated number, such as `-4`, is kept as a single negative literal.
+impl FromMeta for syn::Lit {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Lit(lit) => Self::from_value(&lit.lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            Expr::Unary(_) => Number::from_expr(expr).map(|number| match number {
+                Number::Int(int) => Lit::Int(int),
+                Number::Float(float) => Lit::Float(float),
+            }),
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        Ok(value.clone())
+    }
+}
+
+/// Generate an impl of `FromMeta` for one kind of literal, which keeps the token as written,
+/// including its span and suffix. Negated numbers are accepted the same way as for `syn::Lit`.
+macro_rules! from_meta_lit {
+    ($impl_ty:path, $lit_variant:path) => {
+        impl FromMeta for $impl_ty {
+            fn from_expr(expr: &Expr) -> Result<Self> {
+                syn::Lit::from_expr(expr).and_then(|lit| Self::from_value(&lit))
+            }
+
+            fn from_value(value: &Lit) -> Result<Self> {
+                if let $lit_variant(ref value) = *value {
+                    Ok(value.clone())
+                } else {
+                    Err(Error::unexpected_lit_type(value))
+                }
+            }
+        }
+    };
+}
+
+from_meta_lit!(syn::LitInt, Lit::Int);
+from_meta_lit!(syn::LitFloat, Lit::Float);
+from_meta_lit!(syn::LitStr, Lit::Str);
+from_meta_lit!(syn::LitByte, Lit::Byte);
+from_meta_lit!(syn::LitByteStr, Lit::ByteStr);
+from_meta_lit!(syn::LitChar, Lit::Char);
+from_meta_lit!(syn::LitBool, Lit::Bool);
+from_meta_lit!(proc_macro2::Literal, Lit::Verbatim);
+
+/// Captures a value as unparsed tokens, so it can be interpreted later or forwarded verbatim:
+///
+/// * `example(a, b = 1)` produces the tokens inside the parentheses, `a, b = 1`.
+/// * `example = x + 1` produces the tokens of the value, `x + 1`.
+/// * `example` produces an empty token stream.
+impl FromMeta for proc_macro2::TokenStream {
+    fn from_nested_meta(item: &NestedMeta) -> Result<Self> {
+        match item {
+            NestedMeta::Lit(lit) => Ok(lit.to_token_stream()),
+            NestedMeta::Meta(meta) => Self::from_meta(meta),
+        }
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        Ok(match item {
+            Meta::Path(_) => proc_macro2::TokenStream::new(),
+            Meta::List(list) => list.tokens.clone(),
+            Meta::NameValue(nv) => nv.value.to_token_stream(),
+        })
+    }
+}
+
+impl FromMeta for syn::Meta {
+    fn from_meta(value: &syn::Meta) -> Result<Self> {
+        Ok(value.clone())
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        Err(Error::unsupported_format("literal").with_span(value))
+    }
+}
+
+impl FromMeta for NestedMeta {
+    fn from_nested_meta(item: &NestedMeta) -> Result<Self> {
+        Ok(item.clone())
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        Ok(NestedMeta::Meta(item.clone()))
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        Ok(NestedMeta::Lit(value.clone()))
+    }
+}
+
+impl FromMeta for Vec<syn::WherePredicate> {
+    fn from_string(value: &str) -> Result<Self> {
+        syn::WhereClause::from_string(&format!("where {}", value))
+            .map(|c| c.predicates.into_iter().collect())
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        if let syn::Lit::Str(s) = value {
+            syn::WhereClause::from_value(&syn::Lit::Str(syn::LitStr::new(
+                &format!("where {}", s.value()),
+                value.span(),
+            )))
+            .map(|c| c.predicates.into_iter().collect())
+        } else {
+            Err(Error::unexpected_lit_type(value))
+        }
+    }
+}
+
+impl FromMeta for ident_case::RenameRule {
+    fn from_string(value: &str) -> Result<Self> {
+        value.parse().map_err(|_| Error::unknown_value(value))
+    }
+}
+
+impl<T: FromMeta> FromMeta for Option<T> {
+    fn from_none() -> Option<Self> {
+        Some(None)
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        FromMeta::from_meta(item).map(Some)
+    }
+}
+
+impl<T: FromMeta> FromMeta for Box<T> {
+    fn from_none() -> Option<Self> {
+        T::from_none().map(Box::new)
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        FromMeta::from_meta(item).map(Box::new)
+    }
+}
+
+impl<T: FromMeta> FromMeta for Result<T> {
+    fn from_none() -> Option<Self> {
+        T::from_none().map(Ok)
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        Ok(FromMeta::from_meta(item))
+    }
+}
+
+/// Parses the meta-item, and in case of error preserves a copy of the input for
+/// later analysis.
+impl<T: FromMeta> FromMeta for ::std::result::Result<T, Meta> {
+    fn from_meta(item: &Meta) -> Result<Self> {
+        T::from_meta(item)
+            .map(Ok)
+            .or_else(|_| Ok(Err(item.clone())))
+    }
+}
+
+impl<T: FromMeta> FromMeta for Rc<T> {
+    fn from_none() -> Option<Self> {
+        T::from_none().map(Rc::new)
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        FromMeta::from_meta(item).map(Rc::new)
+    }
+}
+
+impl<T: FromMeta> FromMeta for Arc<T> {
+    fn from_none() -> Option<Self> {
+        T::from_none().map(Arc::new)
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
         FromMeta::from_meta(item).map(Arc::new)
     }
 }
@@ -614,6 +1341,36 @@ impl<T: FromMeta> FromMeta for RefCell<T> {
     }
 }
 
+impl<T: FromMeta> FromMeta for Cell<T> {
+    fn from_none() -> Option<Self> {
+        T::from_none().map(Cell::new)
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        FromMeta::from_meta(item).map(Cell::new)
+    }
+}
+
+impl<T: FromMeta> FromMeta for Mutex<T> {
+    fn from_none() -> Option<Self> {
+        T::from_none().map(Mutex::new)
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        FromMeta::from_meta(item).map(Mutex::new)
+    }
+}
+
+impl<T: FromMeta> FromMeta for RwLock<T> {
+    fn from_none() -> Option<Self> {
+        T::from_none().map(RwLock::new)
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        FromMeta::from_meta(item).map(RwLock::new)
+    }
+}
+
 /// Trait to convert from a path into an owned key for a map.
 trait KeyFromPath: Sized {
     fn from_path(path: &syn::Path) -> Result<Self>;
@@ -657,79 +1414,124 @@ impl KeyFromPath for syn::Ident {
     }
 }
 
+impl KeyFromPath for IdentString {
+    fn from_path(path: &syn::Path) -> Result<Self> {
+        syn::Ident::from_path(path).map(IdentString::new)
+    }
+
+    fn to_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+}
+
+/// Parse each nested meta item into a key from its path and a value from its contents,
+/// in the order they were written.
+fn map_entries<K, V>(nested: &[NestedMeta]) -> Result<Vec<(K, V)>>
+where
+    K: KeyFromPath + Hash + Eq + Clone,
+    V: FromMeta,
+{
+    // Convert the nested meta items into a sequence of (path, value result) result tuples.
+    // An outer Err means no (key, value) structured could be found, while an Err in the
+    // second position of the tuple means that value was rejected by FromMeta.
+    //
+    // We defer key conversion into K so that we don't lose span information in the case
+    // of String keys; we'll need it for good duplicate key errors later.
+    let pairs = nested
+        .iter()
+        .map(|item| -> Result<(&syn::Path, Result<V>)> {
+            match *item {
+                NestedMeta::Meta(ref inner) => {
+                    let path = inner.path();
+                    Ok((
+                        path,
+                        FromMeta::from_meta(inner).map_err(|e| e.at_path(path)),
+                    ))
+                }
+                NestedMeta::Lit(_) => Err(Error::unsupported_format("expression")),
+            }
+        });
+
+    let mut errors = Error::accumulator();
+    // We need to track seen keys separately from the entries, since a seen key with an
+    // Err value won't go into the entries but should trigger a duplicate field error.
+    //
+    // This is a set of K rather than Path to avoid the possibility that a key type
+    // parses two paths of different values to the same key value.
+    let mut seen_keys = HashSet::with_capacity(nested.len());
+
+    // The entries to return in the Ok case. Its size will always be exactly nested.len(),
+    // since otherwise ≥1 field had a problem and the entire list is dropped immediately
+    // when the function returns `Err`.
+    let mut entries = Vec::with_capacity(nested.len());
+
+    for item in pairs {
+        if let Some((path, value)) = errors.handle(item) {
+            let key: K = match KeyFromPath::from_path(path) {
+                Ok(k) => k,
+                Err(e) => {
+                    errors.push(e);
+
+                    // Surface value errors even under invalid keys
+                    errors.handle(value);
+
+                    continue;
+                }
+            };
+
+            let already_seen = seen_keys.contains(&key);
+
+            if already_seen {
+                errors.push(Error::duplicate_field(&key.to_display()).with_span(path));
+            }
+
+            match value {
+                Ok(_) if already_seen => {}
+                Ok(val) => {
+                    entries.push((key.clone(), val));
+                }
+                Err(e) => {
+                    errors.push(e);
+                }
+            }
+
+            seen_keys.insert(key);
+        }
+    }
+
+    errors.finish_with(entries)
+}
+
 macro_rules! hash_map {
     ($key:ty) => {
         impl<V: FromMeta, S: BuildHasher + Default> FromMeta for HashMap<$key, V, S> {
             fn from_list(nested: &[NestedMeta]) -> Result<Self> {
-                // Convert the nested meta items into a sequence of (path, value result) result tuples.
-                // An outer Err means no (key, value) structured could be found, while an Err in the
-                // second position of the tuple means that value was rejected by FromMeta.
-                //
-                // We defer key conversion into $key so that we don't lose span information in the case
-                // of String keys; we'll need it for good duplicate key errors later.
-                let pairs = nested
-                    .iter()
-                    .map(|item| -> Result<(&syn::Path, Result<V>)> {
-                        match *item {
-                            NestedMeta::Meta(ref inner) => {
-                                let path = inner.path();
-                                Ok((
-                                    path,
-                                    FromMeta::from_meta(inner).map_err(|e| e.at_path(&path)),
-                                ))
-                            }
-                            NestedMeta::Lit(_) => Err(Error::unsupported_format("expression")),
-                        }
-                    });
+                map_entries::<$key, V>(nested).map(|entries| entries.into_iter().collect())
+            }
+        }
+    };
+}
 
-                let mut errors = Error::accumulator();
-                // We need to track seen keys separately from the final map, since a seen key with an
-                // Err value won't go into the final map but should trigger a duplicate field error.
-                //
-                // This is a set of $key rather than Path to avoid the possibility that a key type
-                // parses two paths of different values to the same key value.
-                let mut seen_keys = HashSet::with_capacity(nested.len());
-
-                // The map to return in the Ok case. Its size will always be exactly nested.len(),
-                // since otherwise ≥1 field had a problem and the entire map is dropped immediately
-                // when the function returns `Err`.
-                let mut map = HashMap::with_capacity_and_hasher(nested.len(), Default::default());
-
-                for item in pairs {
-                    if let Some((path, value)) = errors.handle(item) {
-                        let key: $key = match KeyFromPath::from_path(path) {
-                            Ok(k) => k,
-                            Err(e) => {
-                                errors.push(e);
-
-                                // Surface value errors even under invalid keys
-                                errors.handle(value);
-
-                                continue;
-                            }
-                        };
-
-                        let already_seen = seen_keys.contains(&key);
-
-                        if already_seen {
-                            errors.push(Error::duplicate_field(&key.to_display()).with_span(path));
-                        }
-
-                        match value {
-                            Ok(_) if already_seen => {}
-                            Ok(val) => {
-                                map.insert(key.clone(), val);
-                            }
-                            Err(e) => {
-                                errors.push(e);
-                            }
-                        }
-
-                        seen_keys.insert(key);
-                    }
-                }
+macro_rules! btree_map {
+    ($key:ty) => {
+        /// Parsing a map with the same formats and duplicate-key errors as `HashMap`,
+        /// whose keys iterate in sorted order.
+        impl<V: FromMeta> FromMeta for BTreeMap<$key, V> {
+            fn from_list(nested: &[NestedMeta]) -> Result<Self> {
+                map_entries::<$key, V>(nested).map(|entries| entries.into_iter().collect())
+            }
+        }
+    };
+}
 
-                errors.finish_with(map)
+#[cfg(feature = "indexmap")]
+macro_rules! index_map {
+    ($key:ty) => {
+        /// Parsing a map with the same formats and duplicate-key errors as `HashMap`,
+        /// whose entries iterate in the order they were written.
+        impl<V: FromMeta, S: BuildHasher + Default> FromMeta for indexmap::IndexMap<$key, V, S> {
+            fn from_list(nested: &[NestedMeta]) -> Result<Self> {
+                map_entries::<$key, V>(nested).map(|entries| entries.into_iter().collect())
             }
         }
     };
@@ -740,15 +1542,34 @@ macro_rules! hash_map {
 hash_map!(String);
 hash_map!(syn::Ident);
 hash_map!(syn::Path);
+hash_map!(IdentString);
+
+// `syn::Path` doesn't implement `Ord`, so it can't be the key of a `BTreeMap`; use
+// `HashMap` or `IndexMap` for path keys.
+btree_map!(String);
+btree_map!(syn::Ident);
+btree_map!(IdentString);
+
+#[cfg(feature = "indexmap")]
+index_map!(String);
+#[cfg(feature = "indexmap")]
+index_map!(syn::Ident);
+#[cfg(feature = "indexmap")]
+index_map!(syn::Path);
+#[cfg(feature = "indexmap")]
+index_map!(IdentString);
 
 /// Tests for `FromMeta` implementations. Wherever the word `ignore` appears in test input,
 /// it should not be considered by the parsing.
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
     use proc_macro2::TokenStream;
-    use quote::quote;
+    use quote::{quote, ToTokens};
     use syn::parse_quote;
 
+    use crate::ast::NestedMeta;
     use crate::{Error, FromMeta, Result};
 
     /// parse a string as a syn::Meta instance.
@@ -778,35 +1599,233 @@ mod tests {
         assert_eq!(fm::<bool>(quote!(ignore = true)), true);
         assert_eq!(fm::<bool>(quote!(ignore = false)), false);
 
-        // string literals
-        assert_eq!(fm::<bool>(quote!(ignore = "true")), true);
-        assert_eq!(fm::<bool>(quote!(ignore = "false")), false);
+        // string literals
+        assert_eq!(fm::<bool>(quote!(ignore = "true")), true);
+        assert_eq!(fm::<bool>(quote!(ignore = "false")), false);
+    }
+
+    #[test]
+    fn char_succeeds() {
+        // char literal
+        assert_eq!(fm::<char>(quote!(ignore = '😬')), '😬');
+
+        // string literal
+        assert_eq!(fm::<char>(quote!(ignore = "😬")), '😬');
+    }
+
+    #[test]
+    fn char_rejects_other_values() {
+        let err = char::from_meta(&pm(quote!(ignore = "ab")).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown literal value `ab`");
+
+        char::from_meta(&pm(quote!(ignore = "")).unwrap()).unwrap_err();
+        char::from_meta(&pm(quote!(ignore = 1)).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn string_succeeds() {
+        // cooked form
+        assert_eq!(&fm::<String>(quote!(ignore = "world")), "world");
+
+        // raw form
+        assert_eq!(&fm::<String>(quote!(ignore = r#"world"#)), "world");
+    }
+
+    #[test]
+    fn str_pointers_succeed() {
+        assert_eq!(&*fm::<Cow<'static, str>>(quote!(ignore = "world")), "world");
+        assert_eq!(&*fm::<Box<str>>(quote!(ignore = "world")), "world");
+        assert_eq!(&*fm::<std::rc::Rc<str>>(quote!(ignore = "world")), "world");
+        assert_eq!(
+            &*fm::<std::sync::Arc<str>>(quote!(ignore = r#"world"#)),
+            "world"
+        );
+
+        Box::<str>::from_meta(&pm(quote!(ignore = 5)).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn pathbuf_succeeds() {
+        assert_eq!(
+            fm::<std::path::PathBuf>(quote!(ignore = r#"C:\"#)),
+            std::path::PathBuf::from(r#"C:\"#)
+        );
+    }
+
+    #[test]
+    fn duration_succeeds() {
+        use std::time::Duration;
+
+        assert_eq!(
+            fm::<Duration>(quote!(ignore = 1500)),
+            Duration::from_millis(1500)
+        );
+        assert_eq!(fm::<Duration>(quote!(ignore = 5s)), Duration::from_secs(5));
+        assert_eq!(
+            fm::<Duration>(quote!(ignore = "300ms")),
+            Duration::from_millis(300)
+        );
+        assert_eq!(
+            fm::<Duration>(quote!(ignore = "2 min")),
+            Duration::from_secs(120)
+        );
+        assert_eq!(
+            fm::<Duration>(quote!(ignore = "1h")),
+            Duration::from_secs(3600)
+        );
+        assert_eq!(
+            fm::<Duration>(quote!(ignore = "10")),
+            Duration::from_millis(10)
+        );
+    }
+
+    #[test]
+    fn duration_rejects_other_values() {
+        use std::time::Duration;
+
+        for tokens in [
+            quote!(ignore = 5days),
+            quote!(ignore = "5 fortnights"),
+            quote!(ignore = "s"),
+            quote!(ignore = "-5s"),
+            quote!(ignore = 1.5),
+        ] {
+            Duration::from_meta(&pm(tokens).unwrap()).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn net_addrs() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+        assert_eq!(
+            fm::<IpAddr>(quote!(ignore = "::1")),
+            IpAddr::V6(Ipv6Addr::LOCALHOST)
+        );
+        assert_eq!(
+            fm::<Ipv4Addr>(quote!(ignore = "127.0.0.1")),
+            Ipv4Addr::LOCALHOST
+        );
+        assert_eq!(
+            fm::<SocketAddr>(quote!(ignore = "0.0.0.0:8080")),
+            SocketAddr::from(([0, 0, 0, 0], 8080))
+        );
+
+        let err = Ipv6Addr::from_meta(&pm(quote!(ignore = "127.0.0.1")).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "`127.0.0.1` is not an IPv6 address");
+        SocketAddr::from_meta(&pm(quote!(ignore = "127.0.0.1")).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn uuid() {
+        assert_eq!(
+            fm::<uuid::Uuid>(quote!(ignore = "67e55044-10b1-426f-9247-bb680e5fe0c8")),
+            uuid::Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8)
+        );
+
+        let err = uuid::Uuid::from_meta(&pm(quote!(ignore = "67e55044")).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "`67e55044` is not a UUID");
+        uuid::Uuid::from_meta(&pm(quote!(ignore = 5)).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "semver")]
+    fn semver() {
+        use semver::{Version, VersionReq};
+
+        assert_eq!(
+            fm::<Version>(quote!(ignore = "1.2.0-beta.1")),
+            Version::parse("1.2.0-beta.1").unwrap()
+        );
+        let req = fm::<VersionReq>(quote!(ignore = ">=1, <2"));
+        assert!(req.matches(&Version::new(1, 9, 0)));
+        assert!(!req.matches(&Version::new(2, 0, 0)));
+
+        let err = Version::from_meta(&pm(quote!(ignore = "1.2")).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "`1.2` is not a semantic version");
+        VersionReq::from_meta(&pm(quote!(ignore = "~>1")).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "url")]
+    fn url() {
+        let url = fm::<url::Url>(quote!(ignore = "https://example.com:8080/api?v=1"));
+        assert_eq!(url.host_str(), Some("example.com"));
+        assert_eq!(url.port(), Some(8080));
+        assert_eq!(url.path(), "/api");
+
+        let err = url::Url::from_meta(&pm(quote!(ignore = "/api")).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`/api` is not a valid URL: relative URL without a base"
+        );
+        assert!(err.has_span());
+        url::Url::from_meta(&pm(quote!(ignore = 5)).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn regex() {
+        let re = fm::<regex::Regex>(quote!(ignore = "^[a-z]+$"));
+        assert_eq!(re.as_str(), "^[a-z]+$");
+        assert!(re.is_match("abc"));
+        assert!(!re.is_match("ABC"));
+
+        let err = regex::Regex::from_meta(&pm(quote!(ignore = "[a-")).unwrap()).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid regular expression"));
+        assert!(err.has_span());
     }
 
     #[test]
-    fn char_succeeds() {
-        // char literal
-        assert_eq!(fm::<char>(quote!(ignore = '😬')), '😬');
+    #[cfg(feature = "chrono")]
+    fn chrono() {
+        use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
 
-        // string literal
-        assert_eq!(fm::<char>(quote!(ignore = "😬")), '😬');
+        assert_eq!(
+            fm::<NaiveDate>(quote!(ignore = "2023-06-01")),
+            NaiveDate::from_ymd_opt(2023, 6, 1).unwrap()
+        );
+        assert_eq!(
+            fm::<DateTime<FixedOffset>>(quote!(ignore = "2023-06-01T12:00:00+02:00")),
+            FixedOffset::east_opt(2 * 3600)
+                .unwrap()
+                .with_ymd_and_hms(2023, 6, 1, 12, 0, 0)
+                .unwrap()
+        );
+
+        let err = NaiveDate::from_meta(&pm(quote!(ignore = "2023-02-30")).unwrap()).unwrap_err();
+        assert!(err.to_string().starts_with("`2023-02-30` is not a date"));
+        assert!(err.has_span());
+        DateTime::<FixedOffset>::from_meta(&pm(quote!(ignore = "2023-06-01")).unwrap())
+            .unwrap_err();
     }
 
     #[test]
-    fn string_succeeds() {
-        // cooked form
-        assert_eq!(&fm::<String>(quote!(ignore = "world")), "world");
+    #[cfg(feature = "time")]
+    fn time() {
+        use time::OffsetDateTime;
 
-        // raw form
-        assert_eq!(&fm::<String>(quote!(ignore = r#"world"#)), "world");
+        assert_eq!(
+            fm::<OffsetDateTime>(quote!(ignore = "2023-06-01T12:00:00Z")).unix_timestamp(),
+            1_685_620_800
+        );
+
+        let err =
+            OffsetDateTime::from_meta(&pm(quote!(ignore = "2023-06-01")).unwrap()).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("`2023-06-01` is not an RFC 3339 date and time"));
     }
 
     #[test]
-    fn pathbuf_succeeds() {
+    fn boxed_path_succeeds() {
         assert_eq!(
-            fm::<std::path::PathBuf>(quote!(ignore = r#"C:\"#)),
-            std::path::PathBuf::from(r#"C:\"#)
+            &*fm::<Box<std::path::Path>>(quote!(ignore = "queries/a.sql")),
+            std::path::Path::new("queries/a.sql")
         );
+
+        std::path::PathBuf::from_meta(&pm(quote!(ignore = 5)).unwrap()).unwrap_err();
     }
 
     #[test]
@@ -817,6 +1836,25 @@ mod tests {
         assert_eq!(fm::<f64>(quote!(ignore = "1.4e10")), 1.4e10);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)] // we want exact equality
+    fn negative_without_quotes() {
+        assert_eq!(fm::<i8>(quote!(ignore = -4)), -4i8);
+        assert_eq!(fm::<i64>(quote!(ignore = -9000i64)), -9000i64);
+        assert_eq!(fm::<i8>(quote!(ignore = -128)), i8::MIN);
+        assert_eq!(fm::<f32>(quote!(ignore = -0.5)), -0.5f32);
+        assert_eq!(fm::<f64>(quote!(ignore = -3)), -3.0f64);
+        assert_eq!(
+            fm::<std::num::NonZeroI32>(quote!(ignore = -1)),
+            std::num::NonZeroI32::new(-1).unwrap()
+        );
+
+        u8::from_meta(&pm(quote!(ignore = -4)).unwrap()).unwrap_err();
+        i8::from_meta(&pm(quote!(ignore = -129)).unwrap()).unwrap_err();
+        i8::from_meta(&pm(quote!(ignore = !4)).unwrap()).unwrap_err();
+        i8::from_meta(&pm(quote!(ignore = --4)).unwrap()).unwrap_err();
+    }
+
     #[test]
     fn int_without_quotes() {
         assert_eq!(fm::<u8>(quote!(ignore = 2)), 2u8);
@@ -827,6 +1865,18 @@ mod tests {
         assert_eq!(fm::<u32>(quote!(ignore = 5000i32)), 5000u32);
     }
 
+    /// Out-of-range literals are reported as spanned errors, rather than panicking.
+    #[test]
+    fn int_out_of_range() {
+        let err = u8::from_meta(&pm(quote!(ignore = 256)).unwrap()).unwrap_err();
+        assert!(err.has_span());
+        assert!(u128::from_meta(
+            &pm(quote!(ignore = 340282366920938463463374607431768211456)).unwrap()
+        )
+        .is_err());
+        assert!(u16::from_meta(&pm(quote!(ignore = "65536")).unwrap()).is_err());
+    }
+
     #[test]
     #[allow(clippy::float_cmp)] // we want exact equality
     fn float_without_quotes() {
@@ -835,6 +1885,57 @@ mod tests {
         assert_eq!(fm::<f64>(quote!(ignore = 1.4e10)), 1.4e10f64);
     }
 
+    #[test]
+    fn nonzero() {
+        use std::num::{NonZeroI32, NonZeroU8};
+
+        assert_eq!(
+            fm::<NonZeroU8>(quote!(ignore = 4)),
+            NonZeroU8::new(4).unwrap()
+        );
+        assert_eq!(
+            fm::<NonZeroI32>(quote!(ignore = "-4")),
+            NonZeroI32::new(-4).unwrap()
+        );
+
+        let err = NonZeroU8::from_meta(&pm(quote!(ignore = 0)).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Expected a non-zero value");
+        NonZeroI32::from_meta(&pm(quote!(ignore = "0")).unwrap()).unwrap_err();
+        NonZeroU8::from_meta(&pm(quote!(ignore = 256)).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // we want exact equality
+    fn float_from_int() {
+        assert_eq!(fm::<f32>(quote!(ignore = 2)), 2.0f32);
+        assert_eq!(fm::<f64>(quote!(ignore = 15u8)), 15.0f64);
+        assert_eq!(fm::<f64>(quote!(ignore = "3")), 3.0f64);
+    }
+
+    #[test]
+    fn custom_from_int() {
+        /// Accepts an integer with a `kb` or `mb` suffix.
+        #[derive(Debug, PartialEq)]
+        struct Bytes(u64);
+
+        impl FromMeta for Bytes {
+            fn from_int(value: &syn::LitInt) -> Result<Self> {
+                let n = value.base10_parse::<u64>()?;
+                match value.suffix() {
+                    "" => Ok(Bytes(n)),
+                    "kb" => Ok(Bytes(n * 1024)),
+                    "mb" => Ok(Bytes(n * 1024 * 1024)),
+                    other => Err(Error::unknown_value(other)),
+                }
+            }
+        }
+
+        assert_eq!(fm::<Bytes>(quote!(ignore = 4kb)), Bytes(4096));
+        assert_eq!(fm::<Bytes>(quote!(ignore = 12)), Bytes(12));
+        Bytes::from_meta(&pm(quote!(ignore = 4gb)).unwrap()).unwrap_err();
+        Bytes::from_meta(&pm(quote!(ignore = 4.0)).unwrap()).unwrap_err();
+    }
+
     #[test]
     fn meta_succeeds() {
         use syn::Meta;
@@ -922,6 +2023,27 @@ mod tests {
         err.unwrap_err();
     }
 
+    #[test]
+    fn hash_map_ident_string_keys() {
+        use std::collections::BTreeMap;
+        use std::collections::HashMap;
+
+        use crate::util::IdentString;
+
+        let map = fm::<HashMap<IdentString, bool>>(quote!(ignore(first, second = false)));
+        assert_eq!(map.len(), 2);
+        let (key, value) = map.iter().find(|(k, _)| k.as_str() == "second").unwrap();
+        assert_eq!(key.as_ident(), "second");
+        assert!(!value);
+
+        let err = BTreeMap::<IdentString, bool>::from_meta(
+            &pm(quote!(ignore(first, first = false))).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Duplicate field `first`");
+        HashMap::<IdentString, bool>::from_meta(&pm(quote!(ignore(a::b))).unwrap()).unwrap_err();
+    }
+
     #[test]
     fn hash_map_path_succeeds() {
         use std::collections::HashMap;
@@ -940,6 +2062,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn btree_map_succeeds() {
+        use std::collections::BTreeMap;
+
+        let map = fm::<BTreeMap<String, u8>>(quote!(ignore(zeta = 1, alpha = 2, mid = 3)));
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("alpha".to_string(), 2),
+                ("mid".to_string(), 3),
+                ("zeta".to_string(), 1)
+            ]
+        );
+
+        let err = BTreeMap::<syn::Ident, bool>::from_meta(
+            &pm(quote!(ignore(first, first = false, a::b))).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "indexmap")]
+    fn index_map_preserves_order() {
+        use indexmap::IndexMap;
+
+        let map = fm::<IndexMap<String, u8>>(quote!(ignore(zeta = 1, alpha = 2, mid = 3)));
+        assert_eq!(
+            map.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["zeta", "alpha", "mid"]
+        );
+
+        IndexMap::<syn::Ident, u8>::from_meta(&pm(quote!(ignore(a = 1, a = 2))).unwrap())
+            .unwrap_err();
+    }
+
+    #[test]
+    fn cell_and_lock_wrappers() {
+        use std::cell::Cell;
+        use std::sync::{Mutex, RwLock};
+
+        assert_eq!(fm::<Cell<u8>>(quote!(ignore = 5)).get(), 5);
+        assert_eq!(
+            *fm::<Mutex<String>>(quote!(ignore = "a")).lock().unwrap(),
+            "a"
+        );
+        assert!(*fm::<RwLock<bool>>(quote!(ignore)).read().unwrap());
+        assert!(<Mutex<Option<u8>>>::from_none()
+            .unwrap()
+            .into_inner()
+            .unwrap()
+            .is_none());
+    }
+
     /// Tests that fallible parsing will always produce an outer `Ok` (from `fm`),
     /// and will accurately preserve the inner contents.
     #[test]
@@ -957,6 +2133,161 @@ mod tests {
         fm::<syn::punctuated::Punctuated<syn::Expr, syn::token::Comma>>(quote!(ignore = "a, b, c"));
     }
 
+    #[test]
+    fn test_type() {
+        assert_eq!(
+            fm::<syn::Type>(quote!(ignore = "Arc<Mutex<T>>")),
+            parse_quote!(Arc<Mutex<T>>)
+        );
+        assert_eq!(
+            fm::<syn::Type>(quote!(ignore = String)),
+            parse_quote!(String)
+        );
+        assert_eq!(
+            fm::<syn::Type>(quote!(ignore = "&'a str")),
+            parse_quote!(&'a str)
+        );
+        assert_eq!(
+            fm::<syn::TypePath>(quote!(ignore = std::vec::Vec::<u8>)),
+            parse_quote!(std::vec::Vec::<u8>)
+        );
+
+        syn::Type::from_meta(&pm(quote!(ignore = 1 + 2)).unwrap()).unwrap_err();
+        syn::Type::from_meta(&pm(quote!(ignore = "not a type!")).unwrap()).unwrap_err();
+        syn::TypePath::from_meta(&pm(quote!(ignore = "&str")).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_visibility() {
+        assert_eq!(
+            fm::<syn::Visibility>(quote!(ignore = "pub(crate)")),
+            parse_quote!(pub(crate))
+        );
+        assert_eq!(
+            fm::<syn::Visibility>(quote!(ignore = "pub(in crate::a)")),
+            parse_quote!(pub(in crate::a))
+        );
+        assert_eq!(
+            fm::<syn::Visibility>(quote!(ignore = "")),
+            syn::Visibility::Inherited
+        );
+
+        syn::Visibility::from_meta(&pm(quote!(ignore = "public")).unwrap()).unwrap_err();
+        syn::Visibility::from_meta(&pm(quote!(ignore = "pub(crate")).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_lit_passthrough() {
+        let lits = [
+            quote!(ignore = "hello"),
+            quote!(ignore = 7u8),
+            quote!(ignore = true),
+            quote!(ignore = 'x'),
+            quote!(ignore = 1.5),
+        ];
+        for tokens in lits {
+            let expected = tokens.clone().into_iter().last().unwrap().to_string();
+            assert_eq!(
+                fm::<syn::Lit>(tokens).to_token_stream().to_string(),
+                expected
+            );
+        }
+
+        match fm::<syn::Lit>(quote!(ignore = -4i32)) {
+            syn::Lit::Int(int) => {
+                assert_eq!(int.base10_digits(), "-4");
+                assert_eq!(int.suffix(), "i32");
+            }
+            other => panic!("Expected an int literal, got {:?}", other),
+        }
+
+        syn::Lit::from_meta(&pm(quote!(ignore = a + b)).unwrap()).unwrap_err();
+        syn::Lit::from_meta(&pm(quote!(ignore = !true)).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_lit_wrappers() {
+        let int = fm::<syn::LitInt>(quote!(ignore = 0x10u16));
+        assert_eq!(int.suffix(), "u16");
+        assert_eq!(int.base10_parse::<u16>().unwrap(), 16);
+        assert_eq!(int.to_string(), "0x10u16");
+        assert_eq!(fm::<syn::LitInt>(quote!(ignore = -3)).base10_digits(), "-3");
+
+        assert_eq!(fm::<syn::LitStr>(quote!(ignore = "hi")).value(), "hi");
+        assert!(fm::<syn::LitBool>(quote!(ignore = true)).value);
+        assert_eq!(
+            fm::<syn::LitFloat>(quote!(ignore = -2.5f32)).base10_digits(),
+            "-2.5"
+        );
+
+        let err = syn::LitStr::from_meta(&pm(quote!(ignore = 5)).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected literal type `int`");
+        syn::LitBool::from_meta(&pm(quote!(ignore = !true)).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_block() {
+        let block = fm::<syn::Block>(quote!(ignore = "{ log::warn!(\"oops\"); return; }"));
+        assert_eq!(block.stmts.len(), 2);
+        let block = fm::<syn::Block>(quote!(
+            ignore = {
+                cleanup();
+            }
+        ));
+        assert_eq!(block.stmts.len(), 1);
+
+        syn::Block::from_meta(&pm(quote!(ignore = "return;")).unwrap()).unwrap_err();
+        syn::Block::from_meta(&pm(quote!(ignore = "{ let }")).unwrap()).unwrap_err();
+        syn::Block::from_meta(&pm(quote!(ignore = cleanup())).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_pat() {
+        assert!(matches!(
+            fm::<syn::Pat>(quote!(ignore = "None | Some(0)")),
+            syn::Pat::Or(_)
+        ));
+        assert!(matches!(
+            fm::<syn::Pat>(quote!(ignore = "Point { x, .. }")),
+            syn::Pat::Struct(_)
+        ));
+        assert!(matches!(
+            fm::<syn::Pat>(quote!(ignore = "1..=9")),
+            syn::Pat::Range(_)
+        ));
+
+        syn::Pat::from_meta(&pm(quote!(ignore = "Some(")).unwrap()).unwrap_err();
+        syn::Pat::from_meta(&pm(quote!(ignore = 5)).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_generics() {
+        let generics = fm::<syn::Generics>(quote!(ignore = "<T: Clone, 'a>"));
+        assert_eq!(generics.params.len(), 2);
+        assert_eq!(generics.type_params().next().unwrap().ident, "T");
+        assert_eq!(generics.lifetimes().next().unwrap().lifetime.ident, "a");
+
+        assert!(fm::<syn::Generics>(quote!(ignore = "")).params.is_empty());
+        syn::Generics::from_meta(&pm(quote!(ignore = "<T")).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_lifetime() {
+        assert_eq!(
+            fm::<syn::Lifetime>(quote!(ignore = "'de")),
+            syn::Lifetime::new("'de", proc_macro2::Span::call_site())
+        );
+        fm::<syn::Lifetime>(quote!(ignore = "'static"));
+
+        let err = syn::Lifetime::from_meta(&pm(quote!(ignore = "de")).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`de` is not a lifetime; expected a name with a leading apostrophe, such as `'de`"
+        );
+        syn::Lifetime::from_meta(&pm(quote!(ignore = "'a + 'b")).unwrap()).unwrap_err();
+        syn::Lifetime::from_meta(&pm(quote!(ignore = 5)).unwrap()).unwrap_err();
+    }
+
     #[test]
     fn test_expr_array() {
         fm::<syn::ExprArray>(quote!(ignore = "[0x1, 0x2]"));
@@ -970,6 +2301,26 @@ mod tests {
         fm::<syn::Expr>(quote!(ignore = "{ a_statement(); in_a_block }"));
     }
 
+    #[test]
+    fn test_expr_default_value() {
+        let expected: syn::Expr = parse_quote!(Vec::with_capacity(4));
+        assert_eq!(
+            fm::<syn::Expr>(quote!(ignore = "Vec::with_capacity(4)")),
+            expected
+        );
+        assert_eq!(
+            fm::<syn::Expr>(quote!(ignore = Vec::with_capacity(4))),
+            expected
+        );
+
+        let err =
+            syn::Expr::from_meta(&pm(quote!(ignore = "Vec::with_capacity(")).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown literal value `Vec::with_capacity(`"
+        );
+    }
+
     #[test]
     fn test_expr_without_quotes() {
         fm::<syn::Expr>(quote!(ignore = x + y));
@@ -996,11 +2347,40 @@ mod tests {
         fm::<syn::ExprPath>(quote!(ignore = example::<Test>));
     }
 
+    #[test]
+    fn test_expr_path_rejects_other_exprs() {
+        let err = syn::ExprPath::from_meta(&pm(quote!(ignore = f(x))).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected literal type `call`");
+        syn::ExprPath::from_meta(&pm(quote!(ignore = "a + b")).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_expr_closure() {
+        let closure = fm::<syn::ExprClosure>(quote!(ignore = |x| x > 0));
+        assert_eq!(closure.inputs.len(), 1);
+        fm::<syn::ExprClosure>(quote!(ignore = "|x: &u8| *x > 0"));
+        fm::<syn::ExprClosure>(quote!(ignore = move || {}));
+
+        syn::ExprClosure::from_meta(&pm(quote!(ignore = filter_fn)).unwrap()).unwrap_err();
+        syn::ExprClosure::from_meta(&pm(quote!(ignore = "x > 0")).unwrap()).unwrap_err();
+    }
+
     #[test]
     fn test_path_without_quotes() {
         fm::<syn::Path>(quote!(ignore = std::mem::replace));
         fm::<syn::Path>(quote!(ignore = x));
         fm::<syn::Path>(quote!(ignore = example::<Test>));
+        assert_eq!(
+            fm::<syn::Path>(quote!(ignore = my_crate::handle)),
+            parse_quote!(my_crate::handle)
+        );
+    }
+
+    #[test]
+    fn test_path_rejects_qualified_paths() {
+        syn::Path::from_meta(&pm(quote!(ignore = <T as Trait>::handle)).unwrap()).unwrap_err();
+        syn::Path::from_meta(&pm(quote!(ignore = my_crate::handle())).unwrap()).unwrap_err();
+        fm::<syn::ExprPath>(quote!(ignore = <T as Trait>::handle));
     }
 
     #[test]
@@ -1023,4 +2403,211 @@ mod tests {
             vec![0x50, 0xffffffff]
         );
     }
+
+    #[test]
+    fn test_vec() {
+        assert_eq!(
+            fm::<Vec<String>>(quote!(ignore("a", "b"))),
+            vec!["a".to_string(), "b".to_string()]
+        );
+        assert_eq!(fm::<Vec<u8>>(quote!(ignore(1, 2, 3))), vec![1, 2, 3]);
+        assert_eq!(
+            fm::<Vec<String>>(quote!(ignore = "[\"a\"]")),
+            vec!["a".to_string()]
+        );
+        Vec::<String>::from_meta(&pm(quote!(ignore = "a")).unwrap()).unwrap_err();
+        Vec::<u16>::from_meta(&pm(quote!(ignore = 7)).unwrap()).unwrap_err();
+        assert_eq!(
+            fm::<Vec<syn::Path>>(quote!(ignore = [a::b, c])),
+            vec![parse_quote!(a::b), parse_quote!(c)]
+        );
+
+        let err = Vec::<u8>::from_meta(&pm(quote!(ignore(1, "x", 256))).unwrap()).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn test_ranges() {
+        use std::ops::{Range, RangeInclusive};
+
+        assert_eq!(fm::<Range<usize>>(quote!(ignore = 1..10)), 1..10);
+        assert_eq!(fm::<Range<usize>>(quote!(ignore = "0..4")), 0..4);
+        assert_eq!(fm::<RangeInclusive<usize>>(quote!(ignore = 1..=10)), 1..=10);
+        assert_eq!(
+            fm::<RangeInclusive<u8>>(quote!(ignore = "0..=255")),
+            0..=255
+        );
+
+        for tokens in [
+            quote!(ignore = 1..),
+            quote!(ignore = ..4),
+            quote!(ignore = 1..=4),
+            quote!(ignore = "1-4"),
+            quote!(ignore = 5),
+        ] {
+            Range::<usize>::from_meta(&pm(tokens).unwrap()).unwrap_err();
+        }
+
+        let err =
+            RangeInclusive::<usize>::from_meta(&pm(quote!(ignore = 1..4)).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Expected a range written as `start..=end`");
+        let err = Range::<u8>::from_meta(&pm(quote!(ignore = "x..300")).unwrap()).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn test_tuples() {
+        assert_eq!(
+            fm::<(u8, String)>(quote!(ignore(1, "a"))),
+            (1, "a".to_string())
+        );
+        assert_eq!(
+            fm::<(i32, i32, bool)>(quote!(ignore = (4, 5, true))),
+            (4, 5, true)
+        );
+
+        let err = <(u8, u8)>::from_meta(&pm(quote!(ignore(1))).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Too few items: Expected at least 2");
+        let err = <(u8, u8)>::from_meta(&pm(quote!(ignore = (1, 2, 3))).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Too many items: Expected no more than 2");
+        let err = <(u8, u8, u8)>::from_meta(&pm(quote!(ignore("a", 2, 300))).unwrap()).unwrap_err();
+        assert_eq!(err.len(), 2);
+        <(u8, u8)>::from_meta(&pm(quote!(ignore = 1)).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_sequences() {
+        use std::collections::{BinaryHeap, LinkedList, VecDeque};
+
+        assert_eq!(
+            fm::<VecDeque<u8>>(quote!(ignore(1, 2))),
+            VecDeque::from(vec![1, 2])
+        );
+        assert_eq!(
+            fm::<LinkedList<String>>(quote!(ignore = ["a"])),
+            vec!["a".to_string()].into_iter().collect::<LinkedList<_>>()
+        );
+        LinkedList::<String>::from_meta(&pm(quote!(ignore = "a")).unwrap()).unwrap_err();
+        assert_eq!(
+            fm::<BinaryHeap<u8>>(quote!(ignore = [3, 9, 1])).into_sorted_vec(),
+            vec![1, 3, 9]
+        );
+        VecDeque::<u8>::from_meta(&pm(quote!(ignore(1, "x"))).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_arrays() {
+        assert_eq!(fm::<[u8; 4]>(quote!(ignore(1, 2, 3, 4))), [1, 2, 3, 4]);
+        assert_eq!(fm::<[u8; 2]>(quote!(ignore = [255, 0])), [255, 0]);
+        assert_eq!(fm::<[u8; 3]>(quote!(ignore = b"abc")), *b"abc");
+        assert_eq!(fm::<[String; 1]>(quote!(ignore("a"))), ["a".to_string()]);
+        <[String; 1]>::from_meta(&pm(quote!(ignore = "a")).unwrap()).unwrap_err();
+
+        let err = <[u8; 4]>::from_meta(&pm(quote!(ignore(1, 2, 3))).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Too few items: Expected at least 4");
+        let err = <[u8; 2]>::from_meta(&pm(quote!(ignore = [1, 2, 3])).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Too many items: Expected no more than 2");
+    }
+
+    #[test]
+    fn test_sets() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let set = fm::<HashSet<String>>(quote!(ignore("a", "b")));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains("b"));
+        assert_eq!(
+            fm::<BTreeSet<u8>>(quote!(ignore = [3, 1, 2]))
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        BTreeSet::<u8>::from_meta(&pm(quote!(ignore = 4)).unwrap()).unwrap_err();
+
+        let err =
+            HashSet::<String>::from_meta(&pm(quote!(ignore("a", "b", "a"))).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Duplicate value `\"a\"`");
+
+        let err = BTreeSet::<u8>::from_meta(&pm(quote!(ignore = [1, 1, 1])).unwrap()).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn test_byte_str() {
+        assert_eq!(
+            fm::<Vec<u8>>(quote!(ignore = b"\x7fELF")),
+            vec![0x7f, b'E', b'L', b'F']
+        );
+        assert_eq!(fm::<Vec<u16>>(quote!(ignore = b"ab")), vec![97, 98]);
+        assert_eq!(
+            &*fm::<Cow<'static, [u8]>>(quote!(ignore = br"\0")),
+            &[b'\\', b'0'][..]
+        );
+        assert_eq!(
+            &*fm::<Cow<'static, [u8]>>(quote!(ignore = [1, 2])),
+            &[1, 2][..]
+        );
+
+        String::from_meta(&pm(quote!(ignore = b"abc")).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_token_stream() {
+        assert_eq!(
+            fm::<TokenStream>(quote!(ignore(a, b = 1, c(d)))).to_string(),
+            quote!(a, b = 1, c(d)).to_string()
+        );
+        assert_eq!(
+            fm::<TokenStream>(quote!(ignore = x + 1)).to_string(),
+            quote!(x + 1).to_string()
+        );
+        assert!(fm::<TokenStream>(quote!(ignore)).is_empty());
+
+        let items = fm::<Vec<TokenStream>>(quote!(ignore(serde(rename = "a"), "lit")));
+        assert_eq!(items[0].to_string(), quote!(rename = "a").to_string());
+        assert_eq!(items[1].to_string(), quote!("lit").to_string());
+    }
+
+    #[test]
+    fn test_ident_list() {
+        assert_eq!(fm::<syn::Ident>(quote!(ignore(json))), "json");
+        assert_eq!(fm::<syn::Ident>(quote!(ignore("json"))), "json");
+        assert_eq!(fm::<syn::Ident>(quote!(ignore = json)), "json");
+
+        syn::Ident::from_meta(&pm(quote!(ignore())).unwrap()).unwrap_err();
+        syn::Ident::from_meta(&pm(quote!(ignore(json, yaml))).unwrap()).unwrap_err();
+        syn::Ident::from_meta(&pm(quote!(ignore(a::b))).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_ident_vec() {
+        let idents = fm::<Vec<syn::Ident>>(quote!(ignore(Debug, Clone, "Hash")));
+        assert_eq!(idents.len(), 3);
+        assert_eq!(idents[0], "Debug");
+        assert_eq!(idents[2], "Hash");
+
+        let err =
+            Vec::<syn::Ident>::from_meta(&pm(quote!(ignore(Debug, std::fmt::Display))).unwrap())
+                .unwrap_err();
+        assert_eq!(err.to_string(), "Expected an identifier");
+        Vec::<syn::Ident>::from_meta(&pm(quote!(ignore(Debug = 1))).unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_meta_vec() {
+        let metas = fm::<Vec<syn::Meta>>(quote!(ignore(a, b = 1, c(d))));
+        assert_eq!(metas.len(), 3);
+        assert!(metas[1].path().is_ident("b"));
+
+        FromMeta::from_meta(&pm(quote!(ignore(a, "lit"))).unwrap())
+            .map(|_: Vec<syn::Meta>| ())
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_nested_meta_vec() {
+        let items = fm::<Vec<NestedMeta>>(quote!(ignore(a, "lit")));
+        assert_eq!(items.len(), 2);
+        assert!(matches!(items[1], NestedMeta::Lit(_)));
+    }
 }
@@ -22,8 +22,12 @@ mod from_meta;
 mod from_type_param;
 mod from_variant;
 pub(crate) mod options;
+#[cfg(test)]
+mod test_util;
+mod to_meta_item;
 pub mod usage;
 pub mod util;
+mod validate;
 
 pub use self::error::{Error, Result};
 pub use self::from_attributes::FromAttributes;
@@ -34,9 +38,15 @@ pub use self::from_generics::FromGenerics;
 pub use self::from_meta::FromMeta;
 pub use self::from_type_param::FromTypeParam;
 pub use self::from_variant::FromVariant;
+pub use self::to_meta_item::ToMetaItem;
+pub use self::validate::Validate;
 
 // Re-exports
 #[doc(hidden)]
 pub use quote::ToTokens;
 #[doc(hidden)]
 pub use syn;
+
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+pub use regex;
@@ -27,7 +27,8 @@
 ///
 /// `darling` cannot derive this trait automatically, as it doesn't know which information extracted from
 /// proc-macro input is meant to constitute "using" the type parameter, but crate consumers should
-/// implement it by hand or using the macro.
+/// implement it by hand or using the macro. Fields wrapped in `Option`, `Vec`, `Box`, or
+/// `darling::util::SpannedValue` can be passed to the macro directly.
 #[macro_export]
 macro_rules! uses_type_params {
     ($impl_type:ty, $accessor:ident) => {
@@ -65,6 +66,8 @@ macro_rules! uses_type_params {
 /// fields for the rest of its arguments.
 ///
 /// The type of each passed-in field must implement `UsesLifetimes`, or the resulting code won't compile.
+/// As with `uses_type_params!`, fields wrapped in `Option`, `Vec`, `Box`, or
+/// `darling::util::SpannedValue` can be passed directly.
 #[macro_export]
 macro_rules! uses_lifetimes {
     ($impl_type:ty, $accessor:ident) => {
@@ -1,9 +1,12 @@
 use ident_case::RenameRule;
+use proc_macro2::Span;
+use syn::spanned::Spanned;
 
 use crate::ast::{Data, Fields, Style};
 use crate::codegen;
 use crate::codegen::PostfixTransform;
 use crate::options::{DefaultExpression, InputField, InputVariant, ParseAttribute, ParseData};
+use crate::util::{path_to_string, PathList, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 /// A struct or enum which should have `FromMeta` or `FromDeriveInput` implementations
@@ -35,11 +38,34 @@ pub struct Core {
     /// The body of the _deriving_ type.
     pub data: Data<InputVariant, InputField>,
 
-    /// The custom bound to apply to the generated impl
+    /// The custom bound to apply to the generated impl, replacing the inferred bounds.
     pub bound: Option<Vec<syn::WherePredicate>>,
 
+    /// Predicates to add to the generated impl alongside the inferred bounds.
+    pub extend_bound: Option<Vec<syn::WherePredicate>>,
+
     /// Whether or not unknown fields should produce an error at compilation time.
     pub allow_unknown_fields: Option<bool>,
+
+    /// Whether field and variant names should be matched without regard to case. Error
+    /// messages still use the canonical names.
+    pub case_insensitive: Option<bool>,
+
+    /// Groups of fields, by identifier, of which at most one may be set by the caller.
+    pub exclusive: Vec<PathList>,
+
+    /// Groups of fields, by identifier, of which at least one must be set by the caller.
+    pub require_any: Vec<PathList>,
+
+    /// Whether the generated impl should call `darling::Validate::validate` on each
+    /// successfully-parsed instance.
+    pub validate: Option<bool>,
+
+    /// The name of an option whose value is a file to read more options from.
+    pub from_file: Option<SpannedValue<String>>,
+
+    /// Whether to emit a `FIELDS` constant describing the fields read from the input.
+    pub reflect: Option<bool>,
 }
 
 impl Core {
@@ -59,10 +85,77 @@ impl Core {
             },
             post_transform: Default::default(),
             bound: Default::default(),
+            extend_bound: Default::default(),
             allow_unknown_fields: Default::default(),
+            case_insensitive: Default::default(),
+            exclusive: Default::default(),
+            require_any: Default::default(),
+            validate: Default::default(),
+            from_file: Default::default(),
+            reflect: Default::default(),
         })
     }
 
+    /// Returns `true` if the field is a member of any field group on the container.
+    fn in_any_group(&self, field: &InputField) -> bool {
+        self.exclusive
+            .iter()
+            .chain(&self.require_any)
+            .flat_map(|group| group.iter())
+            .any(|path| path.is_ident(&field.ident))
+    }
+
+    /// Check that each field group names at least two distinct fields which are read from
+    /// the input by name.
+    fn validate_groups(&self, option: &str, groups: &[PathList]) -> Result<()> {
+        let fields = match self.data {
+            Data::Struct(ref fields) => &fields.fields,
+            Data::Enum(_) => {
+                return match groups.first().and_then(|group| group.first()) {
+                    Some(path) => Err(Error::custom(format!(
+                        "`{}` is only supported on structs",
+                        option
+                    ))
+                    .with_span(path)),
+                    None => Ok(()),
+                };
+            }
+        };
+
+        let mut errors = Error::accumulator();
+        for group in groups {
+            if group.len() < 2 {
+                errors.push(
+                    Error::custom(format!("`{}` needs at least two fields", option)).with_span(
+                        &group
+                            .first()
+                            .map(|p| p.span())
+                            .unwrap_or_else(Span::call_site),
+                    ),
+                );
+            }
+
+            for (i, path) in group.iter().enumerate() {
+                let field = fields.iter().find(|f| path.is_ident(&f.ident));
+                let problem = match field {
+                    None => "is not a field of this struct",
+                    Some(f) if !f.is_read_by_name() => "is not read from the input by name",
+                    Some(_) if group.iter().take(i).any(|p| p == path) => {
+                        "is listed more than once"
+                    }
+                    Some(_) => continue,
+                };
+
+                errors.push(
+                    Error::custom(format!("`{}` {}", path_to_string(path), problem))
+                        .with_span(path),
+                );
+            }
+        }
+
+        errors.finish()
+    }
+
     fn as_codegen_default(&self) -> Option<codegen::DefaultExpression<'_>> {
         self.default.as_ref().map(|expr| match *expr {
             DefaultExpression::Explicit(ref path) => codegen::DefaultExpression::Explicit(path),
@@ -109,14 +202,66 @@ impl ParseAttribute for Core {
 
             self.post_transform =
                 Some(PostfixTransform::new(transformer, FromMeta::from_meta(mi)?));
-        } else if path.is_ident("bound") {
-            self.bound = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("bound") || path.is_ident("extend_bound") {
+            // This unwrap is safe because we just called is_ident above
+            let option = path.get_ident().unwrap().to_string();
+            let (slot, other, other_name) = if option == "bound" {
+                (&mut self.bound, &self.extend_bound, "extend_bound")
+            } else {
+                (&mut self.extend_bound, &self.bound, "bound")
+            };
+
+            if slot.is_some() {
+                return Err(Error::duplicate_field(&option).with_span(mi));
+            }
+
+            if other.is_some() {
+                return Err(Error::custom(format!(
+                    "Options `{}` and `{}` are mutually exclusive",
+                    option, other_name
+                ))
+                .with_span(mi));
+            }
+
+            *slot = FromMeta::from_meta(mi)?;
         } else if path.is_ident("allow_unknown_fields") {
             if self.allow_unknown_fields.is_some() {
                 return Err(Error::duplicate_field("allow_unknown_fields").with_span(mi));
             }
 
             self.allow_unknown_fields = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("exclusive") {
+            self.exclusive.push(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("require_any") {
+            self.require_any.push(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("validate") {
+            if self.validate.is_some() {
+                return Err(Error::duplicate_field("validate").with_span(mi));
+            }
+
+            self.validate = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("case_insensitive") {
+            if self.case_insensitive.is_some() {
+                return Err(Error::duplicate_field("case_insensitive").with_span(mi));
+            }
+
+            self.case_insensitive = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("from_file") {
+            if self.from_file.is_some() {
+                return Err(Error::duplicate_field("from_file").with_span(mi));
+            }
+
+            self.from_file = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("reflect") {
+            if self.reflect.is_some() {
+                return Err(Error::duplicate_field("reflect").with_span(mi));
+            }
+
+            if self.data.is_enum() {
+                return Err(Error::custom("`reflect` is only supported on structs").with_span(mi));
+            }
+
+            self.reflect = FromMeta::from_meta(mi)?;
         } else {
             return Err(Error::unknown_field_path(path).with_span(mi));
         }
@@ -146,12 +291,34 @@ impl ParseData for Core {
                 style: Style::Unit, ..
             }) => panic!("Core::parse_field should not be called on unit"),
             Data::Struct(Fields { ref mut fields, .. }) => {
+                if f.takes_leftovers() && fields.iter().any(InputField::takes_leftovers) {
+                    return Err(Error::custom(
+                        "`flatten` and `rest` can only be used on one field between them",
+                    )
+                    .with_span(field));
+                }
+
                 fields.push(f);
                 Ok(())
             }
             Data::Enum(_) => panic!("Core::parse_field should never be called for an enum"),
         }
     }
+
+    fn validate_body(&self) -> Result<()> {
+        let mut errors = Error::accumulator();
+
+        if let Data::Struct(ref fields) = self.data {
+            errors.handle(InputField::validate_siblings(&fields.fields));
+        }
+
+        // Variants validate their own fields when they're parsed.
+
+        errors.handle(self.validate_groups("exclusive", &self.exclusive));
+        errors.handle(self.validate_groups("require_any", &self.require_any));
+
+        errors.finish()
+    }
 }
 
 impl<'a> From<&'a Core> for codegen::TraitImpl<'a> {
@@ -162,12 +329,26 @@ impl<'a> From<&'a Core> for codegen::TraitImpl<'a> {
             data: v
                 .data
                 .as_ref()
-                .map_struct_fields(InputField::as_codegen_field)
+                .map_struct_fields(|field| {
+                    let mut codegen_field = field.as_codegen_field();
+                    codegen_field.track_path = v.in_any_group(field);
+                    codegen_field
+                })
                 .map_enum_variants(|variant| variant.as_codegen_variant(&v.ident)),
             default: v.as_codegen_default(),
             post_transform: v.post_transform.as_ref(),
             bound: v.bound.as_deref(),
+            extend_bound: v.extend_bound.as_deref().unwrap_or_default(),
             allow_unknown_fields: v.allow_unknown_fields.unwrap_or_default(),
+            case_insensitive: v.case_insensitive.unwrap_or_default(),
+            exclusive: &v.exclusive,
+            require_any: &v.require_any,
+            validate: v.validate.unwrap_or_default(),
+            from_file: v.from_file.as_ref().map(|key| key.as_str()),
+            reflect: v.reflect.unwrap_or_default(),
+            unwrap_cfg_attr: false,
+            docs: None,
+            cfg_predicates: None,
         }
     }
 }
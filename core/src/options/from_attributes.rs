@@ -2,7 +2,7 @@ use quote::ToTokens;
 
 use crate::{ast::Data, codegen::FromAttributesImpl, Error, Result};
 
-use super::{OuterFrom, ParseAttribute, ParseData};
+use super::{Core, OuterFrom, ParseAttribute, ParseData};
 
 /// Receiver for derived `FromAttributes` impls.
 pub struct FromAttributesOptions {
@@ -51,12 +51,16 @@ impl ParseData for FromAttributesOptions {
     fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
         self.base.parse_field(field)
     }
+
+    fn core(&self) -> Option<&Core> {
+        Some(&self.base.container)
+    }
 }
 
 impl<'a> From<&'a FromAttributesOptions> for FromAttributesImpl<'a> {
     fn from(v: &'a FromAttributesOptions) -> Self {
         FromAttributesImpl {
-            base: (&v.base.container).into(),
+            base: (&v.base).into(),
             attr_names: &v.base.attr_names,
         }
     }
@@ -3,8 +3,9 @@ use quote::ToTokens;
 use syn::Ident;
 
 use crate::codegen::FromDeriveInputImpl;
-use crate::options::{DeriveInputShapeSet, OuterFrom, ParseAttribute, ParseData};
-use crate::{FromMeta, Result};
+use crate::options::{Core, DeriveInputShapeSet, OuterFrom, ParseAttribute, ParseData};
+use crate::util::SpannedValue;
+use crate::{Error, FromMeta, Result};
 
 #[derive(Debug)]
 pub struct FdiOptions {
@@ -19,6 +20,12 @@ pub struct FdiOptions {
     pub data: Option<Ident>,
 
     pub supports: Option<DeriveInputShapeSet>,
+
+    /// Whether `data` should inherit settings from the receiver via `ast::Inherit`.
+    pub inherit: Option<SpannedValue<bool>>,
+
+    /// Whether to generate `from_derive_input_lossy`, which builds the receiver despite errors.
+    pub lossy: Option<bool>,
 }
 
 impl FdiOptions {
@@ -29,9 +36,23 @@ impl FdiOptions {
             generics: Default::default(),
             data: Default::default(),
             supports: Default::default(),
+            inherit: Default::default(),
+            lossy: Default::default(),
         })
         .parse_attributes(&di.attrs)?
-        .parse_body(&di.data)
+        .parse_body(&di.data)?
+        .validate()
+    }
+
+    fn validate(self) -> Result<Self> {
+        match self.inherit {
+            Some(ref inherit) if **inherit && self.data.is_none() => Err(syn::Error::new(
+                inherit.span(),
+                "`inherit` requires a `data` field on the receiver",
+            )
+            .into()),
+            _ => Ok(self),
+        }
     }
 }
 
@@ -40,6 +61,20 @@ impl ParseAttribute for FdiOptions {
         if mi.path().is_ident("supports") {
             self.supports = FromMeta::from_meta(mi)?;
             Ok(())
+        } else if mi.path().is_ident("inherit") {
+            if self.inherit.is_some() {
+                return Err(Error::duplicate_field("inherit").with_span(mi));
+            }
+
+            self.inherit = FromMeta::from_meta(mi)?;
+            Ok(())
+        } else if mi.path().is_ident("lossy") {
+            if self.lossy.is_some() {
+                return Err(Error::duplicate_field("lossy").with_span(mi));
+            }
+
+            self.lossy = FromMeta::from_meta(mi)?;
+            Ok(())
         } else {
             self.base.parse_nested(mi)
         }
@@ -68,12 +103,16 @@ impl ParseData for FdiOptions {
             _ => self.base.parse_field(field),
         }
     }
+
+    fn core(&self) -> Option<&Core> {
+        Some(&self.base.container)
+    }
 }
 
 impl<'a> From<&'a FdiOptions> for FromDeriveInputImpl<'a> {
     fn from(v: &'a FdiOptions) -> Self {
         FromDeriveInputImpl {
-            base: (&v.base.container).into(),
+            base: (&v.base).into(),
             attr_names: &v.base.attr_names,
             from_ident: v.base.from_ident,
             ident: v.base.ident.as_ref(),
@@ -83,6 +122,8 @@ impl<'a> From<&'a FdiOptions> for FromDeriveInputImpl<'a> {
             attrs: v.base.attrs.as_ref(),
             forward_attrs: v.base.forward_attrs.as_ref(),
             supports: v.supports.as_ref(),
+            inherit: v.inherit.as_ref().map_or(false, |inherit| **inherit),
+            lossy: v.lossy.unwrap_or_default(),
         }
     }
 }
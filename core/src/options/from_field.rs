@@ -3,7 +3,7 @@ use quote::ToTokens;
 use syn::Ident;
 
 use crate::codegen::FromFieldImpl;
-use crate::options::{OuterFrom, ParseAttribute, ParseData};
+use crate::options::{Core, OuterFrom, ParseAttribute, ParseData};
 use crate::Result;
 
 #[derive(Debug)]
@@ -49,6 +49,10 @@ impl ParseData for FromFieldOptions {
             _ => self.base.parse_field(field),
         }
     }
+
+    fn core(&self) -> Option<&Core> {
+        Some(&self.base.container)
+    }
 }
 
 impl<'a> From<&'a FromFieldOptions> for FromFieldImpl<'a> {
@@ -58,7 +62,7 @@ impl<'a> From<&'a FromFieldOptions> for FromFieldImpl<'a> {
             vis: v.vis.as_ref(),
             ty: v.ty.as_ref(),
             attrs: v.base.attrs.as_ref(),
-            base: (&v.base.container).into(),
+            base: (&v.base).into(),
             attr_names: &v.base.attr_names,
             forward_attrs: v.base.forward_attrs.as_ref(),
             from_ident: v.base.from_ident,
@@ -33,6 +33,10 @@ impl ParseData for FromMetaOptions {
     fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
         self.base.parse_field(field)
     }
+
+    fn core(&self) -> Option<&Core> {
+        Some(&self.base)
+    }
 }
 
 impl<'a> From<&'a FromMetaOptions> for FromMetaImpl<'a> {
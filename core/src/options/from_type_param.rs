@@ -3,7 +3,7 @@ use quote::ToTokens;
 use syn::Ident;
 
 use crate::codegen::FromTypeParamImpl;
-use crate::options::{OuterFrom, ParseAttribute, ParseData};
+use crate::options::{Core, OuterFrom, ParseAttribute, ParseData};
 use crate::Result;
 
 #[derive(Debug)]
@@ -49,12 +49,16 @@ impl ParseData for FromTypeParamOptions {
             _ => self.base.parse_field(field),
         }
     }
+
+    fn core(&self) -> Option<&Core> {
+        Some(&self.base.container)
+    }
 }
 
 impl<'a> From<&'a FromTypeParamOptions> for FromTypeParamImpl<'a> {
     fn from(v: &'a FromTypeParamOptions) -> Self {
         FromTypeParamImpl {
-            base: (&v.base.container).into(),
+            base: (&v.base).into(),
             ident: v.base.ident.as_ref(),
             attrs: v.base.attrs.as_ref(),
             bounds: v.bounds.as_ref(),
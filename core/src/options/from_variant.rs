@@ -3,7 +3,7 @@ use quote::ToTokens;
 use syn::{DeriveInput, Field, Ident, Meta};
 
 use crate::codegen::FromVariantImpl;
-use crate::options::{DataShape, OuterFrom, ParseAttribute, ParseData};
+use crate::options::{Core, DataShape, OuterFrom, ParseAttribute, ParseData};
 use crate::{FromMeta, Result};
 
 #[derive(Debug, Clone)]
@@ -32,7 +32,7 @@ impl FromVariantOptions {
 impl<'a> From<&'a FromVariantOptions> for FromVariantImpl<'a> {
     fn from(v: &'a FromVariantOptions) -> Self {
         FromVariantImpl {
-            base: (&v.base.container).into(),
+            base: (&v.base).into(),
             ident: v.base.ident.as_ref(),
             discriminant: v.discriminant.as_ref(),
             fields: v.fields.as_ref(),
@@ -70,6 +70,10 @@ impl ParseData for FromVariantOptions {
             _ => self.base.parse_field(field),
         }
     }
+
+    fn core(&self) -> Option<&Core> {
+        Some(&self.base.container)
+    }
 }
 
 impl ToTokens for FromVariantOptions {
@@ -2,24 +2,60 @@ use std::borrow::Cow;
 
 use syn::{parse_quote_spanned, spanned::Spanned};
 
+use crate::ast::NestedMeta;
 use crate::codegen;
-use crate::options::{Core, DefaultExpression, ParseAttribute};
-use crate::util::SpannedValue;
+use crate::options::{Core, DefaultExpression, NumericRange, ParseAttribute};
+use crate::util::{doc_lines, Callable, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 #[derive(Debug, Clone)]
 pub struct InputField {
     pub ident: syn::Ident,
     pub attr_name: Option<String>,
+    /// Alternate names which are accepted in place of `attr_name`, so that renamed
+    /// options remain backwards-compatible.
+    pub aliases: Vec<SpannedValue<String>>,
+    /// Alternate names which are still accepted, but record a warning when used.
+    pub deprecated_aliases: Vec<SpannedValue<String>>,
     pub ty: syn::Type,
     pub default: Option<DefaultExpression>,
-    pub with: Option<syn::Path>,
+    pub with: Option<Callable>,
 
     /// If `true`, generated code will not look for this field in the input meta item,
     /// instead always falling back to either `InputField::default` or `Default::default`.
     pub skip: Option<SpannedValue<bool>>,
     pub post_transform: Option<codegen::PostfixTransform>,
     pub multiple: Option<bool>,
+    /// A message to use instead of the standard "missing field" error when the field is
+    /// absent and has no default.
+    pub missing: Option<SpannedValue<String>>,
+    /// If `true`, the field's type is parsed from all the meta items that no other
+    /// field claims, as though its fields were declared directly on the container.
+    pub flatten: Option<SpannedValue<bool>>,
+    /// If `true`, the field receives every item no other field claims, including
+    /// literals, without any of them being reported as unknown.
+    pub rest: Option<SpannedValue<bool>>,
+    /// If `true`, the field isn't read from the input, but instead receives the warnings
+    /// recorded while parsing its siblings.
+    pub warnings: Option<SpannedValue<bool>>,
+    /// Fields, by identifier, which must also be present when this field is.
+    pub requires: Vec<syn::Ident>,
+    /// Fields, by identifier, which must not be present when this field is.
+    pub conflicts_with: Vec<syn::Ident>,
+    /// Inclusive bounds which each parsed value must fall within.
+    pub range: Option<NumericRange>,
+    /// The values which the parsed value must be equal to one of, if not empty.
+    pub one_of: Vec<String>,
+    /// A regular expression which the parsed value must match. This requires the `regex` feature.
+    pub matches: Option<SpannedValue<String>>,
+    /// If `true`, `${VAR}` placeholders in the field's string values are replaced with
+    /// environment variables before the field is parsed.
+    pub expand_env: Option<bool>,
+    /// If set, the field receives its item unparsed through `util::FromRawMeta`, rather than
+    /// through the type's `FromMeta` impl.
+    pub raw: Option<SpannedValue<bool>>,
+    /// The lines of the field's doc comments, for `#[darling(reflect)]`.
+    pub docs: Vec<String>,
 }
 
 impl InputField {
@@ -29,24 +65,84 @@ impl InputField {
             ident: &self.ident,
             name_in_attr: self
                 .attr_name
-                .as_ref()
+                .as_deref()
                 .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed),
+            aliases: self.aliases.iter().map(|alias| alias.as_str()).collect(),
+            deprecated_aliases: self
+                .deprecated_aliases
+                .iter()
+                .map(|alias| alias.as_str())
+                .collect(),
             ty: &self.ty,
             default_expression: self.as_codegen_default(),
-            with_path: self.with.as_ref().map_or_else(
+            with_callable: self.with.as_ref().map_or_else(
                 || {
-                    Cow::Owned(
-                        parse_quote_spanned!(self.ty.span()=> ::darling::FromMeta::from_meta),
-                    )
+                    let span = self.ty.span();
+                    Cow::Owned(if self.is_raw() {
+                        parse_quote_spanned!(span=> ::darling::util::FromRawMeta::from_raw_meta)
+                    } else {
+                        parse_quote_spanned!(span=> ::darling::FromMeta::from_meta)
+                    })
                 },
-                Cow::Borrowed,
+                |callable| Cow::Borrowed(callable.as_expr()),
             ),
             skip: *self.skip.unwrap_or_default(),
             post_transform: self.post_transform.as_ref(),
             multiple: self.multiple.unwrap_or_default(),
+            missing_message: self.missing.as_ref().map(|msg| msg.as_str()),
+            flatten: self.is_flatten(),
+            rest: self.is_rest(),
+            warnings: self.is_warnings(),
+            requires: &self.requires,
+            conflicts_with: &self.conflicts_with,
+            track_path: false,
+            range: self.range.as_ref(),
+            one_of: &self.one_of,
+            matches: self.matches.as_ref().map(|pattern| pattern.as_str()),
+            expand_env: self.expand_env.unwrap_or_default(),
+            raw: self.is_raw(),
+            docs: &self.docs,
         }
     }
 
+    /// The name the field is read by, before any aliases.
+    fn name_in_attr(&self) -> String {
+        self.attr_name
+            .clone()
+            .unwrap_or_else(|| self.ident.to_string())
+    }
+
+    /// Returns `true` if this field is read from an input item bearing its name.
+    pub fn is_read_by_name(&self) -> bool {
+        !self.skip.map_or(false, |v| *v) && !self.takes_leftovers() && !self.is_warnings()
+    }
+
+    /// Returns `true` if this field absorbs the meta items no other field claims.
+    pub fn is_flatten(&self) -> bool {
+        self.flatten.as_ref().map_or(false, |v| **v)
+    }
+
+    /// Returns `true` if this field receives its item unparsed.
+    pub fn is_raw(&self) -> bool {
+        self.raw.as_ref().map_or(false, |v| **v)
+    }
+
+    /// Returns `true` if this field collects every item no other field claims.
+    pub fn is_rest(&self) -> bool {
+        self.rest.as_ref().map_or(false, |v| **v)
+    }
+
+    /// Returns `true` if this field receives the warnings recorded while parsing its siblings.
+    pub fn is_warnings(&self) -> bool {
+        self.warnings.as_ref().map_or(false, |v| **v)
+    }
+
+    /// Returns `true` if this field is built from the items other fields don't claim,
+    /// rather than from an item of its own.
+    pub fn takes_leftovers(&self) -> bool {
+        self.is_flatten() || self.is_rest()
+    }
+
     /// Generate a codegen::DefaultExpression for this field. This requires the field name
     /// in the `Inherit` case.
     fn as_codegen_default(&self) -> Option<codegen::DefaultExpression<'_>> {
@@ -62,11 +158,25 @@ impl InputField {
             ident,
             ty,
             attr_name: None,
+            aliases: vec![],
+            deprecated_aliases: vec![],
             default: None,
             with: None,
             skip: None,
             post_transform: Default::default(),
             multiple: None,
+            missing: None,
+            flatten: None,
+            rest: None,
+            warnings: None,
+            requires: vec![],
+            conflicts_with: vec![],
+            range: None,
+            one_of: vec![],
+            matches: None,
+            expand_env: None,
+            raw: None,
+            docs: vec![],
         }
     }
 
@@ -76,7 +186,11 @@ impl InputField {
             .clone()
             .unwrap_or_else(|| syn::Ident::new("__unnamed", ::proc_macro2::Span::call_site()));
         let ty = f.ty.clone();
-        let base = Self::new(ident, ty).parse_attributes(&f.attrs)?;
+        let mut base = Self::new(ident, ty).parse_attributes(&f.attrs)?;
+        base.docs = doc_lines(&f.attrs);
+        base.validate_markers()?;
+        base.validate_missing()?;
+        base.validate_raw()?;
 
         Ok(if let Some(container) = parent {
             base.with_inherited(container)
@@ -85,6 +199,205 @@ impl InputField {
         })
     }
 
+    /// Flattened, rest, and warnings fields don't have a name of their own in the input, so
+    /// options which only make sense for a named field are rejected.
+    fn validate_markers(&self) -> Result<()> {
+        let markers = [
+            ("flatten", &self.flatten),
+            ("rest", &self.rest),
+            ("warnings", &self.warnings),
+        ];
+
+        let mut present = markers.iter().filter_map(|(marker, value)| match value {
+            Some(value) if **value => Some((*marker, value.span())),
+            _ => None,
+        });
+
+        let (marker, span) = match (present.next(), present.next()) {
+            (None, _) => return Ok(()),
+            (Some(first), Some(second)) => {
+                return Err(syn::Error::new(
+                    second.1,
+                    format!(
+                        "`{}` and `{}` cannot be used on the same field",
+                        first.0, second.0
+                    ),
+                )
+                .into());
+            }
+            (Some(only), None) => only,
+        };
+
+        let is_warnings = marker == "warnings";
+        let conflicts = [
+            ("rename", self.attr_name.is_some()),
+            ("alias", !self.aliases.is_empty()),
+            ("deprecated_alias", !self.deprecated_aliases.is_empty()),
+            ("with", self.with.is_some()),
+            ("skip", self.skip.map_or(false, |v| *v)),
+            ("multiple", self.multiple.unwrap_or_default()),
+            ("missing", self.missing.is_some()),
+            ("requires", !self.requires.is_empty()),
+            ("conflicts_with", !self.conflicts_with.is_empty()),
+            ("range", self.range.is_some()),
+            ("one_of", !self.one_of.is_empty()),
+            ("matches", self.matches.is_some()),
+            ("expand_env", self.expand_env.unwrap_or_default()),
+            ("default", is_warnings && self.default.is_some()),
+            ("map", is_warnings && self.post_transform.is_some()),
+        ];
+
+        let mut errors = Error::accumulator();
+        for (option, present) in conflicts.iter() {
+            if *present {
+                errors.push(
+                    syn::Error::new(
+                        span,
+                        format!(
+                            "`{}` and `{}` cannot be used on the same field",
+                            marker, option
+                        ),
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Checks rules which involve more than one field of the same struct or variant.
+    pub fn validate_siblings(fields: &[InputField]) -> Result<()> {
+        let mut errors = Error::accumulator();
+        let mut warnings_fields = fields
+            .iter()
+            .filter_map(|f| f.warnings.as_ref().filter(|v| ***v));
+
+        if warnings_fields.next().is_some() {
+            for extra in warnings_fields {
+                errors.push(
+                    syn::Error::new(extra.span(), "`warnings` can only be used on one field")
+                        .into(),
+                );
+            }
+        } else {
+            for alias in fields.iter().flat_map(|f| f.deprecated_aliases.iter()) {
+                errors.push(
+                    syn::Error::new(
+                        alias.span(),
+                        "`deprecated_alias` requires a `#[darling(warnings)]` field to record warnings in",
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        // Each name can only match one field, or the later field's match arm is never reached.
+        let mut claimed: Vec<(String, &syn::Ident)> = vec![];
+        for field in fields.iter().filter(|f| f.is_read_by_name()) {
+            let name = (field.name_in_attr(), field.ident.span());
+            let aliases = field
+                .aliases
+                .iter()
+                .chain(field.deprecated_aliases.iter())
+                .map(|alias| (alias.to_string(), alias.span()));
+            for (name, span) in std::iter::once(name).chain(aliases) {
+                match claimed.iter().find(|(claimed, _)| *claimed == name) {
+                    Some((_, owner)) => errors.push(
+                        syn::Error::new(
+                            span,
+                            format!("`{}` is already the name or an alias of `{}`", name, owner),
+                        )
+                        .into(),
+                    ),
+                    None => claimed.push((name, &field.ident)),
+                }
+            }
+        }
+
+        // Constraints can only refer to fields that are read from the input by name.
+        for field in fields {
+            for other in field.requires.iter().chain(field.conflicts_with.iter()) {
+                let target = fields.iter().find(|f| f.ident == *other);
+                let problem = match target {
+                    None => "is not a field of this struct",
+                    Some(target) if target.ident == field.ident => "cannot refer to itself",
+                    Some(target) if !target.is_read_by_name() => {
+                        "is not read from the input by name"
+                    }
+                    Some(_) => continue,
+                };
+
+                errors
+                    .push(syn::Error::new(other.span(), format!("`{}` {}", other, problem)).into());
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// A raw field's value isn't parsed, so options which parse or check it are rejected.
+    fn validate_raw(&self) -> Result<()> {
+        let raw = match self.raw {
+            Some(ref raw) if **raw => raw,
+            _ => return Ok(()),
+        };
+
+        let conflicts = [
+            ("with", self.with.is_some()),
+            ("range", self.range.is_some()),
+            ("one_of", !self.one_of.is_empty()),
+            ("matches", self.matches.is_some()),
+            ("expand_env", self.expand_env.unwrap_or_default()),
+            ("flatten", self.is_flatten()),
+            ("rest", self.is_rest()),
+            ("warnings", self.is_warnings()),
+        ];
+
+        let mut errors = Error::accumulator();
+        for (option, present) in conflicts.iter() {
+            if *present {
+                errors.push(
+                    syn::Error::new(
+                        raw.span(),
+                        format!("`raw` and `{}` cannot be used on the same field", option),
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// A custom missing-field message is only used by fields which can be missing, so
+    /// combining it with options that always provide a value is a mistake.
+    fn validate_missing(&self) -> Result<()> {
+        let missing = match self.missing {
+            Some(ref missing) => missing,
+            None => return Ok(()),
+        };
+
+        let conflict = if self.default.is_some() {
+            "default"
+        } else if self.skip.map_or(false, |v| *v) {
+            "skip"
+        } else if self.multiple.unwrap_or_default() {
+            "multiple"
+        } else {
+            return Ok(());
+        };
+
+        Err(syn::Error::new(
+            missing.span(),
+            format!(
+                "`missing` has no effect on a field with `{}`, since it always has a value",
+                conflict
+            ),
+        )
+        .into())
+    }
+
     /// Apply inherited settings from the container. This is done _after_ parsing
     /// to ensure deference to explicit field-level settings.
     fn with_inherited(mut self, parent: &Core) -> Self {
@@ -130,6 +443,20 @@ impl ParseAttribute for InputField {
             }
 
             self.attr_name = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("alias") {
+            let alias: SpannedValue<String> = FromMeta::from_meta(mi)?;
+            if self.aliases.iter().any(|a| **a == *alias) {
+                return Err(Error::duplicate_field(&alias).with_span(mi));
+            }
+
+            self.aliases.push(alias);
+        } else if path.is_ident("deprecated_alias") {
+            let alias: SpannedValue<String> = FromMeta::from_meta(mi)?;
+            if self.deprecated_aliases.iter().any(|a| **a == *alias) {
+                return Err(Error::duplicate_field(&alias).with_span(mi));
+            }
+
+            self.deprecated_aliases.push(alias);
         } else if path.is_ident("default") {
             if self.default.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
@@ -171,6 +498,64 @@ impl ParseAttribute for InputField {
             }
 
             self.multiple = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("missing") {
+            if self.missing.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.missing = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("flatten") {
+            if self.flatten.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.flatten = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("rest") {
+            if self.rest.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.rest = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("warnings") {
+            if self.warnings.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.warnings = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("requires") {
+            self.requires.push(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("conflicts_with") {
+            self.conflicts_with.push(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("range") {
+            if self.range.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.range = Some(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("one_of") {
+            if !self.one_of.is_empty() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.one_of = parse_one_of(mi)?;
+        } else if path.is_ident("matches") {
+            if self.matches.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.matches = Some(parse_matches(mi)?);
+        } else if path.is_ident("expand_env") {
+            if self.expand_env.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.expand_env = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("raw") {
+            if self.raw.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.raw = FromMeta::from_meta(mi)?;
         } else {
             return Err(Error::unknown_field_path(path).with_span(mi));
         }
@@ -178,3 +563,91 @@ impl ParseAttribute for InputField {
         Ok(())
     }
 }
+
+/// Parse the list of string literals in `one_of("gzip", "zstd")`.
+fn parse_one_of(mi: &syn::Meta) -> Result<Vec<String>> {
+    let items = match mi {
+        syn::Meta::List(list) => NestedMeta::parse_meta_list(list.tokens.clone())?,
+        _ => return Err(Error::unsupported_format("non-list").with_span(mi)),
+    };
+
+    if items.is_empty() {
+        return Err(Error::too_few_items(1).with_span(mi));
+    }
+
+    let mut errors = Error::accumulator();
+    let mut values: Vec<String> = Vec::with_capacity(items.len());
+    for item in &items {
+        match item {
+            NestedMeta::Lit(syn::Lit::Str(value)) => {
+                if values.contains(&value.value()) {
+                    errors.push(
+                        Error::custom(format!("Duplicate value `{}`", value.value()))
+                            .with_span(value),
+                    );
+                } else {
+                    values.push(value.value());
+                }
+            }
+            NestedMeta::Lit(lit) => errors.push(Error::unexpected_lit_type(lit).with_span(lit)),
+            NestedMeta::Meta(meta) => {
+                errors.push(Error::unsupported_format("non-literal").with_span(meta))
+            }
+        }
+    }
+
+    errors.finish_with(values)
+}
+
+/// Parse the pattern in `matches = "..."`, checking that it's a valid regular expression so
+/// that mistakes are reported at the attribute rather than when the receiver is used.
+#[cfg(feature = "regex")]
+fn parse_matches(mi: &syn::Meta) -> Result<SpannedValue<String>> {
+    let pattern: SpannedValue<String> = FromMeta::from_meta(mi)?;
+    if let Err(e) = regex::Regex::new(&pattern) {
+        return Err(
+            syn::Error::new(pattern.span(), format!("Invalid regular expression: {}", e)).into(),
+        );
+    }
+
+    Ok(pattern)
+}
+
+#[cfg(not(feature = "regex"))]
+fn parse_matches(mi: &syn::Meta) -> Result<SpannedValue<String>> {
+    Err(Error::custom("`matches` requires the `regex` feature of darling").with_span(mi))
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use crate::options::FromMetaOptions;
+
+    #[test]
+    fn alias_collisions() {
+        let err = FromMetaOptions::new(&parse_quote! {
+            struct Receiver {
+                #[darling(alias = "size")]
+                limit: u32,
+                size: u32,
+                #[darling(rename = "colour")]
+                color: String,
+                #[darling(deprecated_alias = "colour")]
+                hue: String,
+                #[darling(warnings)]
+                warnings: ::darling::util::Warnings,
+            }
+        })
+        .err()
+        .unwrap();
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            vec![
+                "`size` is already the name or an alias of `limit`",
+                "`colour` is already the name or an alias of `color`",
+            ]
+        );
+    }
+}
@@ -13,6 +13,8 @@ pub struct InputVariant {
     skip: Option<bool>,
     /// Whether or not unknown fields are acceptable in this
     allow_unknown_fields: Option<bool>,
+    /// Whether field names in this variant are matched without regard to case.
+    case_insensitive: bool,
 }
 
 impl InputVariant {
@@ -22,11 +24,12 @@ impl InputVariant {
             variant_ident: &self.ident,
             name_in_attr: self
                 .attr_name
-                .as_ref()
+                .as_deref()
                 .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed),
             data: self.data.as_ref().map(InputField::as_codegen_field),
             skip: self.skip.unwrap_or_default(),
             allow_unknown_fields: self.allow_unknown_fields.unwrap_or_default(),
+            case_insensitive: self.case_insensitive,
         }
     }
 
@@ -37,6 +40,7 @@ impl InputVariant {
             data: Fields::empty_from(&v.fields),
             skip: Default::default(),
             allow_unknown_fields: None,
+            case_insensitive: false,
         })
         .parse_attributes(&v.attrs)?;
 
@@ -53,13 +57,23 @@ impl InputVariant {
             syn::Fields::Named(ref fields) => {
                 let mut items = Vec::with_capacity(fields.named.len());
                 for item in &fields.named {
-                    items.push(InputField::from_field(item, parent)?);
+                    let field = InputField::from_field(item, parent)?;
+                    if field.takes_leftovers() && items.iter().any(InputField::takes_leftovers) {
+                        return Err(Error::custom(
+                            "`flatten` and `rest` can only be used on one field between them",
+                        )
+                        .with_span(item));
+                    }
+
+                    items.push(field);
                 }
 
                 items
             }
         };
 
+        InputField::validate_siblings(&starter.data.fields)?;
+
         Ok(if let Some(p) = parent {
             starter.with_inherited(p)
         } else {
@@ -76,6 +90,8 @@ impl InputVariant {
             self.allow_unknown_fields = Some(parent.allow_unknown_fields.unwrap_or_default());
         }
 
+        self.case_insensitive = parent.case_insensitive.unwrap_or_default();
+
         self
     }
 }
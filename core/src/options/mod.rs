@@ -15,7 +15,9 @@ mod from_variant;
 mod input_field;
 mod input_variant;
 mod outer_from;
+mod range;
 mod shape;
+mod to_meta_item;
 
 pub use self::core::Core;
 pub use self::forward_attrs::ForwardAttrs;
@@ -28,7 +30,9 @@ pub use self::from_variant::FromVariantOptions;
 pub use self::input_field::InputField;
 pub use self::input_variant::InputVariant;
 pub use self::outer_from::OuterFrom;
+pub use self::range::NumericRange;
 pub use self::shape::{DataShape, DeriveInputShapeSet};
+pub use self::to_meta_item::ToMetaItemOptions;
 
 /// A default/fallback expression encountered in attributes during parsing.
 #[derive(Debug, Clone)]
@@ -132,7 +136,20 @@ pub trait ParseData: Sized {
             Data::Union(_) => unreachable!(),
         };
 
-        errors.finish_with(self)
+        errors.finish()?;
+        self.validate_body()?;
+        Ok(self)
+    }
+
+    /// The options shared by every derive, if the implementer has them.
+    fn core(&self) -> Option<&Core> {
+        None
+    }
+
+    /// Check rules that span the whole body once every field and variant has been parsed.
+    /// By default, this checks the rules of the shared options.
+    fn validate_body(&self) -> Result<()> {
+        self.core().map_or(Ok(()), Core::validate_body)
     }
 
     /// Apply the next found variant to the object, returning an error
@@ -1,9 +1,10 @@
 use syn::spanned::Spanned;
 use syn::{Field, Ident, Meta};
 
+use crate::codegen::TraitImpl;
 use crate::options::{Core, DefaultExpression, ForwardAttrs, ParseAttribute, ParseData};
 use crate::util::PathList;
-use crate::{FromMeta, Result};
+use crate::{Error, FromMeta, Result};
 
 /// Reusable base for `FromDeriveInput`, `FromVariant`, `FromField`, and other top-level
 /// `From*` traits.
@@ -15,6 +16,13 @@ pub struct OuterFrom {
     /// The field on the target struct which should receive the type attributes, if any.
     pub attrs: Option<Ident>,
 
+    /// The field on the target struct which should receive the doc comments, if any.
+    pub docs: Option<Ident>,
+
+    /// Whether a field named `docs` receives the doc comments, rather than being read from
+    /// the input like any other field.
+    pub read_docs: Option<bool>,
+
     pub container: Core,
 
     /// The attribute names that should be searched.
@@ -26,6 +34,13 @@ pub struct OuterFrom {
 
     /// Whether or not the container can be made through conversion from the type `Ident`.
     pub from_ident: bool,
+
+    /// Whether to read the searched attributes from inside `#[cfg_attr(...)]` as well.
+    pub unwrap_cfg_attr: Option<bool>,
+
+    /// The field on the target struct which should receive the predicates of the unwrapped
+    /// `cfg_attr`s that options were read from, if any.
+    pub cfg_predicates: Option<Ident>,
 }
 
 impl OuterFrom {
@@ -34,9 +49,13 @@ impl OuterFrom {
             container: Core::start(di)?,
             attrs: Default::default(),
             ident: Default::default(),
+            docs: Default::default(),
+            read_docs: Default::default(),
             attr_names: Default::default(),
             forward_attrs: Default::default(),
             from_ident: Default::default(),
+            unwrap_cfg_attr: Default::default(),
+            cfg_predicates: Default::default(),
         })
     }
 }
@@ -57,6 +76,18 @@ impl ParseAttribute for OuterFrom {
                 span: path.span(),
             });
             self.from_ident = true;
+        } else if path.is_ident("unwrap_cfg_attr") {
+            if self.unwrap_cfg_attr.is_some() {
+                return Err(Error::duplicate_field("unwrap_cfg_attr").with_span(mi));
+            }
+
+            self.unwrap_cfg_attr = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("docs") {
+            if self.read_docs.is_some() {
+                return Err(Error::duplicate_field("docs").with_span(mi));
+            }
+
+            self.read_docs = FromMeta::from_meta(mi)?;
         } else {
             return self.container.parse_nested(mi);
         }
@@ -75,7 +106,48 @@ impl ParseData for OuterFrom {
                 self.attrs = field.ident.clone();
                 Ok(())
             }
+            Some("docs") if self.read_docs.unwrap_or_default() => {
+                self.docs = field.ident.clone();
+                Ok(())
+            }
+            Some("cfg_predicates") if self.unwrap_cfg_attr.unwrap_or_default() => {
+                self.cfg_predicates = field.ident.clone();
+                Ok(())
+            }
             _ => self.container.parse_field(field),
         }
     }
+
+    fn core(&self) -> Option<&Core> {
+        Some(&self.container)
+    }
+}
+
+impl<'a> From<&'a OuterFrom> for TraitImpl<'a> {
+    fn from(v: &'a OuterFrom) -> Self {
+        TraitImpl {
+            unwrap_cfg_attr: v.unwrap_cfg_attr.unwrap_or_default(),
+            docs: v.docs.as_ref(),
+            cfg_predicates: v.cfg_predicates.as_ref(),
+            ..(&v.container).into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use crate::options::FdiOptions;
+
+    #[test]
+    fn duplicate_unwrap_cfg_attr() {
+        let err = FdiOptions::new(&parse_quote! {
+            #[darling(unwrap_cfg_attr, unwrap_cfg_attr = false)]
+            struct Receiver;
+        })
+        .err()
+        .unwrap();
+        assert_eq!(err.to_string(), "Duplicate field `unwrap_cfg_attr`");
+    }
 }
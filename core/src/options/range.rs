@@ -0,0 +1,183 @@
+use quote::{quote, ToTokens};
+use syn::{Expr, ExprLit, ExprUnary, Lit, UnOp};
+
+use crate::ast::NestedMeta;
+use crate::util::path_to_string;
+use crate::{Error, FromMeta, Result};
+
+/// Inclusive bounds for a numeric field, from `#[darling(range(min = 1, max = 64))]`.
+#[derive(Debug, Clone)]
+pub struct NumericRange {
+    pub min: Option<RangeBound>,
+    pub max: Option<RangeBound>,
+}
+
+impl NumericRange {
+    /// Describe the accepted values, for use in errors.
+    pub fn describe(&self) -> String {
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) => format!("a value from {} to {}", min.text, max.text),
+            (Some(min), None) => format!("a value of at least {}", min.text),
+            (None, Some(max)) => format!("a value of at most {}", max.text),
+            (None, None) => unreachable!("Ranges have at least one bound"),
+        }
+    }
+}
+
+impl FromMeta for NumericRange {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        let mut errors = Error::accumulator();
+        let mut min = None;
+        let mut max = None;
+
+        for item in items {
+            let meta = match item {
+                NestedMeta::Meta(meta) => meta,
+                NestedMeta::Lit(lit) => {
+                    errors.push(Error::unsupported_format("literal").with_span(lit));
+                    continue;
+                }
+            };
+
+            let path = meta.path();
+            let slot = if path.is_ident("min") {
+                &mut min
+            } else if path.is_ident("max") {
+                &mut max
+            } else {
+                errors.push(
+                    Error::unknown_field_with_alts(&path_to_string(path), &["min", "max"])
+                        .with_span(path),
+                );
+                continue;
+            };
+
+            if slot.is_some() {
+                errors.push(Error::duplicate_field_path(path).with_span(meta));
+                continue;
+            }
+
+            *slot = errors.handle(
+                Expr::from_meta(meta)
+                    .and_then(RangeBound::new)
+                    .map_err(|e| e.at_path(path)),
+            );
+        }
+
+        if let (Some(min), Some(max)) = (&min, &max) {
+            if min.value > max.value {
+                errors.push(
+                    Error::custom(format!(
+                        "`min` of {} is greater than `max` of {}",
+                        min.text, max.text
+                    ))
+                    .with_span(&min.expr),
+                );
+            }
+        }
+
+        errors.finish()?;
+
+        if min.is_none() && max.is_none() {
+            return Err(Error::custom("Expected `min`, `max`, or both"));
+        }
+
+        Ok(Self { min, max })
+    }
+}
+
+/// One end of a `NumericRange`: a numeric literal, which may be negated.
+#[derive(Debug, Clone)]
+pub struct RangeBound {
+    /// The bound as written, which generated code compares values against.
+    pub expr: Expr,
+    /// The bound as a float literal, if it's written as an unsuffixed integer, so that it can
+    /// also be compared against float values.
+    pub float: Option<Expr>,
+    text: String,
+    value: f64,
+}
+
+impl RangeBound {
+    fn new(expr: Expr) -> Result<Self> {
+        let (negated, lit) = match &expr {
+            Expr::Lit(ExprLit { lit, .. }) => (false, lit),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr: inner,
+                ..
+            }) => match &**inner {
+                Expr::Lit(ExprLit { lit, .. }) => (true, lit),
+                _ => return Err(Error::unexpected_expr_type(&expr).with_span(&expr)),
+            },
+            _ => return Err(Error::unexpected_expr_type(&expr).with_span(&expr)),
+        };
+
+        let sign = if negated { "-" } else { "" };
+        let (value, text, float) = match lit {
+            Lit::Int(int) => (
+                int.base10_parse::<f64>()?,
+                int.to_string(),
+                if int.suffix().is_empty() {
+                    Some(syn::parse_str(&format!(
+                        "{}{}.0",
+                        sign,
+                        int.base10_digits()
+                    ))?)
+                } else {
+                    None
+                },
+            ),
+            Lit::Float(float) => (float.base10_parse::<f64>()?, float.to_string(), None),
+            _ => return Err(Error::unexpected_lit_type(lit).with_span(lit)),
+        };
+
+        Ok(Self {
+            float,
+            text: format!("{}{}", sign, text),
+            value: if negated { -value } else { value },
+            expr,
+        })
+    }
+}
+
+impl ToTokens for RangeBound {
+    /// Emit the bound converted to the type of the value it's compared against, `__v`.
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let expr = &self.expr;
+        match &self.float {
+            Some(float) => tokens.extend(quote!(
+                ::darling::util::range_bound(&__v, #expr, #float)
+            )),
+            None => expr.to_tokens(tokens),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::NumericRange;
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn bounds() {
+        let range = parse_meta::<NumericRange>(quote!(range(min = 1, max = 64))).unwrap();
+        assert_eq!(range.describe(), "a value from 1 to 64");
+        let range = parse_meta::<NumericRange>(quote!(range(min = -2.5))).unwrap();
+        assert_eq!(range.describe(), "a value of at least -2.5");
+        let range = parse_meta::<NumericRange>(quote!(range(max = "10"))).unwrap();
+        assert_eq!(range.describe(), "a value of at most 10");
+    }
+
+    #[test]
+    fn rejects_bad_bounds() {
+        parse_meta::<NumericRange>(quote!(range())).unwrap_err();
+        parse_meta::<NumericRange>(quote!(range(min = 5, max = 1))).unwrap_err();
+        parse_meta::<NumericRange>(quote!(range(min = 1, min = 2))).unwrap_err();
+        parse_meta::<NumericRange>(quote!(range(min = "one"))).unwrap_err();
+        parse_meta::<NumericRange>(quote!(range(min = -x))).unwrap_err();
+        parse_meta::<NumericRange>(quote!(range(step = 1))).unwrap_err();
+    }
+}
@@ -0,0 +1,90 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use crate::codegen::ToMetaItemImpl;
+use crate::options::{Core, ParseAttribute, ParseData};
+use crate::{Error, Result};
+
+/// Options for deriving `ToMetaItem`. These are the same as the options for `FromMeta`, so
+/// that a type deriving both renders each field under the name it's parsed from.
+pub struct ToMetaItemOptions {
+    base: Core,
+}
+
+impl ToMetaItemOptions {
+    pub fn new(di: &syn::DeriveInput) -> Result<Self> {
+        // A tuple struct with several fields has no name for each field to be rendered under.
+        if let syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(ref fields),
+            ..
+        }) = di.data
+        {
+            if fields.unnamed.len() > 1 {
+                return Err(Error::unsupported_shape("multi-field tuple").with_span(fields));
+            }
+        }
+
+        (ToMetaItemOptions {
+            base: Core::start(di)?,
+        })
+        .parse_attributes(&di.attrs)?
+        .parse_body(&di.data)
+    }
+}
+
+impl ParseAttribute for ToMetaItemOptions {
+    fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
+        self.base.parse_nested(mi)
+    }
+}
+
+impl ParseData for ToMetaItemOptions {
+    fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
+        self.base.parse_variant(variant)
+    }
+
+    fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
+        self.base.parse_field(field)
+    }
+
+    fn core(&self) -> Option<&Core> {
+        Some(&self.base)
+    }
+}
+
+impl<'a> From<&'a ToMetaItemOptions> for ToMetaItemImpl<'a> {
+    fn from(v: &'a ToMetaItemOptions) -> Self {
+        ToMetaItemImpl {
+            base: (&v.base).into(),
+        }
+    }
+}
+
+impl ToTokens for ToMetaItemOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        ToMetaItemImpl::from(self).to_tokens(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::ToMetaItemOptions;
+
+    #[test]
+    fn rejects_multi_field_tuple() {
+        let err = ToMetaItemOptions::new(&parse_quote! {
+            struct Pair(u8, u8);
+        })
+        .err()
+        .unwrap();
+        assert!(err.has_span());
+        assert!(err.to_string().contains("multi-field tuple"));
+
+        ToMetaItemOptions::new(&parse_quote! {
+            struct Newtype(u8);
+        })
+        .unwrap();
+    }
+}
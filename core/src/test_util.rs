@@ -0,0 +1,12 @@
+//! Helpers shared by the unit tests of `FromMeta` impls.
+
+use proc_macro2::TokenStream;
+use syn::parse_quote;
+
+use crate::{FromMeta, Result};
+
+/// Parse `tokens`, written as the contents of an attribute such as `ignore = "a"`, as a `T`.
+pub fn parse_meta<T: FromMeta>(tokens: TokenStream) -> Result<T> {
+    let attr: syn::Attribute = parse_quote!(#[#tokens]);
+    T::from_meta(&attr.meta)
+}
@@ -0,0 +1,397 @@
+use std::borrow::Cow;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use proc_macro2::Span;
+use quote::ToTokens;
+use syn::{parse_quote, Expr, ExprLit, Lit, Meta, MetaNameValue, Path};
+
+use crate::ast::NestedMeta;
+use crate::util::{meta_list, pretty_meta};
+
+/// Render a value back into an item of an attribute declaration, the reverse of
+/// [`FromMeta`](crate::FromMeta).
+///
+/// For any value `v` of a type which implements both traits, `FromMeta::from_meta` on the
+/// rendered item produces a value equal to `v`. This makes it possible to write round-trip
+/// tests for receivers, to copy parsed options onto generated items, and to build
+/// attributes programmatically.
+///
+/// # Deriving `ToMetaItem`
+/// The derive reads the same `#[darling(...)]` attributes as `FromMeta`, so renamed fields
+/// are rendered with the name they're parsed from:
+///
+/// * Skipped fields and `warnings` fields aren't rendered.
+/// * `multiple` fields render each element as a separate item with the field's name.
+/// * `flatten` and `rest` fields splice their items into the parent list.
+/// * Fields with a custom `with` parser are rendered with their type's `ToMetaItem` impl.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta, ToMetaItem)]
+/// struct Lorem {
+///     ipsum: bool,
+///     dolor: Option<String>,
+/// }
+///
+/// let lorem = Lorem { ipsum: true, dolor: None };
+/// let attr = lorem.to_attribute(&parse_quote!(lorem)).unwrap();
+/// // #[lorem(ipsum = true)]
+/// ```
+pub trait ToMetaItem {
+    /// Render `self` as the meta item named `path`, or `None` if the item should be left out
+    /// entirely, as for `Option::None` or an absent `Flag`.
+    fn to_meta_item(&self, path: &Path) -> Option<Meta>;
+
+    /// Render `self` as an outer attribute named `path`, such as `#[path(...)]`.
+    fn to_attribute(&self, path: &Path) -> Option<syn::Attribute> {
+        let meta = self.to_meta_item(path)?;
+        Some(parse_quote!(#[#meta]))
+    }
+
+    /// Render `self` as the meta item named `path` for people to read, such as when
+    /// reporting what a macro parsed. See [`pretty_meta`] for the format. Values which
+    /// render as no item produce an empty string.
+    fn to_pretty_string(&self, path: &Path) -> String {
+        self.to_meta_item(path)
+            .map(|meta| pretty_meta(&meta))
+            .unwrap_or_default()
+    }
+
+    /// Render `self` as the items of a list, without a name of its own. This is used for
+    /// `flatten` and `rest` fields, whose items are spliced into the list of the parent.
+    /// Values which don't render as a list produce no items.
+    fn to_nested_items(&self) -> Vec<NestedMeta> {
+        match self.to_meta_item(&parse_quote!(flatten)) {
+            Some(Meta::List(list)) => NestedMeta::parse_meta_list(list.tokens).unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+}
+
+/// Create the item `path = value`.
+fn name_value(path: &Path, value: Expr) -> Meta {
+    Meta::NameValue(MetaNameValue {
+        path: path.clone(),
+        eq_token: Default::default(),
+        value,
+    })
+}
+
+fn lit_expr(lit: Lit) -> Expr {
+    Expr::Lit(ExprLit { attrs: vec![], lit })
+}
+
+fn str_expr(value: &str) -> Expr {
+    lit_expr(syn::LitStr::new(value, Span::call_site()).into())
+}
+
+/// Render a number from its decimal representation, writing a negative number as a negated
+/// literal because literals can't carry a sign.
+fn number_expr(repr: &str, make_lit: fn(&str) -> Lit) -> Expr {
+    match repr.strip_prefix('-') {
+        Some(digits) => {
+            let lit = lit_expr(make_lit(digits));
+            parse_quote!(-#lit)
+        }
+        None => lit_expr(make_lit(repr)),
+    }
+}
+
+fn int_lit(digits: &str) -> Lit {
+    syn::LitInt::new(digits, Span::call_site()).into()
+}
+
+fn float_lit(digits: &str) -> Lit {
+    syn::LitFloat::new(digits, Span::call_site()).into()
+}
+
+impl ToMetaItem for () {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        Some(Meta::Path(path.clone()))
+    }
+}
+
+impl ToMetaItem for bool {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        Some(name_value(
+            path,
+            lit_expr(syn::LitBool::new(*self, Span::call_site()).into()),
+        ))
+    }
+}
+
+impl ToMetaItem for char {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        Some(name_value(
+            path,
+            lit_expr(syn::LitChar::new(*self, Span::call_site()).into()),
+        ))
+    }
+}
+
+impl ToMetaItem for str {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        Some(name_value(path, str_expr(self)))
+    }
+}
+
+/// Generate an impl of `ToMetaItem` for a string type which derefs to `str`.
+macro_rules! to_meta_item_str {
+    ($ty:ty) => {
+        impl ToMetaItem for $ty {
+            fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+                str::to_meta_item(self, path)
+            }
+        }
+    };
+}
+
+to_meta_item_str!(String);
+to_meta_item_str!(Cow<'static, str>);
+
+impl ToMetaItem for std::path::PathBuf {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        Some(name_value(path, str_expr(&self.to_string_lossy())))
+    }
+}
+
+/// Generate an impl of `ToMetaItem` for an integer type, whose `Display` impl writes its
+/// decimal digits.
+macro_rules! to_meta_item_int {
+    ($($ty:ident),*) => {
+        $(
+            impl ToMetaItem for $ty {
+                fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+                    Some(name_value(path, number_expr(&self.to_string(), int_lit)))
+                }
+            }
+        )*
+    };
+}
+
+to_meta_item_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+to_meta_item_int!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize
+);
+
+/// Generate an impl of `ToMetaItem` for a float type. `Debug` is used because it always
+/// writes a decimal point or exponent, and values which have no literal form, such as
+/// `NaN`, are written as strings.
+macro_rules! to_meta_item_float {
+    ($($ty:ident),*) => {
+        $(
+            impl ToMetaItem for $ty {
+                fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+                    let value = if self.is_finite() {
+                        number_expr(&format!("{:?}", self), float_lit)
+                    } else {
+                        str_expr(&self.to_string())
+                    };
+
+                    Some(name_value(path, value))
+                }
+            }
+        )*
+    };
+}
+
+to_meta_item_float!(f32, f64);
+
+impl ToMetaItem for syn::Expr {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        Some(name_value(path, self.clone()))
+    }
+}
+
+impl ToMetaItem for syn::Lit {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        Some(name_value(path, lit_expr(self.clone())))
+    }
+}
+
+impl ToMetaItem for syn::Path {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        let value = self;
+        Some(name_value(path, parse_quote!(#value)))
+    }
+}
+
+impl ToMetaItem for syn::Ident {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        let value = self;
+        Some(name_value(path, parse_quote!(#value)))
+    }
+}
+
+/// Generate an impl of `ToMetaItem` for a syntax tree type which is written as a string
+/// containing its tokens.
+macro_rules! to_meta_item_tokens_str {
+    ($($ty:path),*) => {
+        $(
+            impl ToMetaItem for $ty {
+                fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+                    Some(name_value(path, str_expr(&self.to_token_stream().to_string())))
+                }
+            }
+        )*
+    };
+}
+
+to_meta_item_tokens_str!(
+    syn::Type,
+    syn::TypePath,
+    syn::WhereClause,
+    syn::WherePredicate,
+    syn::Lifetime
+);
+
+/// The item is rendered as itself, ignoring `path`, because `FromMeta` for `syn::Meta` keeps
+/// the entire item it was parsed from, including its name.
+impl ToMetaItem for syn::Meta {
+    fn to_meta_item(&self, _path: &Path) -> Option<Meta> {
+        Some(self.clone())
+    }
+}
+
+impl<T: ToMetaItem + ?Sized> ToMetaItem for &T {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        (**self).to_meta_item(path)
+    }
+}
+
+impl<T: ToMetaItem> ToMetaItem for Option<T> {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        self.as_ref()?.to_meta_item(path)
+    }
+}
+
+/// Generate an impl of `ToMetaItem` for a smart pointer, which is rendered as its contents.
+macro_rules! to_meta_item_pointer {
+    ($($ty:ident),*) => {
+        $(
+            impl<T: ToMetaItem + ?Sized> ToMetaItem for $ty<T> {
+                fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+                    (**self).to_meta_item(path)
+                }
+            }
+        )*
+    };
+}
+
+to_meta_item_pointer!(Box, Rc, Arc);
+
+/// Elements which render as a value under `path` are written as an array, such as
+/// `path = [1, 2]`, and any others are written as a list, such as `path(a(x = 1), b)`.
+impl<T: ToMetaItem> ToMetaItem for Vec<T> {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        let items: Vec<Meta> = self
+            .iter()
+            .filter_map(|item| item.to_meta_item(path))
+            .collect();
+
+        let values = items
+            .iter()
+            .map(|item| match item {
+                Meta::NameValue(nv) if nv.path == *path => Some(&nv.value),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        Some(match values {
+            Some(values) => name_value(path, parse_quote!([#(#values),*])),
+            None => meta_list(path, items.into_iter().map(NestedMeta::Meta).collect()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use quote::{quote, ToTokens};
+    use syn::parse_quote;
+
+    use super::ToMetaItem;
+    use crate::FromMeta;
+
+    fn render<T: ToMetaItem>(value: T) -> TokenStream {
+        value
+            .to_meta_item(&parse_quote!(ignore))
+            .map_or_else(TokenStream::new, ToTokens::into_token_stream)
+    }
+
+    /// Check that `value` renders as `expected`, and that parsing the rendered item gives
+    /// back `value`.
+    fn round_trip<T>(value: T, expected: TokenStream)
+    where
+        T: ToMetaItem + FromMeta + PartialEq + std::fmt::Debug,
+    {
+        let meta = value.to_meta_item(&parse_quote!(ignore)).unwrap();
+        assert_eq!(meta.to_token_stream().to_string(), expected.to_string());
+        assert_eq!(T::from_meta(&meta).unwrap(), value);
+    }
+
+    #[test]
+    fn scalars() {
+        round_trip(true, quote!(ignore = true));
+        round_trip('#', quote!(ignore = '#'));
+        round_trip(String::from("a \"b\""), quote!(ignore = "a \"b\""));
+        round_trip(404u16, quote!(ignore = 404));
+        round_trip(-4i8, quote!(ignore = -4));
+        round_trip(i64::MIN, quote!(ignore = -9223372036854775808));
+        round_trip(1.0f64, quote!(ignore = 1.0));
+        round_trip(-0.25f32, quote!(ignore = -0.25));
+        round_trip(f64::INFINITY, quote!(ignore = "inf"));
+    }
+
+    #[test]
+    fn syntax() {
+        round_trip::<syn::Path>(parse_quote!(a::b), quote!(ignore = a::b));
+        round_trip::<syn::Ident>(parse_quote!(c), quote!(ignore = c));
+        round_trip::<syn::Expr>(parse_quote!(1 + 2), quote!(ignore = 1 + 2));
+        round_trip::<syn::Type>(parse_quote!(Vec<u8>), quote!(ignore = "Vec < u8 >"));
+        round_trip::<syn::Meta>(parse_quote!(ignore(a, b = 1)), quote!(ignore(a, b = 1)));
+    }
+
+    #[test]
+    fn wrappers() {
+        round_trip(Some(5u8), quote!(ignore = 5));
+        assert!(render(None::<u8>).is_empty());
+        round_trip(Box::new(true), quote!(ignore = true));
+        assert_eq!(render(()).to_string(), quote!(ignore).to_string());
+    }
+
+    #[test]
+    fn sequences() {
+        round_trip(vec![1u8, 2], quote!(ignore = [1, 2]));
+        round_trip(Vec::<String>::new(), quote!(ignore = []));
+
+        let metas: Vec<syn::Meta> = vec![parse_quote!(a = 1), parse_quote!(b(c))];
+        round_trip(metas.clone(), quote!(ignore(a = 1, b(c))));
+        assert_eq!(metas.to_nested_items().len(), 2);
+    }
+
+    #[test]
+    fn attribute() {
+        let attr = true.to_attribute(&parse_quote!(lorem)).unwrap();
+        assert_eq!(
+            attr.to_token_stream().to_string(),
+            quote!(#[lorem = true]).to_string()
+        );
+    }
+}
@@ -3,6 +3,7 @@ use syn::punctuated::Punctuated;
 use syn::{Lifetime, Type};
 
 use crate::usage::Options;
+use crate::util::SpannedValue;
 
 /// A set of lifetimes.
 pub type LifetimeSet = FnvHashSet<Lifetime>;
@@ -73,6 +74,42 @@ where
     }
 }
 
+impl UsesLifetimes for () {
+    fn uses_lifetimes<'a>(&self, _: &Options, _: &'a LifetimeSet) -> LifetimeRefSet<'a> {
+        Default::default()
+    }
+}
+
+impl<T: UsesLifetimes + ?Sized> UsesLifetimes for &T {
+    fn uses_lifetimes<'a>(
+        &self,
+        options: &Options,
+        lifetimes: &'a LifetimeSet,
+    ) -> LifetimeRefSet<'a> {
+        (**self).uses_lifetimes(options, lifetimes)
+    }
+}
+
+impl<T: UsesLifetimes + ?Sized> UsesLifetimes for Box<T> {
+    fn uses_lifetimes<'a>(
+        &self,
+        options: &Options,
+        lifetimes: &'a LifetimeSet,
+    ) -> LifetimeRefSet<'a> {
+        (**self).uses_lifetimes(options, lifetimes)
+    }
+}
+
+impl<T: UsesLifetimes> UsesLifetimes for SpannedValue<T> {
+    fn uses_lifetimes<'a>(
+        &self,
+        options: &Options,
+        lifetimes: &'a LifetimeSet,
+    ) -> LifetimeRefSet<'a> {
+        (**self).uses_lifetimes(options, lifetimes)
+    }
+}
+
 impl<T: UsesLifetimes> UsesLifetimes for Vec<T> {
     fn uses_lifetimes<'a>(
         &self,
@@ -120,6 +157,7 @@ uses_lifetimes!(syn::Constraint, bounds);
 uses_lifetimes!(syn::DataEnum, variants);
 uses_lifetimes!(syn::DataStruct, fields);
 uses_lifetimes!(syn::DataUnion, fields);
+uses_lifetimes!(syn::DeriveInput, data);
 uses_lifetimes!(syn::Field, ty);
 uses_lifetimes!(syn::FieldsNamed, named);
 uses_lifetimes!(syn::LifetimeParam, lifetime, bounds);
@@ -136,7 +174,6 @@ uses_lifetimes!(syn::TypeGroup, elem);
 uses_lifetimes!(syn::TypeImplTrait, bounds);
 uses_lifetimes!(syn::TypeParam, bounds);
 uses_lifetimes!(syn::TypeParen, elem);
-uses_lifetimes!(syn::TypePtr, elem);
 uses_lifetimes!(syn::TypeReference, lifetime, elem);
 uses_lifetimes!(syn::TypeSlice, elem);
 uses_lifetimes!(syn::TypeTuple, elems);
@@ -193,6 +230,20 @@ impl UsesLifetimes for syn::Fields {
     }
 }
 
+impl UsesLifetimes for syn::TypePtr {
+    fn uses_lifetimes<'a>(
+        &self,
+        options: &Options,
+        lifetimes: &'a LifetimeSet,
+    ) -> LifetimeRefSet<'a> {
+        if options.include_raw_pointer_elem() {
+            self.elem.uses_lifetimes(options, lifetimes)
+        } else {
+            Default::default()
+        }
+    }
+}
+
 impl UsesLifetimes for syn::TypePath {
     fn uses_lifetimes<'a>(
         &self,
@@ -333,6 +384,45 @@ mod tests {
         assert_eq!(matches.len(), 2);
     }
 
+    #[test]
+    fn wrappers_and_derive_input() {
+        let input: DeriveInput = parse_quote! {
+            struct Foo<'a, 'b> {
+                name: &'a str,
+                count: usize,
+            }
+        };
+        let lifetimes = input.generics.declared_lifetimes();
+        let matches = input.uses_lifetimes(&BoundImpl.into(), &lifetimes);
+        assert_eq!(matches.len(), 1);
+        assert!(matches.contains(&syn::Lifetime::new("'a", Span::call_site())));
+
+        let ty: syn::Type = parse_quote!(Option<&'b str>);
+        let boxed = Box::new(ty.clone());
+        assert_eq!(boxed.uses_lifetimes(&BoundImpl.into(), &lifetimes).len(), 1);
+        let by_ref: &syn::Type = &ty;
+        assert_eq!(
+            UsesLifetimes::uses_lifetimes(&by_ref, &BoundImpl.into(), &lifetimes).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn raw_pointer() {
+        let input: DeriveInput = parse_quote! {
+            struct Foo<'a, 'b> {
+                parent: *const Bar<'a>,
+                child: &'b Baz,
+            }
+        };
+        let lifetimes = input.generics.declared_lifetimes();
+        let matches = input.data.uses_lifetimes(&BoundImpl.into(), &lifetimes);
+        assert_eq!(matches.len(), 1);
+
+        let decl_matches = input.data.uses_lifetimes(&Declare.into(), &lifetimes);
+        assert_eq!(decl_matches.len(), 2);
+    }
+
     #[test]
     fn qself() {
         let input: DeriveInput = parse_quote! {
@@ -10,7 +10,8 @@
 pub enum Purpose {
     /// The tracing is being used to generate an `impl` block.
     ///
-    /// Uses such as `syn::TypePath.qself` will _not_ be returned.
+    /// Uses such as `syn::TypePath.qself` and the pointee of a raw pointer will _not_ be
+    /// returned, since they don't need bounds.
     BoundImpl,
     /// The tracing is being used to generate a new struct or enum.
     ///
@@ -31,7 +32,9 @@ pub enum Purpose {
 /// ```rust
 /// # use darling_core::usage::{Options, Purpose};
 /// let opts: Options = Purpose::BoundImpl.into();
+/// assert_eq!(opts.purpose(), Purpose::BoundImpl);
 /// assert!(!opts.include_type_path_qself());
+/// assert!(!opts.include_raw_pointer_elem());
 /// ```
 #[derive(Debug, Clone)]
 pub struct Options {
@@ -50,9 +53,21 @@ impl From<Purpose> for Options {
 }
 
 impl Options {
+    /// Returns the goal this search is being performed for.
+    pub fn purpose(&self) -> Purpose {
+        self.purpose
+    }
+
     /// Returns `true` if the implementer of `UseTypeParams` should search
     /// `<___ as ...>::...` when looking for type parameter uses.
     pub fn include_type_path_qself(&self) -> bool {
         self.purpose == Purpose::Declare
     }
+
+    /// Returns `true` if the pointee of a raw pointer such as `*const T` should be searched.
+    /// Raw pointers implement traits without regard to their pointee, so generated impls
+    /// don't need bounds on it.
+    pub fn include_raw_pointer_elem(&self) -> bool {
+        self.purpose == Purpose::Declare
+    }
 }
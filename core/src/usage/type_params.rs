@@ -2,6 +2,7 @@ use syn::punctuated::Punctuated;
 use syn::{Ident, Type};
 
 use crate::usage::{IdentRefSet, IdentSet, Options};
+use crate::util::SpannedValue;
 
 /// Searcher for finding type params in a syntax tree.
 /// This can be used to determine if a given type parameter needs to be bounded in a generated impl.
@@ -80,6 +81,24 @@ impl<T: UsesTypeParams> UsesTypeParams for Vec<T> {
     }
 }
 
+impl<T: UsesTypeParams + ?Sized> UsesTypeParams for &T {
+    fn uses_type_params<'a>(&self, options: &Options, type_set: &'a IdentSet) -> IdentRefSet<'a> {
+        (**self).uses_type_params(options, type_set)
+    }
+}
+
+impl<T: UsesTypeParams + ?Sized> UsesTypeParams for Box<T> {
+    fn uses_type_params<'a>(&self, options: &Options, type_set: &'a IdentSet) -> IdentRefSet<'a> {
+        (**self).uses_type_params(options, type_set)
+    }
+}
+
+impl<T: UsesTypeParams> UsesTypeParams for SpannedValue<T> {
+    fn uses_type_params<'a>(&self, options: &Options, type_set: &'a IdentSet) -> IdentRefSet<'a> {
+        (**self).uses_type_params(options, type_set)
+    }
+}
+
 impl<T: UsesTypeParams, U> UsesTypeParams for Punctuated<T, U> {
     fn uses_type_params<'a>(&self, options: &Options, type_set: &'a IdentSet) -> IdentRefSet<'a> {
         self.collect_type_params(options, type_set)
@@ -93,6 +112,7 @@ uses_type_params!(syn::Constraint, bounds);
 uses_type_params!(syn::DataEnum, variants);
 uses_type_params!(syn::DataStruct, fields);
 uses_type_params!(syn::DataUnion, fields);
+uses_type_params!(syn::DeriveInput, data);
 uses_type_params!(syn::Field, ty);
 uses_type_params!(syn::FieldsNamed, named);
 uses_type_params!(syn::ParenthesizedGenericArguments, inputs, output);
@@ -104,7 +124,6 @@ uses_type_params!(syn::TypeBareFn, inputs, output);
 uses_type_params!(syn::TypeGroup, elem);
 uses_type_params!(syn::TypeImplTrait, bounds);
 uses_type_params!(syn::TypeParen, elem);
-uses_type_params!(syn::TypePtr, elem);
 uses_type_params!(syn::TypeReference, elem);
 uses_type_params!(syn::TypeSlice, elem);
 uses_type_params!(syn::TypeTuple, elems);
@@ -166,6 +185,16 @@ impl UsesTypeParams for Type {
     }
 }
 
+impl UsesTypeParams for syn::TypePtr {
+    fn uses_type_params<'a>(&self, options: &Options, type_set: &'a IdentSet) -> IdentRefSet<'a> {
+        if options.include_raw_pointer_elem() {
+            self.elem.uses_type_params(options, type_set)
+        } else {
+            Default::default()
+        }
+    }
+}
+
 impl UsesTypeParams for syn::TypePath {
     fn uses_type_params<'a>(&self, options: &Options, type_set: &'a IdentSet) -> IdentRefSet<'a> {
         let hits = self.path.uses_type_params(options, type_set);
@@ -346,6 +375,39 @@ mod tests {
         assert!(matches.contains::<Ident>(&parse_quote!(T)));
     }
 
+    #[test]
+    fn wrappers_and_derive_input() {
+        let input: DeriveInput = parse_quote! { struct Foo<T, U>(Vec<T>, u8); };
+        let generics = ident_set(vec!["T", "U"]);
+        let matches = input.uses_type_params(&BoundImpl.into(), &generics);
+        assert_eq!(matches.len(), 1);
+        assert!(matches.contains::<Ident>(&parse_quote!(T)));
+
+        let ty: syn::Type = parse_quote!(Option<U>);
+        let boxed = Box::new(ty.clone());
+        assert_eq!(
+            boxed.uses_type_params(&BoundImpl.into(), &generics).len(),
+            1
+        );
+        let by_ref: &syn::Type = &ty;
+        assert_eq!(
+            UsesTypeParams::uses_type_params(&by_ref, &BoundImpl.into(), &generics).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn raw_pointer() {
+        let input: DeriveInput = parse_quote! { struct Foo<T, U>(*const T, Box<*mut U>); };
+        let generics = ident_set(vec!["T", "U"]);
+
+        let bound_matches = input.data.uses_type_params(&BoundImpl.into(), &generics);
+        assert_eq!(bound_matches.len(), 0);
+
+        let declare_matches = input.data.uses_type_params(&Declare.into(), &generics);
+        assert_eq!(declare_matches.len(), 2);
+    }
+
     /// Test that `syn::TypePath` is correctly honoring the different modes a
     /// search can execute in.
     #[test]
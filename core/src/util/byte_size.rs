@@ -0,0 +1,159 @@
+use std::ops::{Deref, DerefMut};
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use crate::{Error, FromMeta, Result};
+
+/// A number of bytes, written as an amount and an optional unit such as `"4KiB"` or `"2MB"`.
+///
+/// Decimal units (`KB`, `MB`, `GB`, `TB`, `PB`) are powers of 1000, and binary units
+/// (`KiB`, `MiB`, `GiB`, `TiB`, `PiB`) are powers of 1024. `B` or no unit at all means bytes.
+/// The unit can also be written as an integer literal suffix, as in `buffer = 4KiB`.
+/// Sizes which don't fit in a `u64` are rejected.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Options {
+///     /// Accepts `buffer = "64KiB"`, `buffer = 64KiB`, or `buffer = 65536`.
+///     buffer: ByteSize,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    /// Get the number of bytes.
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl Deref for ByteSize {
+    type Target = u64;
+
+    fn deref(&self) -> &u64 {
+        &self.0
+    }
+}
+
+impl DerefMut for ByteSize {
+    fn deref_mut(&mut self) -> &mut u64 {
+        &mut self.0
+    }
+}
+
+/// Emits the number of bytes as a `u64` literal.
+impl ToTokens for ByteSize {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(size: ByteSize) -> Self {
+        size.0
+    }
+}
+
+impl FromMeta for ByteSize {
+    fn from_string(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (amount, unit) = s.split_at(split);
+        amount
+            .parse()
+            .ok()
+            .and_then(|amount| bytes_from_parts(amount, unit.trim_start()))
+            .map(ByteSize)
+            .ok_or_else(|| Error::unknown_value(s))
+    }
+
+    fn from_int(value: &syn::LitInt) -> Result<Self> {
+        let amount = value.base10_parse()?;
+        bytes_from_parts(amount, value.suffix())
+            .map(ByteSize)
+            .ok_or_else(|| Error::unknown_value(&value.to_string()))
+    }
+}
+
+/// Multiply `amount` by the size of `unit`, where an empty unit means bytes.
+fn bytes_from_parts(amount: u64, unit: &str) -> Option<u64> {
+    let multiplier: u64 = match unit {
+        "" | "B" => 1,
+        "KB" => 1000,
+        "MB" => 1000u64.pow(2),
+        "GB" => 1000u64.pow(3),
+        "TB" => 1000u64.pow(4),
+        "PB" => 1000u64.pow(5),
+        "KiB" => 1 << 10,
+        "MiB" => 1 << 20,
+        "GiB" => 1 << 30,
+        "TiB" => 1 << 40,
+        "PiB" => 1 << 50,
+        _ => return None,
+    };
+
+    amount.checked_mul(multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::ByteSize;
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            parse_meta::<ByteSize>(quote!(ignore = "512")).unwrap(),
+            ByteSize(512)
+        );
+        assert_eq!(
+            parse_meta::<ByteSize>(quote!(ignore = "2MB")).unwrap(),
+            ByteSize(2_000_000)
+        );
+        assert_eq!(
+            parse_meta::<ByteSize>(quote!(ignore = "4 KiB")).unwrap(),
+            ByteSize(4096)
+        );
+        assert_eq!(
+            parse_meta::<ByteSize>(quote!(ignore = "1GiB")).unwrap(),
+            ByteSize(1 << 30)
+        );
+    }
+
+    #[test]
+    fn literal_suffixes() {
+        assert_eq!(
+            parse_meta::<ByteSize>(quote!(ignore = 4096)).unwrap(),
+            ByteSize(4096)
+        );
+        assert_eq!(
+            parse_meta::<ByteSize>(quote!(ignore = 64KiB)).unwrap(),
+            ByteSize(65536)
+        );
+        assert_eq!(
+            parse_meta::<ByteSize>(quote!(ignore = 3TB)).unwrap(),
+            ByteSize(3_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = parse_meta::<ByteSize>(quote!(ignore = "4kb")).unwrap_err();
+        assert!(err.has_span());
+        parse_meta::<ByteSize>(quote!(ignore = "KiB")).unwrap_err();
+        parse_meta::<ByteSize>(quote!(ignore = "1.5GiB")).unwrap_err();
+        parse_meta::<ByteSize>(quote!(ignore = 20000PiB)).unwrap_err();
+        parse_meta::<ByteSize>(quote!(ignore = -1)).unwrap_err();
+    }
+}
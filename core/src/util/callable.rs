@@ -0,0 +1,131 @@
+use quote::ToTokens;
+
+use crate::{Error, FromMeta, Result};
+
+/// Either a path or a closure.
+///
+/// This type is useful for options that historically took a path, such as
+/// `#[darling(with = ...)]`, but can also accept an inline closure so that a
+/// one-off parsing rule doesn't need a separately-declared function.
+///
+/// For backwards compatibility, a string literal containing a path or closure
+/// is also accepted.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Receiver {
+///     #[darling(with = |m| Ok(String::from_meta(m)?.to_uppercase()))]
+///     shouty: String,
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Callable {
+    call: syn::Expr,
+}
+
+impl Callable {
+    /// Returns `true` if this is a path to a function, rather than a closure.
+    pub fn is_path(&self) -> bool {
+        matches!(self.call, syn::Expr::Path(_))
+    }
+
+    /// Returns the underlying expression.
+    pub fn as_expr(&self) -> &syn::Expr {
+        &self.call
+    }
+}
+
+impl AsRef<syn::Expr> for Callable {
+    fn as_ref(&self) -> &syn::Expr {
+        &self.call
+    }
+}
+
+impl From<syn::ExprPath> for Callable {
+    fn from(value: syn::ExprPath) -> Self {
+        Self {
+            call: syn::Expr::Path(value),
+        }
+    }
+}
+
+impl From<syn::ExprClosure> for Callable {
+    fn from(value: syn::ExprClosure) -> Self {
+        Self {
+            call: syn::Expr::Closure(value),
+        }
+    }
+}
+
+impl From<syn::Path> for Callable {
+    fn from(path: syn::Path) -> Self {
+        syn::ExprPath {
+            attrs: vec![],
+            qself: None,
+            path,
+        }
+        .into()
+    }
+}
+
+impl From<Callable> for syn::Expr {
+    fn from(value: Callable) -> Self {
+        value.call
+    }
+}
+
+impl FromMeta for Callable {
+    fn from_expr(expr: &syn::Expr) -> Result<Self> {
+        match expr {
+            syn::Expr::Path(_) | syn::Expr::Closure(_) => Ok(Self { call: expr.clone() }),
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }) => syn::parse_str::<syn::Expr>(&s.value())
+                .map_err(|_| Error::unknown_lit_str_value(s))
+                .and_then(|call| Self::from_expr(&call))
+                .map_err(|e| e.with_span(s)),
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+impl ToTokens for Callable {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.call.to_tokens(tokens);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::Callable;
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn path_and_closure() {
+        assert!(parse_meta::<Callable>(quote!(ignore = path::to::func))
+            .unwrap()
+            .is_path());
+        assert!(!parse_meta::<Callable>(quote!(ignore = |m| Ok(m)))
+            .unwrap()
+            .is_path());
+    }
+
+    #[test]
+    fn quoted_path() {
+        assert_eq!(
+            parse_meta::<Callable>(quote!(ignore = "path::to::func")).unwrap(),
+            parse_meta::<Callable>(quote!(ignore = path::to::func)).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_other_expressions() {
+        parse_meta::<Callable>(quote!(ignore = 1 + 2)).unwrap_err();
+        parse_meta::<Callable>(quote!(ignore = "1 + 2")).unwrap_err();
+    }
+}
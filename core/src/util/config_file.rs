@@ -0,0 +1,349 @@
+use std::env;
+use std::fs;
+use std::path::{Component, Path};
+
+use proc_macro2::{Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::{quote, TokenStreamExt};
+use syn::spanned::Spanned;
+use syn::Meta;
+
+use crate::ast::NestedMeta;
+use crate::error::Accumulator;
+use crate::util::{path_to_string, Warnings};
+use crate::{Error, FromMeta, Result};
+
+/// Replace the `key = "path"` item in `items` with the options read from that file.
+///
+/// This is used by receivers declaring `#[darling(from_file = "key")]`. The path is relative
+/// to the directory of the crate being compiled, and can't be absolute or contain `..`. The
+/// file is recorded in `warnings`, so that emitting them makes cargo rebuild the crate when the
+/// file changes; a proc-macro can't tell cargo about the file itself on stable Rust, so it isn't
+/// tracked unless the warnings are emitted. `.toml` files require the `toml` feature and
+/// `.json` files require the `json` feature. The file must hold a table whose entries become
+/// items: strings, numbers, and booleans become `name = value`, arrays become `name = [..]`,
+/// and nested tables become `name(..)`. An option set both in the file and in the same attribute
+/// as `key` takes its inline value.
+///
+/// Errors reading the file are added to `errors`, and the remaining items are returned.
+pub fn items_with_file(
+    items: &[NestedMeta],
+    key: &str,
+    errors: &mut Accumulator,
+    warnings: &mut Warnings,
+) -> Vec<NestedMeta> {
+    let mut inline = Vec::with_capacity(items.len());
+    let mut loaded = vec![];
+    let mut seen = false;
+
+    for item in items {
+        match item {
+            NestedMeta::Meta(meta) if path_to_string(meta.path()) == key => {
+                if seen {
+                    errors.push(Error::duplicate_field(key).with_span(meta));
+                    continue;
+                }
+
+                seen = true;
+                if let Some(file_items) = errors.handle(load(meta, warnings)) {
+                    loaded = file_items;
+                }
+            }
+            _ => inline.push(item.clone()),
+        }
+    }
+
+    let inline_names: Vec<String> = inline.iter().filter_map(item_name).collect();
+    loaded.retain(|item| item_name(item).map_or(true, |name| !inline_names.contains(&name)));
+    loaded.extend(inline);
+    loaded
+}
+
+fn item_name(item: &NestedMeta) -> Option<String> {
+    match item {
+        NestedMeta::Meta(meta) => Some(path_to_string(meta.path())),
+        NestedMeta::Lit(_) => None,
+    }
+}
+
+/// Read the file named by `meta` into meta items spanned to the file name in the input.
+fn load(meta: &Meta, warnings: &mut Warnings) -> Result<Vec<NestedMeta>> {
+    let span = match meta {
+        Meta::NameValue(nv) => nv.value.span(),
+        _ => meta.span(),
+    };
+
+    let path = String::from_meta(meta)?;
+    let relative = Path::new(&path);
+    if relative.has_root()
+        || relative
+            .components()
+            .any(|c| matches!(c, Component::Prefix(_) | Component::ParentDir))
+    {
+        return Err(Error::custom(format!(
+            "`{}` must be a path inside the crate directory, without `..`",
+            path
+        ))
+        .with_span(&span));
+    }
+
+    // Without a crate directory the path can't be made absolute, so it can't be tracked.
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR");
+    let full_path = match manifest_dir {
+        Some(ref dir) => Path::new(dir).join(relative),
+        None => relative.to_path_buf(),
+    };
+
+    let value = fs::read_to_string(&full_path)
+        .map_err(|e| Error::custom(format!("Unable to read `{}`: {}", path, e)))
+        .map(|contents| {
+            if manifest_dir.is_some() {
+                warnings.track_file(&full_path);
+            }
+            contents
+        })
+        .and_then(
+            |contents| match full_path.extension().and_then(|ext| ext.to_str()) {
+                Some("toml") => parse_toml(&contents),
+                Some("json") => parse_json(&contents),
+                _ => Err(Error::custom(format!(
+                    "Unsupported file `{}`; expected a `.toml` or `.json` file",
+                    path
+                ))),
+            },
+        )
+        .map_err(|e| e.with_span(&span))?;
+
+    let entries = match value {
+        FileValue::Table(entries) => entries,
+        _ => return Err(Error::custom(format!("`{}` must contain a table", path)).with_span(&span)),
+    };
+
+    let mut tokens = TokenStream::new();
+    for (name, value) in &entries {
+        if let Some(entry) = entry_tokens(name, value, span).map_err(|e| e.with_span(&span))? {
+            tokens.extend(quote!(#entry,));
+        }
+    }
+
+    NestedMeta::parse_meta_list(tokens).map_err(|e| Error::from(e).with_span(&span))
+}
+
+/// A value read from a configuration file, independent of its format.
+#[cfg_attr(not(feature = "json"), allow(dead_code))]
+enum FileValue {
+    Null,
+    Str(String),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Bool(bool),
+    Array(Vec<FileValue>),
+    Table(Vec<(String, FileValue)>),
+}
+
+/// Generate `name = value` or `name(..)` for an entry, or `None` for a null value.
+fn entry_tokens(name: &str, value: &FileValue, span: Span) -> Result<Option<TokenStream>> {
+    // Keys which are keywords, such as `type`, name fields declared as raw identifiers.
+    let ident = if syn::parse_str::<syn::Ident>(name).is_ok() {
+        Ident::new(name, span)
+    } else if syn::parse_str::<syn::Ident>(&format!("r#{}", name)).is_ok() {
+        Ident::new_raw(name, span)
+    } else {
+        return Err(Error::custom(format!(
+            "`{}` is not a valid option name",
+            name
+        )));
+    };
+    Ok(match value {
+        FileValue::Null => None,
+        FileValue::Table(entries) => {
+            let mut nested = TokenStream::new();
+            for (name, value) in entries {
+                if let Some(entry) = entry_tokens(name, value, span)? {
+                    nested.extend(quote!(#entry,));
+                }
+            }
+            Some(quote!(#ident(#nested)))
+        }
+        _ => {
+            let value = value_tokens(value, span)?;
+            Some(quote!(#ident = #value))
+        }
+    })
+}
+
+/// Generate an expression for a value that isn't a table.
+fn value_tokens(value: &FileValue, span: Span) -> Result<TokenStream> {
+    let negative = |literal: Literal, is_negative: bool| {
+        let mut tokens = TokenStream::new();
+        if is_negative {
+            let mut minus = Punct::new('-', Spacing::Alone);
+            minus.set_span(span);
+            tokens.append(minus);
+        }
+        tokens.append(spanned(literal, span));
+        tokens
+    };
+
+    Ok(match value {
+        FileValue::Str(s) => spanned(Literal::string(s), span).into(),
+        FileValue::Int(i) => negative(Literal::u64_unsuffixed(i.unsigned_abs()), *i < 0),
+        FileValue::UInt(u) => spanned(Literal::u64_unsuffixed(*u), span).into(),
+        FileValue::Float(f) if f.is_finite() => {
+            negative(Literal::f64_unsuffixed(f.abs()), f.is_sign_negative())
+        }
+        FileValue::Float(f) => return Err(Error::custom(format!("Unsupported number `{}`", f))),
+        FileValue::Bool(b) => TokenTree::Ident(Ident::new(&b.to_string(), span)).into(),
+        FileValue::Array(elems) => {
+            let elems = elems
+                .iter()
+                .map(|elem| value_tokens(elem, span))
+                .collect::<Result<Vec<_>>>()?;
+            quote!([#(#elems),*])
+        }
+        FileValue::Null | FileValue::Table(_) => {
+            return Err(Error::custom(
+                "Tables and nulls are only supported as entries of a table",
+            ))
+        }
+    })
+}
+
+fn spanned(mut literal: Literal, span: Span) -> TokenTree {
+    literal.set_span(span);
+    TokenTree::Literal(literal)
+}
+
+#[cfg(feature = "toml")]
+fn parse_toml(contents: &str) -> Result<FileValue> {
+    fn convert(value: toml::Value) -> FileValue {
+        match value {
+            toml::Value::String(s) => FileValue::Str(s),
+            toml::Value::Integer(i) => FileValue::Int(i),
+            toml::Value::Float(f) => FileValue::Float(f),
+            toml::Value::Boolean(b) => FileValue::Bool(b),
+            toml::Value::Datetime(d) => FileValue::Str(d.to_string()),
+            toml::Value::Array(elems) => FileValue::Array(elems.into_iter().map(convert).collect()),
+            toml::Value::Table(entries) => FileValue::Table(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, convert(value)))
+                    .collect(),
+            ),
+        }
+    }
+
+    contents
+        .parse::<toml::Value>()
+        .map(convert)
+        .map_err(|e| Error::custom(format!("Invalid TOML: {}", e)))
+}
+
+#[cfg(not(feature = "toml"))]
+fn parse_toml(_: &str) -> Result<FileValue> {
+    Err(Error::custom(
+        "Reading `.toml` files requires the `toml` feature of darling",
+    ))
+}
+
+#[cfg(feature = "json")]
+fn parse_json(contents: &str) -> Result<FileValue> {
+    fn convert(value: serde_json::Value) -> FileValue {
+        match value {
+            serde_json::Value::Null => FileValue::Null,
+            serde_json::Value::String(s) => FileValue::Str(s),
+            serde_json::Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    FileValue::Int(i)
+                } else if let Some(u) = n.as_u64() {
+                    FileValue::UInt(u)
+                } else {
+                    FileValue::Float(n.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            serde_json::Value::Bool(b) => FileValue::Bool(b),
+            serde_json::Value::Array(elems) => {
+                FileValue::Array(elems.into_iter().map(convert).collect())
+            }
+            serde_json::Value::Object(entries) => FileValue::Table(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, convert(value)))
+                    .collect(),
+            ),
+        }
+    }
+
+    serde_json::from_str::<serde_json::Value>(contents)
+        .map(convert)
+        .map_err(|e| Error::custom(format!("Invalid JSON: {}", e)))
+}
+
+#[cfg(not(feature = "json"))]
+fn parse_json(_: &str) -> Result<FileValue> {
+    Err(Error::custom(
+        "Reading `.json` files requires the `json` feature of darling",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+    use quote::quote;
+
+    use super::{entry_tokens, FileValue};
+
+    fn entry(name: &str, value: FileValue) -> String {
+        entry_tokens(name, &value, Span::call_site())
+            .unwrap()
+            .map(|tokens| tokens.to_string())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn entries() {
+        assert_eq!(
+            entry("name", FileValue::Str("a".into())),
+            quote!(name = "a").to_string()
+        );
+        assert_eq!(
+            entry("offset", FileValue::Int(-4)),
+            quote!(offset = -4).to_string()
+        );
+        assert_eq!(
+            entry("ratio", FileValue::Float(-0.5)),
+            quote!(ratio = -0.5).to_string()
+        );
+        assert_eq!(entry("missing", FileValue::Null), "");
+        assert_eq!(
+            entry("type", FileValue::Str("a".into())),
+            quote!(r#type = "a").to_string()
+        );
+        assert_eq!(
+            entry(
+                "nested",
+                FileValue::Table(vec![
+                    ("on".into(), FileValue::Bool(true)),
+                    (
+                        "sizes".into(),
+                        FileValue::Array(vec![FileValue::UInt(1), FileValue::UInt(2)])
+                    ),
+                ])
+            ),
+            quote!(nested(on = true, sizes = [1, 2],)).to_string()
+        );
+    }
+
+    #[test]
+    fn invalid_entries() {
+        entry_tokens("not-an-ident", &FileValue::Bool(true), Span::call_site()).unwrap_err();
+        entry_tokens("self", &FileValue::Bool(true), Span::call_site()).unwrap_err();
+        entry_tokens(
+            "tables",
+            &FileValue::Array(vec![FileValue::Table(vec![])]),
+            Span::call_site(),
+        )
+        .unwrap_err();
+        entry_tokens("nan", &FileValue::Float(f64::NAN), Span::call_site()).unwrap_err();
+    }
+}
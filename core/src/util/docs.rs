@@ -0,0 +1,109 @@
+use syn::{Attribute, Expr, ExprLit, Lit, Meta};
+
+/// Get the lines of the doc comments in `attrs`, in order.
+///
+/// Both `/// line` comments and explicit `#[doc = "..."]` attributes are read. The single space
+/// that conventionally follows `///` is removed, as is trailing whitespace; other indentation is
+/// kept. Doc attributes whose value isn't a string literal, such as `#[doc = include_str!(..)]`,
+/// and `#[doc(hidden)]`-style attributes are skipped.
+pub fn doc_lines(attrs: &[Attribute]) -> Vec<String> {
+    let mut lines = vec![];
+    for attr in attrs {
+        if let Meta::NameValue(nv) = &attr.meta {
+            if !nv.path.is_ident("doc") {
+                continue;
+            }
+
+            if let Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) = &nv.value
+            {
+                let value = s.value();
+                lines.extend(value.split('\n').map(|line| {
+                    line.strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()
+                }));
+            }
+        }
+    }
+    lines
+}
+
+/// A type that can be built from the doc comments of the input.
+///
+/// When a receiver is marked `#[darling(docs)]`, its field named `docs` is filled from the
+/// input's doc comments using this trait, the same way `attrs` receives forwarded attributes.
+pub trait FromDocs: Sized {
+    /// Create an instance from lines returned by [`doc_lines`].
+    fn from_docs(lines: Vec<String>) -> Self;
+}
+
+impl FromDocs for Vec<String> {
+    fn from_docs(lines: Vec<String>) -> Self {
+        lines
+    }
+}
+
+/// The lines joined by newlines, or an empty string if there are no doc comments.
+impl FromDocs for String {
+    fn from_docs(lines: Vec<String>) -> Self {
+        lines.join("\n")
+    }
+}
+
+/// The lines joined by newlines, or `None` if there are no doc comments.
+impl FromDocs for Option<String> {
+    fn from_docs(lines: Vec<String>) -> Self {
+        if lines.is_empty() {
+            None
+        } else {
+            Some(String::from_docs(lines))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::{doc_lines, FromDocs};
+
+    #[test]
+    fn reads_doc_comments() {
+        let item: syn::ItemStruct = parse_quote! {
+            /// First line.
+            ///
+            ///     indented
+            #[doc = "explicit"]
+            #[doc(hidden)]
+            #[derive(Debug)]
+            #[doc = " block\n second"]
+            struct Example;
+        };
+
+        assert_eq!(
+            doc_lines(&item.attrs),
+            vec![
+                "First line.",
+                "",
+                "    indented",
+                "explicit",
+                "block",
+                "second"
+            ]
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let lines = || vec!["a".to_string(), "b".to_string()];
+        assert_eq!(String::from_docs(lines()), "a\nb");
+        assert_eq!(
+            <Option<String>>::from_docs(lines()).as_deref(),
+            Some("a\nb")
+        );
+        assert_eq!(<Option<String>>::from_docs(vec![]), None);
+    }
+}
@@ -0,0 +1,135 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Meta;
+
+use crate::ast::NestedMeta;
+use crate::{Error, FromMeta, Result};
+
+/// A value which is parsed as either `L` or `R`.
+///
+/// `FromMeta` tries `L` first and only falls back to `R` if `L` rejects the input,
+/// so put the stricter type on the left. If both reject the input, the errors from
+/// both attempts are returned.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Receiver {
+///     /// Accepts `rename = "name"` or `rename(serialize = "a", deserialize = "b")`.
+///     rename: Either<String, RenameRules>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    /// Get the left value, discarding a right value.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(value) => Some(value),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Get the right value, discarding a left value.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(value) => Some(value),
+        }
+    }
+
+    pub fn as_ref(&self) -> Either<&L, &R> {
+        match self {
+            Either::Left(value) => Either::Left(value),
+            Either::Right(value) => Either::Right(value),
+        }
+    }
+
+    /// Try `left`, then `right`, keeping the errors from both if neither succeeds.
+    fn parse(left: impl FnOnce() -> Result<L>, right: impl FnOnce() -> Result<R>) -> Result<Self> {
+        left().map(Either::Left).or_else(|left_err| {
+            right()
+                .map(Either::Right)
+                .map_err(|right_err| Error::multiple(vec![left_err, right_err]))
+        })
+    }
+}
+
+impl<L: ToTokens, R: ToTokens> ToTokens for Either<L, R> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Either::Left(value) => value.to_tokens(tokens),
+            Either::Right(value) => value.to_tokens(tokens),
+        }
+    }
+}
+
+impl<L: FromMeta, R: FromMeta> FromMeta for Either<L, R> {
+    fn from_none() -> Option<Self> {
+        L::from_none()
+            .map(Either::Left)
+            .or_else(|| R::from_none().map(Either::Right))
+    }
+
+    fn from_nested_meta(item: &NestedMeta) -> Result<Self> {
+        Self::parse(|| L::from_nested_meta(item), || R::from_nested_meta(item))
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        Self::parse(|| L::from_meta(item), || R::from_meta(item))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::Either;
+    use crate::test_util::parse_meta;
+    use crate::FromMeta;
+
+    type StrOrList = Either<String, Vec<String>>;
+
+    #[test]
+    fn left_first() {
+        assert_eq!(
+            parse_meta::<StrOrList>(quote!(ignore = "a")).unwrap(),
+            Either::Left("a".to_string())
+        );
+    }
+
+    #[test]
+    fn falls_back_to_right() {
+        assert_eq!(
+            parse_meta::<StrOrList>(quote!(ignore("a", "b"))).unwrap(),
+            Either::Right(vec!["a".to_string(), "b".to_string()])
+        );
+    }
+
+    #[test]
+    fn reports_both_errors() {
+        let err = parse_meta::<StrOrList>(quote!(ignore)).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn from_none() {
+        assert_eq!(
+            <Either<Option<u8>, String>>::from_none(),
+            Some(Either::Left(None))
+        );
+        assert_eq!(<Either<String, u8>>::from_none(), None);
+    }
+}
@@ -0,0 +1,174 @@
+use std::cell::RefCell;
+use std::env;
+
+use quote::quote;
+use syn::{Expr, ExprLit, Lit, LitStr, Meta};
+
+use crate::ast::NestedMeta;
+use crate::util::Warnings;
+use crate::{Error, Result};
+
+/// Replace `${VAR}` placeholders in the string literals of `meta` with the values of those
+/// environment variables, as read when the macro is expanded.
+///
+/// This is used by fields marked `#[darling(expand_env)]`. String literals in a value, in an
+/// array value, or anywhere in a list are expanded; other literals are left alone. Write `$$`
+/// for a literal `$`. It's an error for a placeholder to name an unset variable, and the error
+/// points at the string containing it.
+///
+/// Each variable named by a placeholder is recorded in `warnings`, so that emitting them makes
+/// cargo rebuild the crate when the variable changes.
+pub fn expand_env(meta: &Meta, warnings: &mut Warnings) -> Result<Meta> {
+    let names = RefCell::new(vec![]);
+    let expanded = expand_meta(meta, &|name| {
+        names.borrow_mut().push(name.to_string());
+        env::var(name).ok()
+    });
+
+    for name in names.into_inner() {
+        warnings.track_env(name);
+    }
+
+    expanded
+}
+
+/// Looks up the value of a variable by name.
+type Lookup<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+fn expand_meta(meta: &Meta, lookup: Lookup<'_>) -> Result<Meta> {
+    match meta {
+        Meta::Path(_) => Ok(meta.clone()),
+        Meta::NameValue(nv) => {
+            let mut nv = nv.clone();
+            nv.value = expand_expr(&nv.value, lookup)?;
+            Ok(Meta::NameValue(nv))
+        }
+        Meta::List(list) => {
+            let items = NestedMeta::parse_meta_list(list.tokens.clone())?;
+            let mut errors = Error::accumulator();
+            let items: Vec<NestedMeta> = items
+                .iter()
+                .filter_map(|item| {
+                    errors.handle(match item {
+                        NestedMeta::Meta(meta) => expand_meta(meta, lookup).map(NestedMeta::Meta),
+                        NestedMeta::Lit(lit) => expand_lit(lit, lookup).map(NestedMeta::Lit),
+                    })
+                })
+                .collect();
+            errors.finish()?;
+
+            let mut list = list.clone();
+            list.tokens = quote!(#(#items),*);
+            Ok(Meta::List(list))
+        }
+    }
+}
+
+fn expand_expr(expr: &Expr, lookup: Lookup<'_>) -> Result<Expr> {
+    match expr {
+        Expr::Lit(expr_lit) => Ok(Expr::Lit(ExprLit {
+            lit: expand_lit(&expr_lit.lit, lookup)?,
+            ..expr_lit.clone()
+        })),
+        Expr::Array(array) => {
+            let mut array = array.clone();
+            let mut errors = Error::accumulator();
+            for elem in array.elems.iter_mut() {
+                if let Some(expanded) = errors.handle(expand_expr(elem, lookup)) {
+                    *elem = expanded;
+                }
+            }
+            errors.finish_with(Expr::Array(array))
+        }
+        _ => Ok(expr.clone()),
+    }
+}
+
+fn expand_lit(lit: &Lit, lookup: Lookup<'_>) -> Result<Lit> {
+    match lit {
+        Lit::Str(s) => {
+            let value = expand_str(&s.value(), lookup).map_err(|e| e.with_span(s))?;
+            Ok(Lit::Str(LitStr::new(&value, s.span())))
+        }
+        _ => Ok(lit.clone()),
+    }
+}
+
+fn expand_str(value: &str, lookup: Lookup<'_>) -> Result<String> {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('$') {
+        expanded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(after) = rest.strip_prefix("$$") {
+            expanded.push('$');
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("${") {
+            let end = after
+                .find('}')
+                .ok_or_else(|| Error::custom("Unterminated `${` in string"))?;
+            let name = &after[..end];
+            if name.is_empty() {
+                return Err(Error::custom("Empty environment variable name in `${}`"));
+            }
+
+            let var = lookup(name).ok_or_else(|| {
+                Error::custom(format!("Environment variable `{}` is not set", name))
+            })?;
+            expanded.push_str(&var);
+            rest = &after[end + 1..];
+        } else {
+            expanded.push('$');
+            rest = &rest[1..];
+        }
+    }
+
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    use super::{expand_meta, expand_str};
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "ROOT" => Some("value".into()),
+            "META" => Some("x".into()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(expand_str("a/${ROOT}/b", &lookup).unwrap(), "a/value/b");
+        assert_eq!(expand_str("$$5 and $x", &lookup).unwrap(), "$5 and $x");
+        expand_str("${UNSET}", &lookup).unwrap_err();
+        expand_str("${ROOT", &lookup).unwrap_err();
+        expand_str("${}", &lookup).unwrap_err();
+    }
+
+    #[test]
+    fn metas() {
+        let meta = expand_meta(
+            &parse_quote!(a(b = "${META}", "$$", c = ["${META}", 1])),
+            &lookup,
+        )
+        .unwrap();
+        assert_eq!(
+            meta.to_token_stream().to_string(),
+            quote::quote!(a(b = "x", "$", c = ["x", 1])).to_string()
+        );
+    }
+
+    #[test]
+    fn errors_for_each_unset_variable() {
+        let err =
+            expand_meta(&parse_quote!(a(b = "${UNSET}", c = "${UNSET}")), &lookup).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+}
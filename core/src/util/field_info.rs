@@ -0,0 +1,159 @@
+/// A description of one field a receiver accepts, which deriving types opt in to with
+/// `#[darling(reflect)]`.
+///
+/// The derive emits an associated constant, `FIELDS`, listing the fields read from the input
+/// by name in declaration order. Tooling, error messages, and documentation generators can
+/// use it to describe the attribute grammar without parsing the receiver's source.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// #[darling(reflect)]
+/// struct Options {
+///     #[darling(alias = "label")]
+///     name: String,
+///     #[darling(default)]
+///     retries: u8,
+/// }
+///
+/// assert_eq!(Options::FIELDS[0].name, "name");
+/// assert_eq!(Options::FIELDS[0].aliases, &["label"]);
+/// assert!(!Options::FIELDS[1].required);
+/// assert_eq!(Options::FIELDS[1].default, Some("Default::default()"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldInfo {
+    /// The name the field is written with in the input, after any renaming.
+    pub name: &'static str,
+    /// Other names which are accepted for the field, including deprecated ones.
+    pub aliases: &'static [&'static str],
+    /// The type of the field as written in the receiver, such as `Option<String>`.
+    pub ty: &'static str,
+    /// Whether omitting the field is an error. This is determined from the field's
+    /// declaration: a field is optional if it or its container has a default, or if it's
+    /// `multiple`, or if its type is written as `Option<..>` or `Flag`.
+    ///
+    /// Because `FIELDS` is a constant, the type is only checked by the name of its last path
+    /// segment, not by calling `FromMeta::from_none`. A field whose type is an alias of
+    /// `Option`, a qualified path such as `<T as Trait>::Output`, or any other type with a
+    /// custom `from_none` is reported as required even though omitting it isn't an error.
+    /// Likewise, a user type which happens to be named `Option` or `Flag` is reported as
+    /// optional.
+    pub required: bool,
+    /// Whether the field may be given more than once.
+    pub multiple: bool,
+    /// The expression the field falls back to when it's absent, such as `Default::default()`,
+    /// or `None` if it has no default.
+    pub default: Option<&'static str>,
+    /// The field's doc comments, with lines joined by newlines.
+    pub docs: &'static str,
+}
+
+/// Render a receiver's fields as a Markdown table, for inclusion in the documentation of the
+/// macro which accepts them.
+///
+/// Each row has the field's name and aliases, its type, whether it's required, its default,
+/// and its doc comments. Doc comment lines are joined into one line, with blank lines kept as
+/// paragraph breaks.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// #[darling(reflect)]
+/// struct Options {
+///     /// The name of the generated type.
+///     name: String,
+/// }
+///
+/// // | Option | Type | Required | Default | Description |
+/// // | --- | --- | --- | --- | --- |
+/// // | `name` | `String` | yes |  | The name of the generated type. |
+/// println!("{}", fields_markdown(Options::FIELDS));
+/// ```
+pub fn fields_markdown(fields: &[FieldInfo]) -> String {
+    let mut out = String::from(
+        "| Option | Type | Required | Default | Description |\n| --- | --- | --- | --- | --- |\n",
+    );
+
+    for field in fields {
+        let mut name = code(field.name);
+        if !field.aliases.is_empty() {
+            let aliases: Vec<String> = field.aliases.iter().map(|alias| code(alias)).collect();
+            name.push_str(&format!(" (alias {})", aliases.join(", ")));
+        }
+        if field.multiple {
+            name.push_str(" (repeatable)");
+        }
+
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            name,
+            code(field.ty),
+            if field.required { "yes" } else { "no" },
+            field.default.map(code).unwrap_or_default(),
+            description(field.docs),
+        ));
+    }
+
+    out
+}
+
+/// Write `text` as inline code in a table cell.
+fn code(text: &str) -> String {
+    format!("`{}`", text.replace('|', "\\|"))
+}
+
+/// Join doc comment lines into the text of one table cell.
+fn description(docs: &str) -> String {
+    docs.split("\n\n")
+        .map(|paragraph| {
+            paragraph
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect::<Vec<_>>()
+        .join("<br><br>")
+        .replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fields_markdown, FieldInfo};
+
+    #[test]
+    fn markdown() {
+        let fields = [
+            FieldInfo {
+                name: "name",
+                aliases: &["label"],
+                ty: "String",
+                required: true,
+                multiple: false,
+                default: None,
+                docs: "The name of the type.\n\nMust be unique | case-sensitive.",
+            },
+            FieldInfo {
+                name: "tag",
+                aliases: &[],
+                ty: "Vec<String>",
+                required: false,
+                multiple: true,
+                default: Some("Default::default()"),
+                docs: "",
+            },
+        ];
+
+        assert_eq!(
+            fields_markdown(&fields),
+            "| Option | Type | Required | Default | Description |
+| --- | --- | --- | --- | --- |
+| `name` (alias `label`) | `String` | yes |  | The name of the type.<br><br>Must be unique \\| case-sensitive. |
+| `tag` (repeatable) | `Vec<String>` | no | `Default::default()` |  |
+"
+        );
+    }
+}
@@ -1,10 +1,15 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
 use syn::{spanned::Spanned, Meta};
 
-use crate::{FromMeta, Result};
+use crate::{FromMeta, Result, ToMetaItem};
 
 /// A meta-item that can be present as a word - with no value - or absent.
 ///
+/// An explicit boolean is also accepted, so `#[example(flag_a = false)]` is the same
+/// as leaving `flag_a` out, and `#[example(flag_a = true)]` is the same as `#[example(flag_a)]`.
+/// This lets macro authors generate attributes without special-casing the absent flag.
+///
 /// # Defaulting
 /// Like `Option`, `Flag` does not require `#[darling(default)]` to be optional.
 /// If the caller does not include the property, then an absent `Flag` will be included
@@ -67,16 +72,37 @@ impl FromMeta for Flag {
     }
 
     fn from_meta(mi: &syn::Meta) -> Result<Self> {
-        if let Meta::Path(p) = mi {
-            Ok(Flag(Some(p.span())))
-        } else {
-            // The implementation for () will produce an error for all non-path meta items;
+        match mi {
+            Meta::Path(p) => Ok(Flag(Some(p.span()))),
+            Meta::NameValue(nv) => bool::from_expr(&nv.value)
+                .map(|present| Flag(if present { Some(nv.path.span()) } else { None }))
+                .map_err(|e| e.with_span(mi)),
+            // The implementation for () will produce an error for all other meta items;
             // call it to make sure the span behaviors and error messages are the same.
-            Err(<()>::from_meta(mi).unwrap_err())
+            Meta::List(_) => Err(<()>::from_meta(mi).unwrap_err()),
+        }
+    }
+}
+
+/// A present flag is rendered as a word, and an absent one is left out.
+impl ToMetaItem for Flag {
+    fn to_meta_item(&self, path: &syn::Path) -> Option<Meta> {
+        if self.is_present() {
+            Some(Meta::Path(path.clone()))
+        } else {
+            None
         }
     }
 }
 
+/// Emits `true` or `false`, with the span of the word if the flag is present.
+impl ToTokens for Flag {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let span = self.0.unwrap_or_else(Span::call_site);
+        syn::LitBool::new(self.is_present(), span).to_tokens(tokens);
+    }
+}
+
 impl From<Flag> for bool {
     fn from(flag: Flag) -> Self {
         flag.is_present()
@@ -92,3 +118,36 @@ impl From<bool> for Flag {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::Flag;
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn to_tokens() {
+        use quote::ToTokens;
+
+        assert_eq!(Flag::present().to_token_stream().to_string(), "true");
+        assert_eq!(Flag::default().to_token_stream().to_string(), "false");
+    }
+
+    #[test]
+    fn word_and_explicit_bool() {
+        assert!(parse_meta::<Flag>(quote!(ignore)).unwrap().is_present());
+        assert!(parse_meta::<Flag>(quote!(ignore = true))
+            .unwrap()
+            .is_present());
+        assert!(!parse_meta::<Flag>(quote!(ignore = false))
+            .unwrap()
+            .is_present());
+    }
+
+    #[test]
+    fn rejects_other_values() {
+        parse_meta::<Flag>(quote!(ignore = "yes")).unwrap_err();
+        parse_meta::<Flag>(quote!(ignore(true))).unwrap_err();
+    }
+}
@@ -5,7 +5,7 @@ use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use syn::{Ident, Meta};
 
-use crate::{FromMeta, Result};
+use crate::{FromMeta, Result, ToMetaItem};
 
 /// A wrapper for an `Ident` which also keeps the value as a string.
 ///
@@ -99,7 +99,7 @@ impl PartialEq<String> for IdentString {
     }
 }
 
-impl<'a> PartialEq<&'a str> for IdentString {
+impl PartialEq<&str> for IdentString {
     fn eq(&self, rhs: &&str) -> bool {
         self.as_str() == *rhs
     }
@@ -135,6 +135,12 @@ impl FromMeta for IdentString {
     }
 }
 
+impl ToMetaItem for IdentString {
+    fn to_meta_item(&self, path: &syn::Path) -> Option<Meta> {
+        self.ident.to_meta_item(path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use syn::parse_quote;
@@ -0,0 +1,242 @@
+use serde_json::{json, Map, Value};
+
+use crate::util::FieldInfo;
+
+/// Describe a receiver's fields as a JSON Schema, so editor plugins and linters can check
+/// attributes without running the macro.
+///
+/// The schema describes an object with a property per field, written as the attribute would be
+/// if its items were converted to JSON: a repeated `multiple` field is an array of its values,
+/// and a word such as a [`Flag`](crate::util::Flag) is `true`. Each alias is a property which
+/// refers to its field's. Required fields are listed in `required`, or when they have aliases,
+/// must have one of their names present according to `allOf`. Unknown keys are rejected.
+///
+/// The schema is a best-effort guess. [`FieldInfo`] only has the field's type as written, so
+/// value shapes are inferred from the type's name rather than from its `FromMeta` impl: `bool`
+/// and `Flag` are booleans, integer types are integers, `f32` and `f64` are numbers, and
+/// string, path, and identifier types are strings. `Option<T>` is described as `T`, and
+/// `Vec<T>` as an array of `T`. Other types accept any value. A type alias, or a user type
+/// which shares one of these names, is described by its name, so the schema can accept values
+/// the macro rejects or reject values it accepts.
+///
+/// Every property also records the Rust type in `x-rust-type`, its aliases in `x-aliases`, and
+/// its default in `x-default`, as extensions which validators ignore.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// #[darling(reflect)]
+/// struct Options {
+///     name: String,
+///     #[darling(default)]
+///     retries: u8,
+/// }
+///
+/// let schema = fields_json_schema(Options::FIELDS);
+/// assert_eq!(schema["properties"]["retries"]["type"], "integer");
+/// assert_eq!(schema["required"], serde_json::json!(["name"]));
+/// ```
+pub fn fields_json_schema(fields: &[FieldInfo]) -> Value {
+    let mut properties = Map::new();
+    let mut required = vec![];
+    let mut required_any = vec![];
+
+    for field in fields {
+        let mut property = if field.multiple {
+            json!({ "type": "array", "items": value_schema(strip_wrapper(field.ty, "Vec")) })
+        } else {
+            value_schema(field.ty)
+        };
+
+        let extensions = property.as_object_mut().expect("schemas are objects");
+        if !field.docs.is_empty() {
+            extensions.insert("description".into(), field.docs.into());
+        }
+        extensions.insert("x-rust-type".into(), field.ty.into());
+        if !field.aliases.is_empty() {
+            extensions.insert("x-aliases".into(), field.aliases.into());
+        }
+        if let Some(default) = field.default {
+            extensions.insert("x-default".into(), default.into());
+        }
+
+        if field.required {
+            if field.aliases.is_empty() {
+                required.push(field.name);
+            } else {
+                let names = std::iter::once(&field.name).chain(field.aliases);
+                let any: Vec<_> = names.map(|name| json!({ "required": [name] })).collect();
+                required_any.push(json!({ "anyOf": any }));
+            }
+        }
+        properties.insert(field.name.into(), property);
+
+        // Aliases are accepted in place of the field's name, so they need properties too, or
+        // else `additionalProperties` would reject them.
+        for alias in field.aliases {
+            let reference = format!("#/properties/{}", field.name);
+            properties.insert((*alias).into(), json!({ "$ref": reference }));
+        }
+    }
+
+    let mut schema = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    });
+    if !required_any.is_empty() {
+        schema["allOf"] = required_any.into();
+    }
+
+    schema
+}
+
+/// Guess the values accepted by a type from how it's written.
+fn value_schema(ty: &str) -> Value {
+    let (name, arg) = split_type(ty);
+    match (name, arg) {
+        ("Option", Some(inner)) => value_schema(inner),
+        ("Vec", Some(inner)) => json!({ "type": "array", "items": value_schema(inner) }),
+        ("PathList", None) => json!({ "type": "array", "items": { "type": "string" } }),
+        ("bool" | "Flag", None) => json!({ "type": "boolean" }),
+        ("f32" | "f64", None) => json!({ "type": "number" }),
+        (
+            "String" | "str" | "char" | "PathBuf" | "Ident" | "IdentString" | "Path" | "LitStr",
+            None,
+        ) => json!({ "type": "string" }),
+        (name, None) if is_integer(name) => json!({ "type": "integer" }),
+        _ => json!({}),
+    }
+}
+
+/// Returns `true` if `name` is a primitive or non-zero integer type.
+fn is_integer(name: &str) -> bool {
+    let name = name.strip_prefix("NonZero").unwrap_or(name);
+    let name = name.to_ascii_lowercase();
+    let bits = name
+        .strip_prefix('i')
+        .or_else(|| name.strip_prefix('u'))
+        .unwrap_or("");
+    matches!(bits, "8" | "16" | "32" | "64" | "128" | "size")
+}
+
+/// Get the type argument of `ty` if it's written as `wrapper<..>`, or `ty` itself otherwise.
+fn strip_wrapper<'a>(ty: &'a str, wrapper: &str) -> &'a str {
+    match split_type(ty) {
+        (name, Some(arg)) if name == wrapper => arg,
+        _ => ty,
+    }
+}
+
+/// Split a type into the last segment of its path and the text between its angle brackets,
+/// if it has any. References are described by the type they refer to.
+fn split_type(ty: &str) -> (&str, Option<&str>) {
+    let ty = ty.trim_start_matches('&').trim();
+    let (path, arg) = match ty.find('<') {
+        Some(open) if ty.ends_with('>') => (&ty[..open], Some(ty[open + 1..ty.len() - 1].trim())),
+        _ => (ty, None),
+    };
+
+    (path.rsplit("::").next().unwrap_or(path).trim(), arg)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::fields_json_schema;
+    use crate::util::FieldInfo;
+
+    fn field(name: &'static str, ty: &'static str) -> FieldInfo {
+        FieldInfo {
+            name,
+            aliases: &[],
+            ty,
+            required: false,
+            multiple: false,
+            default: None,
+            docs: "",
+        }
+    }
+
+    #[test]
+    fn value_shapes() {
+        let fields = [
+            field("a", "bool"),
+            field("b", "Option<std::num::NonZeroU32>"),
+            field("c", "Vec<f64>"),
+            field("d", "syn::Path"),
+            field("e", "HashMap<String, u8>"),
+            field("f", "usize"),
+            field("g", "Unsigned"),
+        ];
+        let schema = fields_json_schema(&fields);
+        let types: Vec<_> = fields
+            .iter()
+            .map(|f| schema["properties"][f.name].get("type").cloned())
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                Some(json!("boolean")),
+                Some(json!("integer")),
+                Some(json!("array")),
+                Some(json!("string")),
+                None,
+                Some(json!("integer")),
+                None,
+            ]
+        );
+        assert_eq!(
+            schema["properties"]["c"]["items"],
+            json!({ "type": "number" })
+        );
+    }
+
+    #[test]
+    fn properties() {
+        let fields = [
+            FieldInfo {
+                aliases: &["label"],
+                required: true,
+                docs: "The name.",
+                ..field("name", "String")
+            },
+            FieldInfo {
+                multiple: true,
+                default: Some("Default::default()"),
+                ..field("tag", "Vec<String>")
+            },
+        ];
+
+        assert_eq!(
+            fields_json_schema(&fields),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "The name.",
+                        "x-rust-type": "String",
+                        "x-aliases": ["label"],
+                    },
+                    "label": { "$ref": "#/properties/name" },
+                    "tag": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "x-rust-type": "Vec<String>",
+                        "x-default": "Default::default()",
+                    },
+                },
+                "required": [],
+                "additionalProperties": false,
+                "allOf": [
+                    { "anyOf": [{ "required": ["name"] }, { "required": ["label"] }] },
+                ],
+            })
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use serde_json::{Map, Value};
+use syn::{Expr, ExprLit, Lit, Meta};
+
+use crate::ast::NestedMeta;
+use crate::util::{path_to_string, Number};
+use crate::{Error, FromMeta, Result};
+
+/// Captures the meta item as dynamic JSON data. This requires the `json` or `serde` feature.
+///
+/// Lists, arrays, and literals are converted as described for `util::SerdeValue`,
+/// so `config(retries = 3, hosts("a", "b"))` is `{"retries": 3, "hosts": ["a", "b"]}`.
+/// A string value of the field itself is parsed as a JSON document instead, so
+/// `config = r#"{"retries": 3}"#` is read the same way.
+impl FromMeta for Value {
+    fn from_word() -> Result<Self> {
+        Ok(Value::Null)
+    }
+
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        list_to_json(items)
+    }
+
+    fn from_string(value: &str) -> Result<Self> {
+        serde_json::from_str(value).map_err(|e| Error::custom(format!("Invalid JSON: {}", e)))
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Self::from_string(&s.value()),
+            _ => expr_to_json(expr),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+/// Convert a list of items to an array if they're all literals, or an object otherwise.
+pub(crate) fn list_to_json(items: &[NestedMeta]) -> Result<Value> {
+    if !items.is_empty() && items.iter().all(|item| matches!(item, NestedMeta::Lit(_))) {
+        let mut errors = Error::accumulator();
+        let elems = items
+            .iter()
+            .filter_map(|item| match item {
+                NestedMeta::Lit(lit) => errors.handle(lit_to_json(lit)),
+                NestedMeta::Meta(_) => None,
+            })
+            .collect();
+        return errors.finish_with(Value::Array(elems));
+    }
+
+    let mut errors = Error::accumulator();
+    let mut object = Map::new();
+    for item in items {
+        let meta = match item {
+            NestedMeta::Meta(meta) => meta,
+            NestedMeta::Lit(lit) => {
+                errors.push(Error::unsupported_format("literal").with_span(lit));
+                continue;
+            }
+        };
+
+        let name = path_to_string(meta.path());
+        if object.contains_key(&name) {
+            errors.push(Error::duplicate_field(&name).with_span(meta));
+            continue;
+        }
+
+        let value = match meta {
+            Meta::Path(_) => Ok(Value::Bool(true)),
+            Meta::NameValue(nv) => expr_to_json(&nv.value),
+            Meta::List(list) => NestedMeta::parse_meta_list(list.tokens.clone())
+                .map_err(Error::from)
+                .and_then(|items| list_to_json(&items)),
+        };
+
+        if let Some(value) = errors.handle(value.map_err(|e| e.at(&name))) {
+            object.insert(name, value);
+        }
+    }
+
+    errors.finish_with(Value::Object(object))
+}
+
+pub(crate) fn expr_to_json(expr: &Expr) -> Result<Value> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => lit_to_json(lit),
+        Expr::Group(group) => expr_to_json(&group.expr),
+        Expr::Unary(_) => number_to_json(&Number::from_expr(expr)?),
+        Expr::Array(array) => {
+            let mut errors = Error::accumulator();
+            let elems = array
+                .elems
+                .iter()
+                .filter_map(|elem| errors.handle(expr_to_json(elem)))
+                .collect();
+            errors.finish_with(Value::Array(elems))
+        }
+        _ => Err(Error::unexpected_expr_type(expr)),
+    }
+}
+
+fn lit_to_json(lit: &Lit) -> Result<Value> {
+    match lit {
+        Lit::Str(s) => Ok(Value::String(s.value())),
+        Lit::Char(c) => Ok(Value::String(c.value().to_string())),
+        Lit::Bool(b) => Ok(Value::Bool(b.value)),
+        Lit::Int(_) | Lit::Float(_) => number_to_json(&Number::from_value(lit)?),
+        _ => Err(Error::unexpected_lit_type(lit)),
+    }
+}
+
+fn number_to_json(number: &Number) -> Result<Value> {
+    let value = if number.is_float() {
+        serde_json::Number::from_f64(number.as_f64()?).map(Value::Number)
+    } else if number.is_negative() {
+        Some(Value::from(number.as_i64()?))
+    } else {
+        Some(Value::from(number.as_u64()?))
+    };
+
+    value.ok_or_else(|| Error::custom("Number is not finite").with_span(number))
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use serde_json::{json, Value};
+
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn nested_meta() {
+        assert_eq!(
+            parse_meta::<Value>(quote!(ignore(retries = 3, hosts("a", "b"), name = "x"))).unwrap(),
+            json!({ "retries": 3, "hosts": ["a", "b"], "name": "x" })
+        );
+        assert_eq!(
+            parse_meta::<Value>(quote!(ignore = [1.5, -2])).unwrap(),
+            json!([1.5, -2])
+        );
+    }
+
+    #[test]
+    fn json_strings() {
+        assert_eq!(
+            parse_meta::<Value>(quote!(ignore = r#"{"retries": 3, "tags": null}"#)).unwrap(),
+            json!({ "retries": 3, "tags": null })
+        );
+
+        let err = parse_meta::<Value>(quote!(ignore = "{ not json")).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid JSON"));
+    }
+}
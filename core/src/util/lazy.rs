@@ -0,0 +1,140 @@
+use std::cell::{Ref, RefCell};
+use std::fmt;
+
+use syn::Meta;
+
+use crate::{FromMeta, Result};
+
+/// A value which is kept as the meta item it was written as, and only parsed as `T` when it's
+/// first accessed, so options which are expensive to parse or rarely needed don't slow down
+/// every expansion.
+///
+/// Parsing a receiver with a `Lazy<T>` field only checks that the field is present; errors in
+/// its value are returned by [`get`](Self::get) instead. A successfully-parsed value is kept for
+/// later calls, while a failure is not, so each call reports the error again.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Options {
+///     #[darling(default)]
+///     schema: Option<Lazy<Schema>>,
+/// }
+///
+/// if let Some(schema) = &options.schema {
+///     let schema = schema.get()?;
+///     // ...
+/// }
+/// ```
+pub struct Lazy<T> {
+    meta: Meta,
+    value: RefCell<Option<T>>,
+}
+
+impl<T> Lazy<T> {
+    /// Create a value which will be parsed from `meta`.
+    pub fn new(meta: Meta) -> Self {
+        Lazy {
+            meta,
+            value: RefCell::new(None),
+        }
+    }
+
+    /// The meta item the value is parsed from.
+    pub fn meta(&self) -> &Meta {
+        &self.meta
+    }
+
+    /// Returns `true` if the value has been parsed successfully.
+    pub fn is_parsed(&self) -> bool {
+        self.value.borrow().is_some()
+    }
+}
+
+impl<T: FromMeta> Lazy<T> {
+    /// Get the value, parsing it if this is the first successful access.
+    pub fn get(&self) -> Result<Ref<'_, T>> {
+        if self.value.borrow().is_none() {
+            let value = parse(&self.meta)?;
+            *self.value.borrow_mut() = Some(value);
+        }
+
+        Ok(Ref::map(self.value.borrow(), |value| {
+            value.as_ref().expect("The value was parsed above")
+        }))
+    }
+
+    /// Get the value mutably, parsing it if this is the first successful access.
+    pub fn get_mut(&mut self) -> Result<&mut T> {
+        let value = self.value.get_mut();
+        if value.is_none() {
+            *value = Some(parse(&self.meta)?);
+        }
+
+        Ok(value.as_mut().expect("The value was parsed above"))
+    }
+
+    /// Take the value, parsing it if it hasn't been already.
+    pub fn into_inner(self) -> Result<T> {
+        match self.value.into_inner() {
+            Some(value) => Ok(value),
+            None => parse(&self.meta),
+        }
+    }
+}
+
+fn parse<T: FromMeta>(meta: &Meta) -> Result<T> {
+    T::from_meta(meta).map_err(|e| e.with_span(meta))
+}
+
+impl<T> FromMeta for Lazy<T> {
+    fn from_meta(item: &Meta) -> Result<Self> {
+        Ok(Lazy::new(item.clone()))
+    }
+}
+
+impl<T: Clone> Clone for Lazy<T> {
+    fn clone(&self) -> Self {
+        Lazy {
+            meta: self.meta.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lazy")
+            .field("meta", &self.meta)
+            .field("value", &self.value.borrow())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::Lazy;
+    use crate::FromMeta;
+
+    #[test]
+    fn parses_on_access() {
+        let lazy = Lazy::<u8>::from_meta(&parse_quote!(ignore = 300)).unwrap();
+        assert!(!lazy.is_parsed());
+        assert!(lazy.get().unwrap_err().has_span());
+        assert!(!lazy.is_parsed());
+
+        let lazy = Lazy::<u8>::from_meta(&parse_quote!(ignore = 3)).unwrap();
+        assert_eq!(*lazy.get().unwrap(), 3);
+        assert!(lazy.is_parsed());
+        assert_eq!(lazy.into_inner().unwrap(), 3);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut lazy = Lazy::<Vec<String>>::from_meta(&parse_quote!(ignore = ["a"])).unwrap();
+        lazy.get_mut().unwrap().push("b".into());
+        assert_eq!(*lazy.get().unwrap(), vec!["a", "b"]);
+    }
+}
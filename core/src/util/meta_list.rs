@@ -0,0 +1,16 @@
+use quote::quote;
+use syn::{Meta, MetaList, Path};
+
+use crate::ast::NestedMeta;
+
+/// Create the list item `path(items...)`, such as `derive(Clone, Debug)`.
+///
+/// This is the counterpart of [`NestedMeta::parse_meta_list`] for code which renders
+/// options back into attributes, such as [`ToMetaItem`](crate::ToMetaItem) impls.
+pub fn meta_list(path: &Path, items: Vec<NestedMeta>) -> Meta {
+    Meta::List(MetaList {
+        path: path.clone(),
+        delimiter: syn::MacroDelimiter::Paren(Default::default()),
+        tokens: quote!(#(#items),*),
+    })
+}
@@ -0,0 +1,439 @@
+use std::collections::btree_map::{BTreeMap, Entry};
+
+use syn::{Expr, Ident, Meta, Path};
+
+use crate::ast::NestedMeta;
+use crate::util::{path_to_string, Flag};
+use crate::{Error, FromMeta, Result};
+
+/// The kind of value a field of a [`MetaParser`] accepts.
+#[derive(Debug, Clone)]
+pub enum ValueType {
+    /// A string, parsed as `String`.
+    Str,
+    /// An integer, parsed as `i64`.
+    Int,
+    /// A floating-point number, parsed as `f64`.
+    Float,
+    /// A boolean, which may be written as a bare word to mean `true`.
+    Bool,
+    /// A bare word, such as `skip`, which is `true` if present.
+    Flag,
+    /// A path, parsed as `syn::Path`.
+    Path,
+    /// An identifier, parsed as `syn::Ident`.
+    Ident,
+    /// An expression, parsed as `syn::Expr`.
+    Expr,
+    /// Any meta item, which is kept as written.
+    Meta,
+    /// A nested list, parsed with another parser.
+    Nested(MetaParser),
+}
+
+/// A value read by a [`MetaParser`].
+#[derive(Debug, Clone)]
+pub enum MetaValue {
+    Str(String),
+    Int(i64),
+    Float(f64),
+    /// The value of a `Bool` or `Flag` field.
+    Bool(bool),
+    Path(Path),
+    Ident(Ident),
+    Expr(Expr),
+    Meta(Meta),
+    Nested(MetaMap),
+    /// The values of a field declared with [`MetaParserBuilder::multiple`], in order.
+    List(Vec<MetaValue>),
+}
+
+impl MetaValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MetaValue::Str(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            MetaValue::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            MetaValue::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            MetaValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_path(&self) -> Option<&Path> {
+        match self {
+            MetaValue::Path(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_ident(&self) -> Option<&Ident> {
+        match self {
+            MetaValue::Ident(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_expr(&self) -> Option<&Expr> {
+        match self {
+            MetaValue::Expr(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_meta(&self) -> Option<&Meta> {
+        match self {
+            MetaValue::Meta(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_nested(&self) -> Option<&MetaMap> {
+        match self {
+            MetaValue::Nested(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[MetaValue]> {
+        match self {
+            MetaValue::List(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// The values read by a [`MetaParser`], by field name.
+///
+/// Optional fields which were absent have no entry, except for `Flag` fields, which are
+/// `false`, and fields declared with [`MetaParserBuilder::multiple`], which are empty lists.
+#[derive(Debug, Clone, Default)]
+pub struct MetaMap(BTreeMap<String, MetaValue>);
+
+impl MetaMap {
+    pub fn get(&self, name: &str) -> Option<&MetaValue> {
+        self.0.get(name)
+    }
+
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the values in order of field name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &MetaValue)> {
+        self.0.iter().map(|(name, value)| (name.as_str(), value))
+    }
+
+    pub fn into_inner(self) -> BTreeMap<String, MetaValue> {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FieldSpec {
+    name: String,
+    ty: ValueType,
+    required: bool,
+    multiple: bool,
+}
+
+/// A parser for meta items whose fields are declared at runtime, for tools which build
+/// attribute schemas on the fly rather than deriving a receiver.
+///
+/// Parsing follows the rules of derived receivers: every error in the input is reported,
+/// unknown and duplicate fields are errors, and required fields must be present.
+///
+/// # Example
+/// ```rust,ignore
+/// let parser = MetaParser::builder()
+///     .required("name", ValueType::Str)
+///     .optional("count", ValueType::Int)
+///     .build();
+///
+/// let values = parser.parse(&parse_quote!(opts(name = "lorem", count = 3)))?;
+/// assert_eq!(values.get("name").and_then(MetaValue::as_str), Some("lorem"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MetaParser {
+    fields: Vec<FieldSpec>,
+    allow_unknown_fields: bool,
+}
+
+impl MetaParser {
+    pub fn builder() -> MetaParserBuilder {
+        MetaParserBuilder::default()
+    }
+
+    /// Parse the items of a list, such as `opts(name = "lorem")`. A bare word is
+    /// treated as an empty list.
+    pub fn parse(&self, meta: &Meta) -> Result<MetaMap> {
+        match meta {
+            Meta::Path(_) => self.parse_items(&[]),
+            Meta::List(list) => {
+                self.parse_items(&NestedMeta::parse_meta_list(list.tokens.clone())?)
+            }
+            Meta::NameValue(_) => Err(Error::unsupported_format("name value")),
+        }
+        .map_err(|e| e.with_span(meta))
+    }
+
+    /// Parse the items of a list which has already been split.
+    pub fn parse_items(&self, items: &[NestedMeta]) -> Result<MetaMap> {
+        let mut errors = Error::accumulator();
+        let mut values = BTreeMap::new();
+
+        for item in items {
+            let meta = match item {
+                NestedMeta::Meta(meta) => meta,
+                NestedMeta::Lit(lit) => {
+                    errors.push(Error::unsupported_format("literal").with_span(lit));
+                    continue;
+                }
+            };
+
+            let name = path_to_string(meta.path());
+            let field = match self.fields.iter().find(|f| f.name == name) {
+                Some(field) => field,
+                None => {
+                    if !self.allow_unknown_fields {
+                        errors.push(
+                            Error::unknown_field_with_alts(
+                                &name,
+                                self.fields.iter().map(|f| &f.name),
+                            )
+                            .with_span(meta.path()),
+                        );
+                    }
+                    continue;
+                }
+            };
+
+            let value = parse_value(&field.ty, meta).map_err(|e| e.at(&name));
+            let value = match errors.handle(value) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            match values.entry(name) {
+                Entry::Vacant(entry) if field.multiple => {
+                    entry.insert(MetaValue::List(vec![value]));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+                Entry::Occupied(mut entry) => match entry.get_mut() {
+                    MetaValue::List(list) if field.multiple => list.push(value),
+                    _ => errors.push(Error::duplicate_field(entry.key()).with_span(meta)),
+                },
+            }
+        }
+
+        for field in &self.fields {
+            if values.contains_key(&field.name) {
+                continue;
+            }
+
+            if field.multiple {
+                values.insert(field.name.clone(), MetaValue::List(vec![]));
+            } else if let ValueType::Flag = field.ty {
+                values.insert(field.name.clone(), MetaValue::Bool(false));
+            } else if field.required {
+                errors.push(Error::missing_field(&field.name));
+            }
+        }
+
+        errors.finish_with(MetaMap(values))
+    }
+}
+
+fn parse_value(ty: &ValueType, meta: &Meta) -> Result<MetaValue> {
+    Ok(match ty {
+        ValueType::Str => MetaValue::Str(FromMeta::from_meta(meta)?),
+        ValueType::Int => MetaValue::Int(FromMeta::from_meta(meta)?),
+        ValueType::Float => MetaValue::Float(FromMeta::from_meta(meta)?),
+        ValueType::Bool => MetaValue::Bool(FromMeta::from_meta(meta)?),
+        ValueType::Flag => MetaValue::Bool(Flag::from_meta(meta)?.is_present()),
+        ValueType::Path => MetaValue::Path(FromMeta::from_meta(meta)?),
+        ValueType::Ident => MetaValue::Ident(FromMeta::from_meta(meta)?),
+        ValueType::Expr => MetaValue::Expr(FromMeta::from_meta(meta)?),
+        ValueType::Meta => MetaValue::Meta(meta.clone()),
+        ValueType::Nested(parser) => MetaValue::Nested(parser.parse(meta)?),
+    })
+}
+
+/// Declares the fields of a [`MetaParser`].
+///
+/// Declaring a field with the name of an earlier one replaces it.
+#[derive(Debug, Clone, Default)]
+pub struct MetaParserBuilder {
+    parser: MetaParser,
+}
+
+impl MetaParserBuilder {
+    /// Declare a field which must be present.
+    pub fn required(self, name: &str, ty: ValueType) -> Self {
+        self.field(name, ty, true, false)
+    }
+
+    /// Declare a field which may be omitted.
+    pub fn optional(self, name: &str, ty: ValueType) -> Self {
+        self.field(name, ty, false, false)
+    }
+
+    /// Declare a field which may be given any number of times. Its values are
+    /// collected into a [`MetaValue::List`].
+    pub fn multiple(self, name: &str, ty: ValueType) -> Self {
+        self.field(name, ty, false, true)
+    }
+
+    /// Skip fields which weren't declared, rather than reporting them as errors.
+    pub fn allow_unknown_fields(mut self, allow: bool) -> Self {
+        self.parser.allow_unknown_fields = allow;
+        self
+    }
+
+    pub fn build(self) -> MetaParser {
+        self.parser
+    }
+
+    fn field(mut self, name: &str, ty: ValueType, required: bool, multiple: bool) -> Self {
+        self.parser.fields.retain(|f| f.name != name);
+        self.parser.fields.push(FieldSpec {
+            name: name.to_string(),
+            ty,
+            required,
+            multiple,
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::{MetaParser, MetaValue, ValueType};
+
+    fn parser() -> MetaParser {
+        MetaParser::builder()
+            .required("name", ValueType::Str)
+            .optional("count", ValueType::Int)
+            .optional("skip", ValueType::Flag)
+            .multiple("derive", ValueType::Path)
+            .optional(
+                "retry",
+                ValueType::Nested(
+                    MetaParser::builder()
+                        .required("attempts", ValueType::Int)
+                        .build(),
+                ),
+            )
+            .build()
+    }
+
+    #[test]
+    fn parses_values() {
+        let values = parser()
+            .parse(&parse_quote!(opts(
+                name = "lorem",
+                skip,
+                derive = Clone,
+                derive = "Debug",
+                retry(attempts = 3)
+            )))
+            .unwrap();
+
+        assert_eq!(
+            values.get("name").and_then(MetaValue::as_str),
+            Some("lorem")
+        );
+        assert!(!values.contains_key("count"));
+        assert_eq!(values.get("skip").and_then(MetaValue::as_bool), Some(true));
+        let derives: Vec<_> = values
+            .get("derive")
+            .and_then(MetaValue::as_list)
+            .unwrap()
+            .iter()
+            .map(|v| v.as_path().unwrap().get_ident().unwrap().to_string())
+            .collect();
+        assert_eq!(derives, vec!["Clone", "Debug"]);
+        assert_eq!(
+            values
+                .get("retry")
+                .and_then(MetaValue::as_nested)
+                .and_then(|retry| retry.get("attempts"))
+                .and_then(MetaValue::as_int),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn defaults() {
+        let values = parser().parse(&parse_quote!(opts(name = "x"))).unwrap();
+        assert_eq!(values.get("skip").and_then(MetaValue::as_bool), Some(false));
+        assert_eq!(
+            values
+                .get("derive")
+                .and_then(MetaValue::as_list)
+                .map(<[_]>::len),
+            Some(0)
+        );
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn errors() {
+        let err = parser()
+            .parse(&parse_quote!(opts(
+                count = "many",
+                count = 2,
+                nmae = "x",
+                retry()
+            )))
+            .unwrap_err();
+        // The bad count, the unknown field, the missing attempts, and the missing name.
+        assert_eq!(err.len(), 4);
+
+        let err = parser()
+            .parse(&parse_quote!(opts(name = "a", name = "b")))
+            .unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert!(err.to_string().contains("Duplicate field"));
+    }
+
+    #[test]
+    fn unknown_fields() {
+        let parser = MetaParser::builder()
+            .optional("a", ValueType::Meta)
+            .allow_unknown_fields(true)
+            .build();
+        let values = parser.parse(&parse_quote!(opts(b = 1, a(x)))).unwrap();
+        assert_eq!(values.len(), 1);
+        assert!(values.get("a").and_then(MetaValue::as_meta).is_some());
+    }
+}
@@ -0,0 +1,163 @@
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{spanned::Spanned, Expr, Lit, Meta};
+
+use crate::ast::NestedMeta;
+use crate::util::path_to_string;
+use crate::{Error, FromMeta, Result};
+
+/// An attribute value of any shape, kept as a tree so receivers can accept open-ended
+/// nested configuration and walk it after parsing.
+///
+/// A field of this type stores the whole meta item, including its own name, so
+/// `#[example(config(a, b = 1, c(d, "e")))]` stores a `List` named `config` whose
+/// children are a `Word`, a `Value`, and another `List`.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromDeriveInput)]
+/// #[darling(attributes(example))]
+/// struct Receiver {
+///     config: MetaTree,
+/// }
+///
+/// for child in receiver.config.children() {
+///     if let MetaTree::Value(path, value) = child {
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetaTree {
+    /// A name with no value, e.g. `a`.
+    Word(syn::Path),
+    /// A name and a value, e.g. `b = 1`.
+    Value(syn::Path, Expr),
+    /// A name and a list of nested trees, e.g. `c(d, "e")`.
+    List(syn::Path, Vec<MetaTree>),
+    /// A literal inside a list, e.g. `"e"` in `c(d, "e")`.
+    Lit(Lit),
+}
+
+impl MetaTree {
+    /// The path naming this item, or `None` for a literal.
+    pub fn path(&self) -> Option<&syn::Path> {
+        match self {
+            MetaTree::Word(path) | MetaTree::Value(path, _) | MetaTree::List(path, _) => Some(path),
+            MetaTree::Lit(_) => None,
+        }
+    }
+
+    /// The name of this item as a string, such as `"a"` or `"a::b"`, or `None` for a literal.
+    pub fn name(&self) -> Option<String> {
+        self.path().map(path_to_string)
+    }
+
+    /// The nested trees of a `List`; other variants have no children.
+    pub fn children(&self) -> &[MetaTree] {
+        match self {
+            MetaTree::List(_, children) => children,
+            _ => &[],
+        }
+    }
+
+    /// Find the first child of a `List` with the given name.
+    pub fn get(&self, name: &str) -> Option<&MetaTree> {
+        self.children().iter().find(|child| {
+            child
+                .path()
+                .map_or(false, |path| path_to_string(path) == name)
+        })
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            MetaTree::Word(path) => path.span(),
+            MetaTree::Value(path, value) => path
+                .span()
+                .join(value.span())
+                .unwrap_or_else(|| path.span()),
+            MetaTree::List(path, _) => path.span(),
+            MetaTree::Lit(lit) => lit.span(),
+        }
+    }
+}
+
+impl FromMeta for MetaTree {
+    fn from_nested_meta(item: &NestedMeta) -> Result<Self> {
+        match item {
+            NestedMeta::Lit(lit) => Ok(MetaTree::Lit(lit.clone())),
+            NestedMeta::Meta(meta) => Self::from_meta(meta),
+        }
+    }
+
+    fn from_meta(item: &Meta) -> Result<Self> {
+        match item {
+            Meta::Path(path) => Ok(MetaTree::Word(path.clone())),
+            Meta::NameValue(nv) => Ok(MetaTree::Value(nv.path.clone(), nv.value.clone())),
+            Meta::List(list) => {
+                let items = NestedMeta::parse_meta_list(list.tokens.clone())?;
+                let mut errors = Error::accumulator();
+                let children = items
+                    .iter()
+                    .filter_map(|item| errors.handle(Self::from_nested_meta(item)))
+                    .collect();
+                errors.finish_with(MetaTree::List(list.path.clone(), children))
+            }
+        }
+        .map_err(|e| e.with_span(item))
+    }
+}
+
+impl ToTokens for MetaTree {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            MetaTree::Word(path) => path.to_tokens(tokens),
+            MetaTree::Value(path, value) => {
+                path.to_tokens(tokens);
+                <syn::Token![=]>::default().to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
+            MetaTree::List(path, children) => {
+                path.to_tokens(tokens);
+                let children = children.iter();
+                tokens.extend(quote::quote!((#(#children),*)));
+            }
+            MetaTree::Lit(lit) => lit.to_tokens(tokens),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::{quote, ToTokens};
+
+    use super::MetaTree;
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn nested() {
+        let config = parse_meta::<MetaTree>(quote!(config(a, b = 1, c(d, "e")))).unwrap();
+        assert_eq!(config.name().as_deref(), Some("config"));
+        assert_eq!(config.children().len(), 3);
+        assert!(matches!(config.get("a"), Some(MetaTree::Word(_))));
+        assert!(matches!(config.get("b"), Some(MetaTree::Value(_, _))));
+
+        let c = config.get("c").unwrap();
+        assert!(matches!(c.children()[1], MetaTree::Lit(_)));
+        assert!(c.get("d").unwrap().children().is_empty());
+        assert!(config.get("missing").is_none());
+    }
+
+    #[test]
+    fn round_trips_to_tokens() {
+        let tokens = quote!(config(a, b = 1, c(d, "e"), ::x::y));
+        let config = parse_meta::<MetaTree>(tokens.clone()).unwrap();
+        assert_eq!(config.to_token_stream().to_string(), tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_malformed_lists() {
+        parse_meta::<MetaTree>(quote!(config(a b))).unwrap_err();
+    }
+}
@@ -1,24 +1,77 @@
 //! Utility types for attribute parsing.
 
+mod byte_size;
+mod callable;
+mod config_file;
+mod docs;
+mod either;
+mod expand_env;
+mod field_info;
 mod flag;
 mod ident_string;
 mod ignored;
+#[cfg(feature = "json")]
+mod json_schema;
+#[cfg(any(feature = "json", feature = "serde"))]
+mod json_value;
+mod lazy;
+mod meta_list;
+mod meta_parser;
+mod meta_tree;
+mod number;
+mod one_or_many;
 mod over_ride;
 mod parse_attribute;
 pub mod parse_expr;
+mod parse_from_str;
+mod parse_nested_meta;
 mod path_list;
 mod path_to_string;
+mod pretty;
+mod range_bound;
+mod raw_meta;
+#[cfg(feature = "serde")]
+mod serde_value;
 mod shape;
 mod spanned_value;
+mod syn_parse;
+#[cfg(feature = "toml")]
+mod toml_value;
+mod warnings;
 mod with_original;
 
+pub use self::byte_size::ByteSize;
+pub use self::callable::Callable;
+pub use self::config_file::items_with_file;
+pub use self::docs::{doc_lines, FromDocs};
+pub use self::either::Either;
+pub use self::expand_env::expand_env;
+pub use self::field_info::{fields_markdown, FieldInfo};
 pub use self::flag::Flag;
 pub use self::ident_string::IdentString;
 pub use self::ignored::Ignored;
+#[cfg(feature = "json")]
+pub use self::json_schema::fields_json_schema;
+pub use self::lazy::Lazy;
+pub use self::meta_list::meta_list;
+pub use self::meta_parser::{MetaMap, MetaParser, MetaParserBuilder, MetaValue, ValueType};
+pub use self::meta_tree::MetaTree;
+pub use self::number::Number;
+pub use self::one_or_many::OneOrMany;
 pub use self::over_ride::Override;
-pub use self::parse_attribute::parse_attribute_to_meta_list;
+pub use self::parse_attribute::{parse_attribute_to_meta_list, unwrap_cfg_attr};
+pub use self::parse_from_str::ParseFromStr;
+pub use self::parse_nested_meta::{parse_nested_items, parse_nested_meta, MetaItem};
 pub use self::path_list::PathList;
 pub use self::path_to_string::path_to_string;
+pub use self::pretty::pretty_meta;
+#[doc(hidden)]
+pub use self::range_bound::{range_bound, RangeBound};
+pub use self::raw_meta::FromRawMeta;
+#[cfg(feature = "serde")]
+pub use self::serde_value::SerdeValue;
 pub use self::shape::{AsShape, Shape, ShapeSet};
 pub use self::spanned_value::SpannedValue;
+pub use self::syn_parse::SynParse;
+pub use self::warnings::Warnings;
 pub use self::with_original::WithOriginal;
@@ -0,0 +1,218 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{Expr, ExprLit, ExprUnary, Lit, LitFloat, LitInt, UnOp};
+
+use crate::{Error, FromMeta, Result};
+
+/// Any integer or float literal, for options that accept "a number" without committing
+/// to a specific primitive type.
+///
+/// The literal is kept as written, including its suffix and a leading minus sign,
+/// so `#[example(offset = -5i16)]` is read as an `Int` whose digits are `-5` and whose
+/// suffix is `i16`. Use the `as_*` methods to convert it once the target type is known;
+/// conversion errors point at the literal.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Tolerance {
+///     value: Number,
+/// }
+///
+/// let epsilon = if tolerance.value.is_float() {
+///     tolerance.value.as_f64()?
+/// } else {
+///     tolerance.value.as_i64()? as f64
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Number {
+    Int(LitInt),
+    Float(LitFloat),
+}
+
+impl Number {
+    /// Whether the literal was written as a float, e.g. `1.0` or `2e3`.
+    pub fn is_float(&self) -> bool {
+        matches!(self, Number::Float(_))
+    }
+
+    /// Whether the literal was preceded by a minus sign.
+    pub fn is_negative(&self) -> bool {
+        self.base10_digits().starts_with('-')
+    }
+
+    /// The digits of the literal in base 10, including any minus sign but not the suffix.
+    pub fn base10_digits(&self) -> &str {
+        match self {
+            Number::Int(int) => int.base10_digits(),
+            Number::Float(float) => float.base10_digits(),
+        }
+    }
+
+    /// The type suffix of the literal, such as `u8` in `7u8`, or `""` if there isn't one.
+    pub fn suffix(&self) -> &str {
+        match self {
+            Number::Int(int) => int.suffix(),
+            Number::Float(float) => float.suffix(),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Number::Int(int) => int.span(),
+            Number::Float(float) => float.span(),
+        }
+    }
+
+    pub fn as_i64(&self) -> Result<i64> {
+        self.parse_int()
+    }
+
+    pub fn as_i128(&self) -> Result<i128> {
+        self.parse_int()
+    }
+
+    pub fn as_isize(&self) -> Result<isize> {
+        self.parse_int()
+    }
+
+    pub fn as_u64(&self) -> Result<u64> {
+        self.parse_int()
+    }
+
+    pub fn as_u128(&self) -> Result<u128> {
+        self.parse_int()
+    }
+
+    pub fn as_usize(&self) -> Result<usize> {
+        self.parse_int()
+    }
+
+    /// Convert the literal to an `f32`; integer literals are accepted.
+    pub fn as_f32(&self) -> Result<f32> {
+        self.parse_float()
+    }
+
+    /// Convert the literal to an `f64`; integer literals are accepted.
+    pub fn as_f64(&self) -> Result<f64> {
+        self.parse_float()
+    }
+
+    fn parse_int<N>(&self) -> Result<N>
+    where
+        N: FromStr,
+        N::Err: Display,
+    {
+        match self {
+            Number::Int(int) => int.base10_parse().map_err(Error::from),
+            Number::Float(_) => Err(Error::unexpected_type("float").with_span(self)),
+        }
+    }
+
+    fn parse_float<N>(&self) -> Result<N>
+    where
+        N: FromStr,
+        N::Err: Display,
+    {
+        match self {
+            Number::Int(int) => int.base10_parse().map_err(Error::from),
+            Number::Float(float) => float.base10_parse().map_err(Error::from),
+        }
+    }
+
+    fn negated(&self) -> Result<Self> {
+        if self.is_negative() {
+            return Err(Error::custom("Number cannot be negated twice").with_span(self));
+        }
+
+        let repr = format!("-{}", self.to_token_stream());
+        Ok(match self {
+            Number::Int(int) => Number::Int(LitInt::new(&repr, int.span())),
+            Number::Float(float) => Number::Float(LitFloat::new(&repr, float.span())),
+        })
+    }
+}
+
+impl FromMeta for Number {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Lit(ExprLit { lit, .. }) => Self::from_value(lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr: inner,
+                ..
+            }) => Self::from_expr(inner)?.negated(),
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        match value {
+            Lit::Int(int) => Ok(Number::Int(int.clone())),
+            Lit::Float(float) => Ok(Number::Float(float.clone())),
+            _ => Err(Error::unexpected_lit_type(value)),
+        }
+    }
+}
+
+impl ToTokens for Number {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Number::Int(int) => int.to_tokens(tokens),
+            Number::Float(float) => float.to_tokens(tokens),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::Number;
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn int() {
+        let n = parse_meta::<Number>(quote!(ignore = 300u16)).unwrap();
+        assert!(!n.is_float());
+        assert_eq!(n.suffix(), "u16");
+        assert_eq!(n.as_u64().unwrap(), 300);
+        assert_eq!(n.as_f64().unwrap(), 300.0);
+    }
+
+    #[test]
+    fn negative() {
+        let n = parse_meta::<Number>(quote!(ignore = -12i8)).unwrap();
+        assert!(n.is_negative());
+        assert_eq!(n.base10_digits(), "-12");
+        assert_eq!(n.suffix(), "i8");
+        assert_eq!(n.as_i64().unwrap(), -12);
+        n.as_u64().unwrap_err();
+
+        let n = parse_meta::<Number>(quote!(ignore = -0.5)).unwrap();
+        assert!(n.is_float());
+        assert_eq!(n.as_f32().unwrap(), -0.5);
+    }
+
+    #[test]
+    fn float_is_not_int() {
+        let n = parse_meta::<Number>(quote!(ignore = 2.5f32)).unwrap();
+        assert_eq!(n.suffix(), "f32");
+        assert_eq!(n.as_f64().unwrap(), 2.5);
+        n.as_i64().unwrap_err();
+    }
+
+    #[test]
+    fn rejects_other_values() {
+        parse_meta::<Number>(quote!(ignore = "5")).unwrap_err();
+        parse_meta::<Number>(quote!(ignore = --5)).unwrap_err();
+        parse_meta::<Number>(quote!(ignore = -x)).unwrap_err();
+        parse_meta::<Number>(quote!(ignore)).unwrap_err();
+    }
+}
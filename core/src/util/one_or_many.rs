@@ -0,0 +1,114 @@
+use std::ops::{Deref, DerefMut};
+
+use syn::{Expr, Meta, Path};
+
+use crate::ast::NestedMeta;
+use crate::{FromMeta, Result, ToMetaItem};
+
+/// A list of values which also accepts a single value as a one-element list.
+///
+/// `Vec<T>` only accepts a list, such as `foo(a, b)`, or an array, such as `foo = [a, b]`. A
+/// `OneOrMany<T>` field accepts those too, and additionally parses any other value with `T`,
+/// so `foo = a` is the same as `foo = [a]`.
+///
+/// # Usage
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Options {
+///     tag: OneOrMany<String>,
+/// }
+///
+/// // Both of these produce `vec!["a"]`:
+/// // #[opts(tag = "a")]
+/// // #[opts(tag("a"))]
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct OneOrMany<T>(pub Vec<T>);
+
+impl<T> OneOrMany<T> {
+    /// Unwrap the list of values.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for OneOrMany<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for OneOrMany<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec<T>> for OneOrMany<T> {
+    fn from(v: Vec<T>) -> Self {
+        OneOrMany(v)
+    }
+}
+
+impl<T> From<OneOrMany<T>> for Vec<T> {
+    fn from(v: OneOrMany<T>) -> Self {
+        v.0
+    }
+}
+
+impl<T: FromMeta> FromMeta for OneOrMany<T> {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        Vec::from_list(items).map(OneOrMany)
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Array(_) => Vec::from_expr(expr).map(OneOrMany),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => T::from_expr(expr)
+                .map(|value| OneOrMany(vec![value]))
+                .map_err(|e| e.with_span(expr)),
+        }
+    }
+}
+
+impl<T: ToMetaItem> ToMetaItem for OneOrMany<T> {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        self.0.to_meta_item(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::OneOrMany;
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn one_or_many() {
+        assert_eq!(
+            *parse_meta::<OneOrMany<String>>(quote!(ignore = "a")).unwrap(),
+            vec!["a"]
+        );
+        assert_eq!(
+            *parse_meta::<OneOrMany<String>>(quote!(ignore = "[a]")).unwrap(),
+            vec!["[a]"]
+        );
+        assert_eq!(
+            *parse_meta::<OneOrMany<u16>>(quote!(ignore = 7)).unwrap(),
+            vec![7]
+        );
+        assert_eq!(
+            *parse_meta::<OneOrMany<u16>>(quote!(ignore = [1, 2])).unwrap(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            *parse_meta::<OneOrMany<String>>(quote!(ignore("a", "b"))).unwrap(),
+            vec!["a", "b"]
+        );
+        parse_meta::<OneOrMany<u8>>(quote!(ignore = 256)).unwrap_err();
+    }
+}
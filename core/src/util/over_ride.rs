@@ -1,9 +1,11 @@
 use std::fmt;
 
+use proc_macro2::TokenStream;
+use quote::ToTokens;
 use syn::Lit;
 
 use crate::ast::NestedMeta;
-use crate::{FromMeta, Result};
+use crate::{FromMeta, Result, ToMetaItem};
 
 use self::Override::*;
 
@@ -134,6 +136,15 @@ impl<T: fmt::Display> fmt::Display for Override<T> {
     }
 }
 
+/// Emits the explicit value, or nothing for `Override::Inherit`.
+impl<T: ToTokens> ToTokens for Override<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Explicit(value) = self {
+            value.to_tokens(tokens);
+        }
+    }
+}
+
 /// Parses a `Meta`. A bare word will produce `Override::Inherit`, while
 /// any value will be forwarded to `T::from_meta`.
 impl<T: FromMeta> FromMeta for Override<T> {
@@ -149,3 +160,13 @@ impl<T: FromMeta> FromMeta for Override<T> {
         Ok(Explicit(FromMeta::from_value(lit)?))
     }
 }
+
+/// `Override::Inherit` is rendered as a word, and an explicit value is rendered by `T`.
+impl<T: ToMetaItem> ToMetaItem for Override<T> {
+    fn to_meta_item(&self, path: &syn::Path) -> Option<syn::Meta> {
+        match self {
+            Inherit => Some(syn::Meta::Path(path.clone())),
+            Explicit(value) => value.to_meta_item(path),
+        }
+    }
+}
@@ -1,8 +1,9 @@
+use crate::util::path_to_string;
 use crate::{Error, Result};
 use std::fmt;
-use syn::punctuated::Pair;
+use syn::punctuated::{Pair, Punctuated};
 use syn::spanned::Spanned;
-use syn::{token, Attribute, Meta, MetaList, Path};
+use syn::{parse_quote, token, Attribute, Meta, MetaList, Path, Token};
 
 /// Try to parse an attribute into a meta list. Path-type meta values are accepted and returned
 /// as empty lists with their passed-in path. Name-value meta values and non-meta attributes
@@ -32,6 +33,64 @@ pub fn parse_attribute_to_meta_list(attr: &Attribute) -> Result<MetaList> {
     }
 }
 
+/// Expand `#[cfg_attr(predicate, ...)]` attributes that contain any of the attribute `names`.
+///
+/// Each matching inner attribute is returned in the position of its `cfg_attr`, paired with
+/// the predicate it's conditional on, and followed by the original `cfg_attr` so it can still
+/// be forwarded. Attributes which aren't unwrapped are paired with `None`. Nested `cfg_attr`s
+/// are expanded too, and their predicates combined with `all(...)`.
+///
+/// A proc-macro can't evaluate the predicate, so it's up to the caller to make the code it
+/// generates from each unwrapped attribute conditional on its predicate.
+/// Malformed `cfg_attr` attributes are returned unchanged for the compiler to report.
+pub fn unwrap_cfg_attr(attrs: &[Attribute], names: &[&str]) -> Vec<(Option<Meta>, Attribute)> {
+    let mut unwrapped = Vec::with_capacity(attrs.len());
+    for attr in attrs {
+        push_cfg_attr_items(attr, &attr.meta, None, names, &mut unwrapped);
+        unwrapped.push((None, attr.clone()));
+    }
+    unwrapped
+}
+
+fn push_cfg_attr_items(
+    attr: &Attribute,
+    meta: &Meta,
+    outer: Option<&Meta>,
+    names: &[&str],
+    out: &mut Vec<(Option<Meta>, Attribute)>,
+) {
+    let list = match meta {
+        Meta::List(list) if list.path.is_ident("cfg_attr") => list,
+        _ => return,
+    };
+
+    let items = match list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+        Ok(items) => items,
+        Err(_) => return,
+    };
+
+    let mut items = items.into_iter();
+    let predicate = match (items.next(), outer) {
+        (Some(predicate), None) => predicate,
+        (Some(predicate), Some(outer)) => parse_quote!(all(#outer, #predicate)),
+        (None, _) => return,
+    };
+
+    for item in items {
+        if names.contains(&path_to_string(item.path()).as_str()) {
+            out.push((
+                Some(predicate.clone()),
+                Attribute {
+                    meta: item,
+                    ..attr.clone()
+                },
+            ));
+        } else {
+            push_cfg_attr_items(attr, &item, Some(&predicate), names, out);
+        }
+    }
+}
+
 struct DisplayPath<'a>(&'a Path);
 
 impl fmt::Display for DisplayPath<'_> {
@@ -53,7 +112,7 @@ impl fmt::Display for DisplayPath<'_> {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_attribute_to_meta_list;
+    use super::{parse_attribute_to_meta_list, unwrap_cfg_attr};
     use crate::ast::NestedMeta;
     use syn::spanned::Spanned;
     use syn::{parse_quote, Ident};
@@ -83,4 +142,34 @@ mod tests {
         let err = parse_attribute_to_meta_list(&parse_quote!(#[bar = 4])).unwrap_err();
         assert!(err.to_string().contains("#[bar(...)]"));
     }
+
+    #[test]
+    fn unwrap_cfg_attr_extracts_named_attributes() {
+        let attrs: Vec<syn::Attribute> = vec![
+            parse_quote!(#[cfg_attr(feature = "a", bar(x), derive(Debug))]),
+            parse_quote!(#[cfg_attr(test, cfg_attr(unix, bar(y)))]),
+            parse_quote!(#[cfg_attr(test, derive(Clone))]),
+            parse_quote!(#[bar(z)]),
+        ];
+
+        let unwrapped = unwrap_cfg_attr(&attrs, &["bar"]);
+        let expected: Vec<(Option<syn::Meta>, syn::Attribute)> = vec![
+            (Some(parse_quote!(feature = "a")), parse_quote!(#[bar(x)])),
+            (None, attrs[0].clone()),
+            (Some(parse_quote!(all(test, unix))), parse_quote!(#[bar(y)])),
+            (None, attrs[1].clone()),
+            (None, attrs[2].clone()),
+            (None, attrs[3].clone()),
+        ];
+        assert_eq!(unwrapped, expected);
+    }
+
+    #[test]
+    fn unwrap_cfg_attr_keeps_malformed() {
+        let attrs: Vec<syn::Attribute> = vec![parse_quote!(#[cfg_attr(bar x)])];
+        assert_eq!(
+            unwrap_cfg_attr(&attrs, &["bar"]),
+            vec![(None, attrs[0].clone())]
+        );
+    }
 }
@@ -0,0 +1,113 @@
+use std::fmt::Display;
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Lit;
+
+use crate::{Error, FromMeta, Result};
+
+/// A value parsed with its `FromStr` impl, so any such type can be used as a field without
+/// writing a `FromMeta` impl for it.
+///
+/// String literals are parsed as written. Unquoted integer, float, and boolean literals are
+/// parsed from their digits or value, so `ParseFromStr<BigUint>` accepts both `limit = "10"`
+/// and `limit = 10`. Errors from `FromStr` are reported with the rejected text.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Options {
+///     /// Accepts `mime = "text/plain"`.
+///     mime: ParseFromStr<mime::Mime>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ParseFromStr<T>(pub T);
+
+impl<T> ParseFromStr<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for ParseFromStr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for ParseFromStr<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: ToTokens> ToTokens for ParseFromStr<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl<T> FromMeta for ParseFromStr<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn from_string(value: &str) -> Result<Self> {
+        value
+            .parse()
+            .map(ParseFromStr)
+            .map_err(|e| Error::custom(format!("Unable to parse `{}`: {}", value, e)))
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        match value {
+            Lit::Str(s) => Self::from_string(&s.value()),
+            Lit::Int(int) => Self::from_string(int.base10_digits()),
+            Lit::Float(float) => Self::from_string(float.base10_digits()),
+            Lit::Bool(b) => Self::from_string(if b.value { "true" } else { "false" }),
+            _ => Err(Error::unexpected_lit_type(value)),
+        }
+        .map_err(|e| e.with_span(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use syn::parse_quote;
+
+    use super::ParseFromStr;
+    use crate::FromMeta;
+
+    #[test]
+    fn string_values() {
+        let addr: ParseFromStr<Ipv4Addr> =
+            FromMeta::from_meta(&parse_quote!(ignore = "127.0.0.1")).unwrap();
+        assert_eq!(*addr, Ipv4Addr::LOCALHOST);
+    }
+
+    #[test]
+    fn unquoted_literals() {
+        let n: ParseFromStr<u128> = FromMeta::from_meta(&parse_quote!(ignore = 7u8)).unwrap();
+        assert_eq!(n.into_inner(), 7);
+        let b: ParseFromStr<bool> = FromMeta::from_meta(&parse_quote!(ignore = true)).unwrap();
+        assert!(*b);
+    }
+
+    #[test]
+    fn errors() {
+        let err =
+            ParseFromStr::<Ipv4Addr>::from_meta(&parse_quote!(ignore = "localhost")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unable to parse `localhost`: invalid IPv4 address syntax"
+        );
+        ParseFromStr::<u8>::from_meta(&parse_quote!(ignore = b"1")).unwrap_err();
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt;
+
+use syn::{Meta, Path};
+
+use crate::ast::NestedMeta;
+use crate::util::path_to_string;
+use crate::{Error, FromMeta, Result};
+
+/// Call `f` with each item of a meta list, such as `opts(name = "lorem", skip)`, as a
+/// lightweight alternative to defining a receiver struct for a small attribute.
+///
+/// Every error returned by `f` is collected, rather than stopping at the first, and errors
+/// without a span are given the span of the item. A bare word is treated as an empty list,
+/// and literals in the list are reported as errors without calling `f`.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut name = None;
+/// let mut skip = false;
+/// parse_nested_meta(&attr.meta, |item| {
+///     if item.is("name") {
+///         name = Some(item.get_string()?);
+///     } else if item.is("skip") {
+///         skip = true;
+///     } else {
+///         return Err(item.unknown_field());
+///     }
+///     Ok(())
+/// })?;
+/// ```
+pub fn parse_nested_meta<F>(meta: &Meta, f: F) -> Result<()>
+where
+    F: FnMut(MetaItem<'_>) -> Result<()>,
+{
+    match meta {
+        Meta::Path(_) => parse_nested_items(&[], f),
+        Meta::List(list) => {
+            parse_nested_items(&NestedMeta::parse_meta_list(list.tokens.clone())?, f)
+        }
+        Meta::NameValue(_) => Err(Error::unsupported_format("name value")),
+    }
+    .map_err(|e| e.with_span(meta))
+}
+
+/// Call `f` with each item of a list which has already been split, as
+/// [`parse_nested_meta`] does.
+pub fn parse_nested_items<F>(items: &[NestedMeta], mut f: F) -> Result<()>
+where
+    F: FnMut(MetaItem<'_>) -> Result<()>,
+{
+    let mut errors = Error::accumulator();
+    for item in items {
+        match item {
+            NestedMeta::Meta(meta) => {
+                errors.handle(f(MetaItem { meta }).map_err(|e| e.with_span(meta)));
+            }
+            NestedMeta::Lit(lit) => {
+                errors.push(Error::unsupported_format("literal").with_span(lit));
+            }
+        }
+    }
+
+    errors.finish()
+}
+
+/// One item of a list being walked by [`parse_nested_meta`].
+#[derive(Debug, Clone, Copy)]
+pub struct MetaItem<'a> {
+    meta: &'a Meta,
+}
+
+impl<'a> MetaItem<'a> {
+    /// The item as written.
+    pub fn meta(&self) -> &'a Meta {
+        self.meta
+    }
+
+    /// The path which names the item.
+    pub fn path(&self) -> &'a Path {
+        self.meta.path()
+    }
+
+    /// The name of the item, with path segments joined by `::`.
+    pub fn name(&self) -> String {
+        path_to_string(self.path())
+    }
+
+    /// Returns `true` if the item is named `name`.
+    pub fn is(&self, name: &str) -> bool {
+        self.name() == name
+    }
+
+    /// Parse the item's value. Errors are located at the item's name.
+    pub fn get<T: FromMeta>(&self) -> Result<T> {
+        T::from_meta(self.meta).map_err(|e| e.at(self.name()))
+    }
+
+    /// Parse the item's value as a string, such as `name = "lorem"`.
+    pub fn get_string(&self) -> Result<String> {
+        self.get()
+    }
+
+    /// Parse the item's value as a path, such as `with = my::func` or `with = "my::func"`.
+    pub fn get_path(&self) -> Result<Path> {
+        self.get()
+    }
+
+    /// Parse the item's value as a boolean. A bare word, such as `skip`, is `true`.
+    pub fn get_bool(&self) -> Result<bool> {
+        self.get()
+    }
+
+    /// Walk the items of a nested list, such as `retry(attempts = 3)`.
+    pub fn parse_nested<F>(&self, f: F) -> Result<()>
+    where
+        F: FnMut(MetaItem<'_>) -> Result<()>,
+    {
+        parse_nested_meta(self.meta, f).map_err(|e| e.at(self.name()))
+    }
+
+    /// Create an error with a custom message, spanned to the item.
+    pub fn error<T: fmt::Display>(&self, msg: T) -> Error {
+        Error::custom(msg).with_span(self.meta)
+    }
+
+    /// Create an error saying the item isn't a known field, spanned to its name.
+    pub fn unknown_field(&self) -> Error {
+        Error::unknown_field_path(self.path()).with_span(self.path())
+    }
+
+    /// Create an error saying the item isn't a known field, suggesting one of `alternates`
+    /// if a similar name is found.
+    pub fn unknown_field_with_alts<'b, T, I>(&self, alternates: I) -> Error
+    where
+        T: AsRef<str> + 'b,
+        I: IntoIterator<Item = &'b T>,
+    {
+        Error::unknown_field_with_alts(&self.name(), alternates).with_span(self.path())
+    }
+
+    /// Create an error saying the item was given more than once, spanned to the item.
+    pub fn duplicate_field(&self) -> Error {
+        Error::duplicate_field_path(self.path()).with_span(self.meta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::parse_nested_meta;
+    use crate::Error;
+
+    #[test]
+    fn walks_items() {
+        let mut name = None;
+        let mut skip = false;
+        let mut attempts = 0u8;
+        parse_nested_meta(
+            &parse_quote!(opts(name = "lorem", skip, retry(attempts = 3))),
+            |item| {
+                if item.is("name") {
+                    name = Some(item.get_string()?);
+                } else if item.is("skip") {
+                    skip = item.get_bool()?;
+                } else if item.is("retry") {
+                    item.parse_nested(|item| {
+                        attempts = item.get()?;
+                        Ok(())
+                    })?;
+                } else {
+                    return Err(item.unknown_field());
+                }
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert_eq!(name.as_deref(), Some("lorem"));
+        assert!(skip);
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn collects_errors() {
+        let err = parse_nested_meta(
+            &parse_quote!(opts(name = 1, "lit", other, with = "not a path")),
+            |item| {
+                if item.is("name") {
+                    item.get_string().map(|_| ())
+                } else if item.is("with") {
+                    item.get_path().map(|_| ())
+                } else {
+                    Err(item.unknown_field())
+                }
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.len(), 4);
+        assert!(err.into_iter().all(|e: Error| e.has_span()));
+    }
+}
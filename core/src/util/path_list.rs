@@ -1,11 +1,13 @@
 use std::ops::Deref;
 
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
 use syn::{Meta, Path};
 
 use crate::ast::NestedMeta;
-use crate::{Error, FromMeta, Result};
+use crate::{Error, FromMeta, Result, ToMetaItem};
 
-use super::path_to_string;
+use super::{meta_list, path_to_string};
 
 /// A list of `syn::Path` instances. This type is used to extract a list of paths from an
 /// attribute.
@@ -62,6 +64,25 @@ impl FromMeta for PathList {
     }
 }
 
+/// Emits the paths separated by commas, so `#[derive(#paths)]` applies each of them.
+impl ToTokens for PathList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let paths = &self.0;
+        tokens.extend(quote!(#(#paths),*));
+    }
+}
+
+impl ToMetaItem for PathList {
+    fn to_meta_item(&self, path: &Path) -> Option<Meta> {
+        let items = self
+            .0
+            .iter()
+            .map(|item| NestedMeta::Meta(Meta::Path(item.clone())))
+            .collect();
+        Some(meta_list(path, items))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::PathList;
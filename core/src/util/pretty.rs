@@ -0,0 +1,209 @@
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::Meta;
+
+use crate::ast::NestedMeta;
+use crate::util::path_to_string;
+
+/// The width at which a list is broken onto multiple lines.
+const MAX_WIDTH: usize = 80;
+
+/// Render a meta item with attribute-style syntax for people to read, such as in error
+/// messages and debug logs.
+///
+/// Short lists stay on one line, as in `retry(attempts = 3)`. Lists which would be wider
+/// than 80 columns put each item on its own line, indented by four spaces and followed by a
+/// comma. Values are written without the spaces that `TokenStream`'s `Display` impl puts
+/// between every token, so a path is written `std::fmt::Debug` rather than `std :: fmt :: Debug`.
+///
+/// Types which implement [`ToMetaItem`](crate::ToMetaItem) can use
+/// [`to_pretty_string`](crate::ToMetaItem::to_pretty_string) instead.
+pub fn pretty_meta(meta: &Meta) -> String {
+    render_meta(meta, 0)
+}
+
+fn render_meta(meta: &Meta, indent: usize) -> String {
+    match meta {
+        Meta::Path(path) => path_to_string(path),
+        Meta::NameValue(nv) => format!(
+            "{} = {}",
+            path_to_string(&nv.path),
+            render_tokens(nv.value.to_token_stream())
+        ),
+        Meta::List(list) => {
+            let name = path_to_string(&list.path);
+            let items = match NestedMeta::parse_meta_list(list.tokens.clone()) {
+                Ok(items) => items,
+                Err(_) => return format!("{}({})", name, render_tokens(list.tokens.clone())),
+            };
+
+            let rendered: Vec<String> = items
+                .iter()
+                .map(|item| render_nested(item, indent + 1))
+                .collect();
+
+            let one_line = format!("{}({})", name, rendered.join(", "));
+            if indent * 4 + one_line.len() <= MAX_WIDTH && !one_line.contains('\n') {
+                return one_line;
+            }
+
+            let pad = "    ".repeat(indent + 1);
+            let mut out = format!("{}(\n", name);
+            for item in rendered {
+                out.push_str(&pad);
+                out.push_str(&item);
+                out.push_str(",\n");
+            }
+            out.push_str(&"    ".repeat(indent));
+            out.push(')');
+            out
+        }
+    }
+}
+
+fn render_nested(item: &NestedMeta, indent: usize) -> String {
+    match item {
+        NestedMeta::Meta(meta) => render_meta(meta, indent),
+        NestedMeta::Lit(lit) => render_tokens(lit.to_token_stream()),
+    }
+}
+
+/// What was last written by `render_tokens`, which decides whether the next token is
+/// preceded by a space.
+#[derive(Clone, Copy, PartialEq)]
+enum Last {
+    /// Nothing, or an opening delimiter.
+    Start,
+    /// An identifier, literal, or group.
+    Word,
+    /// Punctuation which is continued by the next character, such as the first `:` of `::`.
+    Joint,
+    /// An operator which the next token is written right after, such as `::`, `.`, or a
+    /// unary operator.
+    Tight,
+    /// An operator which is followed by a space, such as `,` or a binary operator.
+    Spaced,
+}
+
+/// Write tokens with spaces only where a person would put them.
+fn render_tokens(tokens: TokenStream) -> String {
+    let mut out = String::new();
+    let mut last = Last::Start;
+    // The operator being written, and whether it's unary.
+    let mut op = String::new();
+    let mut unary = false;
+
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{ ", " }"),
+                    Delimiter::None => ("", ""),
+                };
+                if last == Last::Spaced || (last == Last::Word && open == "{ ") {
+                    out.push(' ');
+                }
+                out.push_str(open);
+                out.push_str(&render_tokens(group.stream()));
+                out.push_str(close);
+                last = Last::Word;
+            }
+            TokenTree::Ident(_) | TokenTree::Literal(_) => {
+                if matches!(last, Last::Word | Last::Spaced) {
+                    out.push(' ');
+                }
+                out.push_str(&token.to_string());
+                last = Last::Word;
+            }
+            TokenTree::Punct(punct) => {
+                let ch = punct.as_char();
+                if last != Last::Joint {
+                    op.clear();
+                    unary = last != Last::Word;
+                    let space_before = match ch {
+                        ',' | ';' => false,
+                        '.' | ':' => last == Last::Spaced,
+                        _ => matches!(last, Last::Word | Last::Spaced),
+                    };
+                    if space_before {
+                        out.push(' ');
+                    }
+                }
+                op.push(ch);
+                out.push(ch);
+
+                last = if punct.spacing() == Spacing::Joint {
+                    Last::Joint
+                } else if matches!(op.as_str(), "::" | "." | ".." | "#")
+                    || unary && matches!(op.as_str(), "-" | "!" | "&" | "*" | "&&")
+                {
+                    Last::Tight
+                } else {
+                    Last::Spaced
+                };
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::pretty_meta;
+
+    #[test]
+    fn one_line() {
+        assert_eq!(
+            pretty_meta(&parse_quote!(opts(
+                ty = std::fmt::Debug,
+                level = -1,
+                tags = ["a", "b"],
+                retry(attempts = 3)
+            ))),
+            r#"opts(ty = std::fmt::Debug, level = -1, tags = ["a", "b"], retry(attempts = 3))"#
+        );
+    }
+
+    #[test]
+    fn multiline() {
+        assert_eq!(
+            pretty_meta(&parse_quote!(opts(
+                name = "a fairly long name for the receiver",
+                retry(attempts = 3, backoff = "exponential", jitter),
+                skip
+            ))),
+            r#"opts(
+    name = "a fairly long name for the receiver",
+    retry(attempts = 3, backoff = "exponential", jitter),
+    skip,
+)"#
+        );
+    }
+
+    #[test]
+    fn expressions() {
+        assert_eq!(
+            pretty_meta(&parse_quote!(f = !x.is_power_of_two() && y < 2 * z)),
+            "f = !x.is_power_of_two() && y < 2 * z"
+        );
+        assert_eq!(
+            pretty_meta(&parse_quote!(ty = "Vec<u8>")),
+            r#"ty = "Vec<u8>""#
+        );
+        assert_eq!(
+            pretty_meta(&parse_quote!(
+                init = Config {
+                    a: 1,
+                    ..Default::default()
+                }
+            )),
+            "init = Config { a: 1, ..Default::default() }"
+        );
+        assert_eq!(pretty_meta(&parse_quote!(a::b)), "a::b");
+    }
+}
@@ -0,0 +1,52 @@
+/// A value which can be checked against a `#[darling(range(...))]` bound.
+///
+/// Generated code passes an unsuffixed integer bound twice: once as written, and once with
+/// `.0` appended. Integer types take the first and float types the second, so `min = 0` works
+/// for both `u8` and `f64` fields, and an integer bound that doesn't fit the field's type is
+/// still rejected by the compiler.
+#[doc(hidden)]
+pub trait RangeBound: PartialOrd {
+    type Int;
+    type Float;
+
+    fn from_bound(int: Self::Int, float: Self::Float) -> Self;
+}
+
+/// Convert a range bound to the type of `value`. Only generated code should call this.
+#[doc(hidden)]
+pub fn range_bound<T: RangeBound>(_value: &T, int: T::Int, float: T::Float) -> T {
+    T::from_bound(int, float)
+}
+
+macro_rules! int_bound {
+    ($($ty:ty),*) => {
+        $(
+            impl RangeBound for $ty {
+                type Int = $ty;
+                type Float = f64;
+
+                fn from_bound(int: Self, _float: f64) -> Self {
+                    int
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float_bound {
+    ($($ty:ty),*) => {
+        $(
+            impl RangeBound for $ty {
+                type Int = i128;
+                type Float = $ty;
+
+                fn from_bound(_int: i128, float: Self) -> Self {
+                    float
+                }
+            }
+        )*
+    };
+}
+
+int_bound!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+float_bound!(f32, f64);
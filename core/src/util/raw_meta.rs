@@ -0,0 +1,89 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Meta;
+
+use crate::ast::NestedMeta;
+use crate::{Error, Result};
+
+/// A type which a `#[darling(raw)]` field can have, to receive its item without parsing it,
+/// such as to forward a sub-attribute verbatim to another library's parser.
+///
+/// Unlike the type's `FromMeta` impl, which may interpret the item, these impls keep it
+/// exactly as written:
+///
+/// * `syn::Meta` receives the whole item, as in `serde(rename = "x")`.
+/// * `Vec<NestedMeta>` receives the items of a list, as in `rename = "x"`. A word has no items,
+///   and a name-value item is an error.
+/// * `proc_macro2::TokenStream` receives the tokens of the whole item, including its name.
+/// * `Option<T>` is `Some` of `T`'s value.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromDeriveInput)]
+/// #[darling(attributes(my_macro))]
+/// struct Receiver {
+///     /// Forwarded to serde as `#[serde(...)]`.
+///     #[darling(raw, default)]
+///     serde: Option<syn::Meta>,
+/// }
+/// ```
+pub trait FromRawMeta: Sized {
+    fn from_raw_meta(item: &Meta) -> Result<Self>;
+}
+
+impl FromRawMeta for Meta {
+    fn from_raw_meta(item: &Meta) -> Result<Self> {
+        Ok(item.clone())
+    }
+}
+
+impl FromRawMeta for Vec<NestedMeta> {
+    fn from_raw_meta(item: &Meta) -> Result<Self> {
+        match item {
+            Meta::Path(_) => Ok(vec![]),
+            Meta::List(list) => {
+                NestedMeta::parse_meta_list(list.tokens.clone()).map_err(Error::from)
+            }
+            Meta::NameValue(_) => Err(Error::unsupported_format("name value").with_span(item)),
+        }
+    }
+}
+
+impl FromRawMeta for TokenStream {
+    fn from_raw_meta(item: &Meta) -> Result<Self> {
+        Ok(item.to_token_stream())
+    }
+}
+
+impl<T: FromRawMeta> FromRawMeta for Option<T> {
+    fn from_raw_meta(item: &Meta) -> Result<Self> {
+        T::from_raw_meta(item).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use quote::quote;
+    use syn::parse_quote;
+
+    use super::FromRawMeta;
+    use crate::ast::NestedMeta;
+
+    #[test]
+    fn keeps_items_as_written() {
+        let meta: syn::Meta = parse_quote!(serde(rename = "x", default));
+        assert_eq!(syn::Meta::from_raw_meta(&meta).unwrap(), meta);
+        assert_eq!(
+            TokenStream::from_raw_meta(&meta).unwrap().to_string(),
+            quote!(serde(rename = "x", default)).to_string()
+        );
+
+        let items = Vec::<NestedMeta>::from_raw_meta(&meta).unwrap();
+        assert_eq!(items.len(), 2);
+        assert!(Vec::<NestedMeta>::from_raw_meta(&parse_quote!(serde))
+            .unwrap()
+            .is_empty());
+        Vec::<NestedMeta>::from_raw_meta(&parse_quote!(serde = "x")).unwrap_err();
+    }
+}
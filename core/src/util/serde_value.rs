@@ -0,0 +1,148 @@
+use std::ops::{Deref, DerefMut};
+
+use serde_crate::de::DeserializeOwned;
+use serde_json::Value;
+use syn::Expr;
+
+use crate::ast::NestedMeta;
+use crate::util::json_value::{expr_to_json, list_to_json};
+use crate::{Error, FromMeta, Result};
+
+/// A value deserialized with `serde` from the meta item, so that configuration types
+/// written for files can be reused in attributes. This requires the `serde` feature.
+///
+/// The meta item is read the way a JSON document would be:
+///
+/// * `name = "text"`, `name = 5`, `name = -1.5`, and `name = true` are strings, numbers, and booleans.
+/// * `name = [..]` and a list of literals, such as `name("a", "b")`, are arrays.
+/// * A list of named items, such as `name(a = 1, b(c = 2), d)`, is an object. A bare word
+///   such as `d` is `true`.
+/// * A bare word on its own, such as `name`, is `null`.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Retry {
+///     attempts: u8,
+///     backoff: Backoff,
+/// }
+///
+/// #[derive(FromMeta)]
+/// struct Options {
+///     /// Accepts `retry(attempts = 3, backoff = "exponential")`.
+///     retry: SerdeValue<Retry>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SerdeValue<T>(pub T);
+
+impl<T> SerdeValue<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    fn deserialize(value: Value) -> Result<Self>
+    where
+        T: DeserializeOwned,
+    {
+        serde_json::from_value(value)
+            .map(SerdeValue)
+            .map_err(Error::custom)
+    }
+}
+
+impl<T> Deref for SerdeValue<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for SerdeValue<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: DeserializeOwned> FromMeta for SerdeValue<T> {
+    fn from_word() -> Result<Self> {
+        Self::deserialize(Value::Null)
+    }
+
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        Self::deserialize(list_to_json(items)?)
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        Self::deserialize(expr_to_json(expr)?).map_err(|e| e.with_span(expr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use serde_json::{json, Value};
+    use syn::parse_quote;
+
+    use super::SerdeValue;
+    use crate::test_util::parse_meta;
+    use crate::FromMeta;
+
+    #[test]
+    fn objects() {
+        assert_eq!(
+            parse_meta::<SerdeValue<Value>>(quote!(ignore(
+                a = "x",
+                b = -2,
+                c(d = 1.5),
+                e,
+                f = [1, 2]
+            )))
+            .unwrap()
+            .into_inner(),
+            json!({ "a": "x", "b": -2, "c": { "d": 1.5 }, "e": true, "f": [1, 2] })
+        );
+        assert_eq!(
+            parse_meta::<SerdeValue<Value>>(quote!(ignore()))
+                .unwrap()
+                .into_inner(),
+            json!({})
+        );
+    }
+
+    #[test]
+    fn arrays_and_scalars() {
+        assert_eq!(
+            parse_meta::<SerdeValue<Value>>(quote!(ignore("a", 'b', false)))
+                .unwrap()
+                .into_inner(),
+            json!(["a", "b", false])
+        );
+        assert_eq!(
+            parse_meta::<SerdeValue<Value>>(quote!(ignore = 7))
+                .unwrap()
+                .into_inner(),
+            json!(7)
+        );
+        assert_eq!(
+            parse_meta::<SerdeValue<Value>>(quote!(ignore))
+                .unwrap()
+                .into_inner(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err =
+            parse_meta::<SerdeValue<Value>>(quote!(ignore(a = 1, a = 2, "b", c = x))).unwrap_err();
+        assert_eq!(err.len(), 3);
+    }
+
+    #[test]
+    fn deserialize_errors() {
+        let err = SerdeValue::<u8>::from_meta(&parse_quote!(ignore = "x")).unwrap_err();
+        assert!(err.to_string().starts_with("invalid type"));
+    }
+}
@@ -1,10 +1,11 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
 use std::ops::{Deref, DerefMut};
 use syn::spanned::Spanned;
 
 use crate::{
     FromDeriveInput, FromField, FromGenericParam, FromGenerics, FromMeta, FromTypeParam,
-    FromVariant, Result,
+    FromVariant, Result, ToMetaItem,
 };
 
 /// A value and an associated position in source code. The main use case for this is
@@ -99,6 +100,20 @@ impl<T: FromMeta> FromMeta for SpannedValue<T> {
     }
 }
 
+/// Emits the value. The span is only used for diagnostics, so the value's own tokens keep
+/// their spans.
+impl<T: ToTokens> ToTokens for SpannedValue<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.value.to_tokens(tokens);
+    }
+}
+
+impl<T: ToMetaItem> ToMetaItem for SpannedValue<T> {
+    fn to_meta_item(&self, path: &syn::Path) -> Option<syn::Meta> {
+        self.value.to_meta_item(path)
+    }
+}
+
 spanned!(FromGenericParam, from_generic_param, syn::GenericParam);
 spanned!(FromGenerics, from_generics, syn::Generics);
 spanned!(FromTypeParam, from_type_param, syn::TypeParam);
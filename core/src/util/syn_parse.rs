@@ -0,0 +1,113 @@
+use std::ops::{Deref, DerefMut};
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::parse::Parse;
+use syn::{Expr, ExprLit, Lit};
+
+use crate::{Error, FromMeta, Result};
+
+/// A value parsed with its `syn::parse::Parse` impl, so syntax types that darling doesn't
+/// support yet can be used as fields.
+///
+/// A string literal is parsed from its contents, like `syn::LitStr::parse`, so the spans
+/// of the parsed tokens point into the string. Any other expression, such as the unquoted
+/// `init = make(1, 2)`, is parsed from its own tokens. Parse errors point at the offending tokens.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Options {
+///     /// Accepts `receiver = "&mut self"`.
+///     receiver: SynParse<syn::Receiver>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SynParse<T>(pub T);
+
+impl<T> SynParse<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for SynParse<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for SynParse<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: ToTokens> ToTokens for SynParse<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl<T: Parse> FromMeta for SynParse<T> {
+    fn from_string(value: &str) -> Result<Self> {
+        syn::parse_str(value).map(SynParse).map_err(Error::from)
+    }
+
+    fn from_value(value: &Lit) -> Result<Self> {
+        match value {
+            Lit::Str(s) => s.parse().map(SynParse).map_err(Error::from),
+            _ => Err(Error::unexpected_lit_type(value)),
+        }
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Lit(ExprLit { lit, .. }) => Self::from_value(lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => syn::parse2(expr.to_token_stream())
+                .map(SynParse)
+                .map_err(Error::from),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::parse_quote;
+
+    use super::SynParse;
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn string_contents() {
+        let receiver = parse_meta::<SynParse<syn::Receiver>>(quote!(ignore = "&mut self"))
+            .unwrap()
+            .into_inner();
+        assert_eq!(receiver, parse_quote!(&mut self));
+
+        let bound = parse_meta::<SynParse<syn::WherePredicate>>(quote!(ignore = "T: Clone"))
+            .unwrap()
+            .into_inner();
+        assert_eq!(bound, parse_quote!(T: Clone));
+    }
+
+    #[test]
+    fn unquoted_tokens() {
+        let call = parse_meta::<SynParse<syn::ExprCall>>(quote!(ignore = make(1, 2)))
+            .unwrap()
+            .into_inner();
+        assert_eq!(quote!(#call).to_string(), quote!(make(1, 2)).to_string());
+    }
+
+    #[test]
+    fn errors() {
+        parse_meta::<SynParse<syn::Receiver>>(quote!(ignore = "not a receiver")).unwrap_err();
+        parse_meta::<SynParse<syn::Receiver>>(quote!(ignore = 5)).unwrap_err();
+        parse_meta::<SynParse<syn::Receiver>>(quote!(ignore)).unwrap_err();
+    }
+}
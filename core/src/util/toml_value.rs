@@ -0,0 +1,175 @@
+use syn::{Expr, ExprLit, Lit, Meta};
+use toml::value::{Array, Table};
+use toml::Value;
+
+use crate::ast::NestedMeta;
+use crate::util::{path_to_string, Number};
+use crate::{Error, FromMeta, Result};
+
+/// Captures the meta item as dynamic TOML data. This requires the `toml` feature.
+///
+/// A list of named items, such as `package(name = "x", edition = 2018, publish)`, is a table,
+/// in which a bare word such as `publish` is `true`. A list of literals or an array, such as
+/// `authors("a", "b")` or `authors = ["a", "b"]`, is an array. Strings, numbers, and booleans
+/// are read as written. A string value of the field itself is parsed as a TOML document
+/// instead, so `package = "name = 'x'"` is a table.
+impl FromMeta for Value {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        list_to_toml(items)
+    }
+
+    fn from_string(value: &str) -> Result<Self> {
+        toml::from_str::<Table>(value)
+            .map(Value::Table)
+            .map_err(|e| Error::custom(format!("Invalid TOML: {}", e)))
+    }
+
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Self::from_string(&s.value()),
+            _ => expr_to_toml(expr),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+/// Convert a list of items to an array if they're all literals, or a table otherwise.
+fn list_to_toml(items: &[NestedMeta]) -> Result<Value> {
+    let mut errors = Error::accumulator();
+
+    if !items.is_empty() && items.iter().all(|item| matches!(item, NestedMeta::Lit(_))) {
+        let elems: Array = items
+            .iter()
+            .filter_map(|item| match item {
+                NestedMeta::Lit(lit) => errors.handle(lit_to_toml(lit)),
+                NestedMeta::Meta(_) => None,
+            })
+            .collect();
+        return errors.finish_with(Value::Array(elems));
+    }
+
+    let mut table = Table::new();
+    for item in items {
+        let meta = match item {
+            NestedMeta::Meta(meta) => meta,
+            NestedMeta::Lit(lit) => {
+                errors.push(Error::unsupported_format("literal").with_span(lit));
+                continue;
+            }
+        };
+
+        let name = path_to_string(meta.path());
+        if table.contains_key(&name) {
+            errors.push(Error::duplicate_field(&name).with_span(meta));
+            continue;
+        }
+
+        let value = match meta {
+            Meta::Path(_) => Ok(Value::Boolean(true)),
+            Meta::NameValue(nv) => expr_to_toml(&nv.value),
+            Meta::List(list) => NestedMeta::parse_meta_list(list.tokens.clone())
+                .map_err(Error::from)
+                .and_then(|items| list_to_toml(&items)),
+        };
+
+        if let Some(value) = errors.handle(value.map_err(|e| e.at(&name))) {
+            table.insert(name, value);
+        }
+    }
+
+    errors.finish_with(Value::Table(table))
+}
+
+fn expr_to_toml(expr: &Expr) -> Result<Value> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => lit_to_toml(lit),
+        Expr::Group(group) => expr_to_toml(&group.expr),
+        Expr::Unary(_) => number_to_toml(&Number::from_expr(expr)?),
+        Expr::Array(array) => {
+            let mut errors = Error::accumulator();
+            let elems = array
+                .elems
+                .iter()
+                .filter_map(|elem| errors.handle(expr_to_toml(elem)))
+                .collect();
+            errors.finish_with(Value::Array(elems))
+        }
+        _ => Err(Error::unexpected_expr_type(expr)),
+    }
+}
+
+fn lit_to_toml(lit: &Lit) -> Result<Value> {
+    match lit {
+        Lit::Str(s) => Ok(Value::String(s.value())),
+        Lit::Char(c) => Ok(Value::String(c.value().to_string())),
+        Lit::Bool(b) => Ok(Value::Boolean(b.value)),
+        Lit::Int(_) | Lit::Float(_) => number_to_toml(&Number::from_value(lit)?),
+        _ => Err(Error::unexpected_lit_type(lit)),
+    }
+}
+
+fn number_to_toml(number: &Number) -> Result<Value> {
+    if number.is_float() {
+        number.as_f64().map(Value::Float)
+    } else {
+        number.as_i64().map(Value::Integer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use toml::Value;
+
+    use crate::test_util::parse_meta;
+
+    #[test]
+    fn nested_meta() {
+        let expected: Value = toml::from_str(
+            r#"
+            name = "x"
+            edition = 2018
+            publish = true
+            authors = ["a", "b"]
+            ratio = -0.5
+
+            [features]
+            default = ["std"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            parse_meta::<Value>(quote!(ignore(
+                name = "x",
+                edition = 2018,
+                publish,
+                authors("a", "b"),
+                ratio = -0.5,
+                features(default = ["std"])
+            )))
+            .unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn toml_strings() {
+        let value = parse_meta::<Value>(quote!(ignore = "name = 'x'\nedition = 2018")).unwrap();
+        assert_eq!(value["name"].as_str(), Some("x"));
+        assert_eq!(value["edition"].as_integer(), Some(2018));
+
+        let err = parse_meta::<Value>(quote!(ignore = "name = ")).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid TOML"));
+    }
+
+    #[test]
+    fn errors() {
+        parse_meta::<Value>(quote!(ignore)).unwrap_err();
+        parse_meta::<Value>(quote!(ignore = 18446744073709551615)).unwrap_err();
+        let err = parse_meta::<Value>(quote!(ignore(a = 1, a = 2, b = x))).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+
+/// Non-fatal diagnostics collected while parsing, such as uses of a deprecated alias.
+///
+/// Stable Rust doesn't let proc macros emit warnings directly, so when converted to tokens
+/// each warning becomes a use of a `#[deprecated]` item. The compiler then reports it as a
+/// deprecation warning pointing at the offending input. Include the tokens in the macro's
+/// output to surface the warnings to the macro's user.
+///
+/// Files and environment variables read while parsing, such as those named by
+/// `#[darling(from_file = "...")]` or by placeholders in `#[darling(expand_env)]` fields, are
+/// also recorded here. Each file becomes an `include_bytes!` and each variable an `option_env!`,
+/// so cargo rebuilds the macro's user when they change.
+///
+/// # Usage
+/// Add a `#[darling(warnings)]` field of this type to a derived receiver to collect warnings
+/// from that receiver's fields. Receivers nested in other receivers have their own warnings,
+/// which can be combined using `extend`.
+///
+/// ```rust,ignore
+/// #[derive(FromDeriveInput)]
+/// #[darling(attributes(my))]
+/// struct Receiver {
+///     #[darling(deprecated_alias = "colour")]
+///     color: String,
+///     #[darling(warnings)]
+///     warnings: Warnings,
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Warnings {
+    warnings: Vec<(Span, String)>,
+    files: Vec<PathBuf>,
+    env_vars: Vec<String>,
+}
+
+impl Warnings {
+    /// Record a warning pointing at `node`.
+    pub fn push<S: Spanned, T: fmt::Display>(&mut self, node: &S, message: T) {
+        self.warnings.push((node.span(), message.to_string()));
+    }
+
+    /// Record that the input depends on the file at `path`, which should be absolute.
+    pub fn track_file<P: AsRef<Path>>(&mut self, path: P) {
+        let path = path.as_ref();
+        if !self.files.iter().any(|file| file == path) {
+            self.files.push(path.to_path_buf());
+        }
+    }
+
+    /// The files which have been recorded with [`track_file`](Self::track_file).
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Record that the input depends on the environment variable `name`, whether or not it's set.
+    pub fn track_env<T: Into<String>>(&mut self, name: T) {
+        let name = name.into();
+        if !self.env_vars.contains(&name) {
+            self.env_vars.push(name);
+        }
+    }
+
+    /// The environment variables which have been recorded with [`track_env`](Self::track_env).
+    pub fn env_vars(&self) -> &[String] {
+        &self.env_vars
+    }
+
+    /// Record a warning that a deprecated name was used in place of its replacement.
+    pub fn deprecated_alias<S: Spanned>(&mut self, node: &S, alias: &str, replacement: &str) {
+        self.push(
+            node,
+            format!("`{}` is deprecated; use `{}` instead", alias, replacement),
+        );
+    }
+
+    /// Returns `true` if no warnings have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Get the number of recorded warnings.
+    pub fn len(&self) -> usize {
+        self.warnings.len()
+    }
+
+    /// Iterate over the location and message of each warning.
+    pub fn iter(&self) -> impl Iterator<Item = (Span, &str)> {
+        self.warnings
+            .iter()
+            .map(|(span, message)| (*span, message.as_str()))
+    }
+}
+
+impl Extend<Warnings> for Warnings {
+    fn extend<I: IntoIterator<Item = Warnings>>(&mut self, iter: I) {
+        for warnings in iter {
+            self.warnings.extend(warnings.warnings);
+            for file in warnings.files {
+                self.track_file(file);
+            }
+            for name in warnings.env_vars {
+                self.track_env(name);
+            }
+        }
+    }
+}
+
+impl ToTokens for Warnings {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for (span, message) in &self.warnings {
+            // Only the use of a deprecated item is reported, and it carries the span of the
+            // offending input so that's where the warning points.
+            tokens.extend(quote_spanned! {*span=>
+                const _: () = {
+                    #[deprecated(note = #message)]
+                    #[allow(non_upper_case_globals)]
+                    const darling_warning: () = ();
+                    darling_warning
+                };
+            });
+        }
+
+        for file in &self.files {
+            let file = file.to_string_lossy();
+            tokens.extend(quote! {
+                const _: &[u8] = include_bytes!(#file);
+            });
+        }
+
+        for name in &self.env_vars {
+            tokens.extend(quote! {
+                const _: ::core::option::Option<&str> = option_env!(#name);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+    use quote::ToTokens;
+
+    use super::Warnings;
+
+    #[test]
+    fn collect_and_emit() {
+        let mut warnings = Warnings::default();
+        assert!(warnings.is_empty());
+        assert!(warnings.to_token_stream().is_empty());
+
+        let ident = syn::Ident::new("colour", Span::call_site());
+        warnings.deprecated_alias(&ident, "colour", "color");
+
+        let mut nested = Warnings::default();
+        nested.push(&ident, "Something else");
+        warnings.extend(Some(nested));
+
+        assert_eq!(
+            warnings.iter().map(|(_, msg)| msg).collect::<Vec<_>>(),
+            vec![
+                "`colour` is deprecated; use `color` instead",
+                "Something else"
+            ]
+        );
+
+        let tokens = warnings.to_token_stream().to_string();
+        assert!(tokens.contains("deprecated"));
+    }
+
+    #[test]
+    fn track_files() {
+        let mut warnings = Warnings::default();
+        warnings.track_file("/crate/options.toml");
+
+        let mut nested = Warnings::default();
+        nested.track_file("/crate/options.toml");
+        nested.track_file("/crate/other.json");
+        warnings.extend(Some(nested));
+
+        assert!(warnings.is_empty());
+        assert_eq!(warnings.files().len(), 2);
+        assert_eq!(
+            warnings.to_token_stream().to_string(),
+            quote::quote! {
+                const _: &[u8] = include_bytes!("/crate/options.toml");
+                const _: &[u8] = include_bytes!("/crate/other.json");
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn track_env_vars() {
+        let mut warnings = Warnings::default();
+        warnings.track_env("HOME");
+
+        let mut nested = Warnings::default();
+        nested.track_env("HOME");
+        nested.track_env("UNSET");
+        warnings.extend(Some(nested));
+
+        assert!(warnings.is_empty());
+        assert_eq!(warnings.env_vars(), ["HOME", "UNSET"]);
+        assert_eq!(
+            warnings.to_token_stream().to_string(),
+            quote::quote! {
+                const _: ::core::option::Option<&str> = option_env!("HOME");
+                const _: ::core::option::Option<&str> = option_env!("UNSET");
+            }
+            .to_string()
+        );
+    }
+}
@@ -1,3 +1,6 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
 use crate::{
     FromDeriveInput, FromField, FromGenericParam, FromGenerics, FromMeta, FromTypeParam,
     FromVariant, Result,
@@ -16,6 +19,13 @@ impl<T, O> WithOriginal<T, O> {
     }
 }
 
+/// Emits the parsed value.
+impl<T: ToTokens, O> ToTokens for WithOriginal<T, O> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.parsed.to_tokens(tokens);
+    }
+}
+
 macro_rules! with_original {
     ($trayt:ident, $func:ident, $syn:path) => {
         impl<T: $trayt> $trayt for WithOriginal<T, $syn> {
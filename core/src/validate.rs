@@ -0,0 +1,32 @@
+use crate::Result;
+
+/// Check invariants of a receiver once it has been parsed.
+///
+/// Deriving types opt in with `#[darling(validate)]`, after which the generated impl
+/// calls `validate` on every successfully-parsed instance, after any container-level
+/// `map` or `and_then`. This gives rules which involve several fields a typed home that
+/// can be shared by every receiver that needs them.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// #[darling(validate)]
+/// struct Retry {
+///     min_delay_ms: u64,
+///     max_delay_ms: u64,
+/// }
+///
+/// impl Validate for Retry {
+///     fn validate(&self) -> Result<()> {
+///         if self.min_delay_ms > self.max_delay_ms {
+///             return Err(Error::custom("`min_delay_ms` must not exceed `max_delay_ms`"));
+///         }
+///
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Validate {
+    /// Check that `self` is a valid instance, returning every problem found.
+    fn validate(&self) -> Result<()>;
+}
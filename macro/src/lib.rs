@@ -7,6 +7,11 @@ pub fn derive_from_meta(input: TokenStream) -> TokenStream {
     derive::from_meta(&parse_macro_input!(input)).into()
 }
 
+#[proc_macro_derive(ToMetaItem, attributes(darling))]
+pub fn derive_to_meta_item(input: TokenStream) -> TokenStream {
+    derive::to_meta_item(&parse_macro_input!(input)).into()
+}
+
 #[proc_macro_derive(FromMetaItem, attributes(darling))]
 pub fn derive_from_meta_item(_input: TokenStream) -> TokenStream {
     Error::custom("darling::FromMetaItem has been replaced by darling::FromMeta")
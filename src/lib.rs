@@ -11,17 +11,103 @@
 //! Proc-macro crates should provide their own structs which implement or derive `FromDeriveInput`,
 //! `FromField`, `FromVariant`, `FromGenerics`, _et alia_ to gather settings relevant to their operation.
 //!
+//! The reverse direction is `ToMetaItem`, which renders a value back into attribute tokens. Deriving it
+//! alongside `FromMeta` enables round-trip tests and copying parsed options onto generated items.
+//!
+//! The traits, `Error`, `Result`, and the most common `ast` and `util` types can all be imported with
+//! `use darling::prelude::*`.
+//!
 //! ## Attributes
 //! There are a number of attributes that `darling` exposes to enable finer-grained control over the code
 //! it generates.
 //!
 //! * **Field renaming**: You can use `#[darling(rename="new_name")]` on a field to change the name Darling looks for.
 //!   You can also use `#[darling(rename_all="...")]` at the struct or enum level to apply a casing rule to all fields or variants.
+//! * **Field aliases**: You can use `#[darling(alias = "old_name")]` one or more times on a field to accept other names
+//!   for it. Supplying the same field under two of its names is a duplicate-field error.
+//!   Use `#[darling(deprecated_alias = "old_name")]` instead to also record a warning in the struct's
+//!   `#[darling(warnings)]` field of type [`util::Warnings`], which the macro can include in its output.
+//! * **Multiple attribute names**: `#[darling(attributes(a, b))]` reads fields from every listed attribute, including
+//!   namespaced ones like `my_crate::options`. Fields are merged as though they'd been written in one attribute.
+//! * **`cfg_attr` unwrapping**: Add `#[darling(unwrap_cfg_attr)]` next to `attributes(...)` to also read those attributes
+//!   from inside `#[cfg_attr(predicate, ...)]`. A derive can't evaluate the predicate, so the options are read whether or
+//!   not it holds; add a `cfg_predicates: Vec<syn::Meta>` field to receive the predicates of the `cfg_attr`s options were
+//!   read from, and make the generated code conditional on them.
+//! * **Case-insensitive names**: You can use `#[darling(case_insensitive)]` at the struct or enum level to match
+//!   field names, and an enum's variant names, regardless of case. Error messages still use the canonical names.
+//! * **Custom parsing**: You can use `#[darling(with = "path::to::function")]` or `#[darling(with = |meta| ...)]` on a field
+//!   to parse it with a `fn(&syn::Meta) -> darling::Result<T>` instead of the field type's `FromMeta` impl.
 //! * **Map function**: You can use `#[darling(map="path::to::function")]` to run code on a field before its stored in the struct.
+//!   Inline closures such as `#[darling(map = |v: String| v.to_lowercase())]` are also accepted.
+//! * **Validation function**: You can use `#[darling(and_then="path::to::function")]` to run a fallible function on a field
+//!   after it's parsed. Errors it returns are attached to the field's location in the input.
+//! * **Custom bounds**: By default, generated impls require each type parameter used by a parsed field to implement the
+//!   trait being derived, and the type of each field that falls back to `Default::default()` to implement `Default`.
+//!   Use `#[darling(bound = "T: MyTrait")]` on the container to replace those bounds, or
+//!   `#[darling(extend_bound = "T: Default")]` to add to them.
+//! * **Validate trait**: You can use `#[darling(validate)]` on a struct or enum to have the generated impl call
+//!   `darling::Validate::validate` on each parsed instance, after any container-level `map` or `and_then`.
 //! * **Default values**: You can use `#[darling(default)]` at the type or field level to use that type's default value to fill
 //!   in values not specified by the caller.
+//! * **Field constraints**: You can use `#[darling(requires = "other")]` or `#[darling(conflicts_with = "other")]` on a
+//!   field, naming another field by its identifier, to reject input that sets this field without, or together with, the other.
+//! * **Exclusive groups**: You can use `#[darling(exclusive(json, yaml, toml))]` on a struct to allow at most one of
+//!   those fields to be set. The error names every field of the group the caller supplied.
+//!   Similarly, `#[darling(require_any(path, inline))]` requires at least one of the fields to be set; combine the two
+//!   to require exactly one.
+//! * **Numeric ranges**: You can use `#[darling(range(min = 1, max = 64))]` on a numeric field to reject values outside
+//!   those inclusive bounds. Either bound may be omitted, integer bounds also work for float fields, and the error
+//!   points at the out-of-range value.
+//! * **Permitted values**: You can use `#[darling(one_of("gzip", "zstd", "none"))]` on a `String` or `Ident` field to
+//!   reject any other value, with an error that lists the permitted ones. Like `range`, this checks the value as parsed,
+//!   before any `map` or `and_then`.
+//! * **Pattern matching**: With the `regex` feature enabled, you can use `#[darling(matches = "^[a-z_]+$")]` on a `String`
+//!   field to reject values that don't match the regular expression. The pattern is checked when the receiver is derived.
+//! * **Environment variables**: You can use `#[darling(expand_env)]` on a field to replace `${VAR}` placeholders in its
+//!   string values with environment variables when the macro is expanded. Write `$$` for a literal `$`; an unset variable is an error.
+//!   Give the struct a `#[darling(warnings)]` field and emit it to make cargo rebuild when the variables change.
+//! * **Options from a file**: You can use `#[darling(from_file = "config")]` on a struct so that callers can write
+//!   `config = "settings.toml"` to read options from a file relative to their crate. Options written alongside `config` override
+//!   the file's. `.toml` files require the `toml` feature and `.json` files require the `json` feature. A proc-macro can't
+//!   tell cargo about the file itself on stable Rust, so give the struct a `#[darling(warnings)]` field and emit it: the
+//!   warnings record the file, and emitting them makes cargo rebuild when it changes.
+//! * **Field reflection**: You can use `#[darling(reflect)]` on a struct to have the derive emit an associated constant,
+//!   `FIELDS: &[util::FieldInfo]`, which lists each field's name, aliases, type, default, and doc comments, and whether
+//!   it's required. [`util::fields_markdown`] renders the list as a Markdown table for a macro crate's documentation,
+//!   and with the `json` feature, `util::fields_json_schema` describes it as a JSON Schema for editor plugins and linters.
+//! * **Runtime parsing**: Tools which only know their attribute grammar at runtime can declare fields with
+//!   [`util::MetaParser::builder`] and parse meta items into a [`util::MetaMap`] of dynamic values, without a receiver struct.
+//! * **Closure-driven parsing**: For attributes too small to need a receiver struct, [`util::parse_nested_meta`] calls a
+//!   closure with each item of a list, along with helpers to read its value and build errors spanned to it.
+//! * **Missing-field messages**: You can use `#[darling(missing = "...")]` on a required field to replace the standard
+//!   "missing field" error with a message explaining why the field is needed.
+//! * **Multiple-occurrence fields**: You can use `#[darling(multiple)]` on a `Vec<T>` field to accept the same key
+//!   more than once, whether in one attribute or across several. Each occurrence is parsed as `T` and pushed in order.
 //! * **Skipped fields**: You can skip a variant or field using `#[darling(skip)]`. Fields marked with this will fall back to
 //!   `Default::default()` for their value, but you can override that with an explicit default or a value from the type-level default.
+//! * **Raw fields**: You can use `#[darling(raw)]` on a `syn::Meta`, `Vec<NestedMeta>`, or `TokenStream` field to receive
+//!   its item unparsed, through [`util::FromRawMeta`], so a sub-attribute can be forwarded verbatim to another library's parser.
+//! * **Flattened fields**: You can use `#[darling(flatten)]` on one field whose type implements `FromMeta` to parse it
+//!   from every item the other fields don't claim, so a group of options can be shared between receivers.
+//! * **Catch-all fields**: You can use `#[darling(rest)]` on one field, such as a `Vec<syn::Meta>` or a
+//!   `HashMap<String, syn::Meta>`, to collect every item the other fields don't claim instead of reporting it as unknown.
+//! * **Lossy parsing**: You can use `#[darling(lossy)]` on a `FromDeriveInput` receiver to have
+//!   `from_derive_input_lossy` build it despite errors, filling fields which are missing or invalid with their defaults,
+//!   so a macro can emit stub output that keeps IDE analysis working while it reports the errors.
+//! * **Inherited settings**: You can use `#[darling(inherit)]` on a `FromDeriveInput` receiver to have its field and
+//!   variant receivers consult the parsed container through [`ast::Inherit`].
+//!
+//! ## Repeated attributes
+//! When an item carries the same attribute more than once, such as `#[my(a)] #[my(b = "x")]`, or several
+//! of the names listed in `attributes(...)`, every occurrence is parsed into the same receiver:
+//!
+//! * A field that isn't `multiple` may be set once; a second value is a "duplicate field" error spanned to the
+//!   repeated key, and the first value is kept so that other errors can still be reported.
+//! * A `multiple` field appends each occurrence in source order.
+//! * A `flatten` or `rest` field sees the unclaimed items from all occurrences, in source order.
+//!
+//! This is configured per field with `multiple`. A receiver that wants the last value to win can make the
+//! field `multiple` and read the final element.
 //!
 //! ## Forwarded Fields
 //! All derivable traits except `FromMeta` support forwarding some fields from the input AST to the derived struct.
@@ -39,6 +125,8 @@
 //! |`generics`|`T: darling::FromGenerics`|The generics of the passed-in type. This can be `syn::Generics`, `darling::ast::Generics`, or any compatible type.|
 //! |`data`|`darling::ast::Data`|The body of the passed-in type|
 //! |`attrs`|`Vec<syn::Attribute>`|The forwarded attributes from the passed in type. These are controlled using the `forward_attrs` attribute.|
+//! |`docs`|`Vec<String>`, `String`, or `Option<String>`|The doc comments of the passed in type, one line per element or joined with newlines. Only recognized with `#[darling(docs)]`.|
+//! |`cfg_predicates`|`Vec<syn::Meta>`|The predicates of the `cfg_attr`s options were read from. Only recognized with `unwrap_cfg_attr`.|
 //!
 //! ### `FromField`
 //! |Field name|Type|Meaning|
@@ -47,6 +135,8 @@
 //! |`vis`|`syn::Visibility`|The visibility of the passed-in field|
 //! |`ty`|`syn::Type`|The type of the passed-in field|
 //! |`attrs`|`Vec<syn::Attribute>`|The forwarded attributes from the passed in field. These are controlled using the `forward_attrs` attribute.|
+//! |`docs`|`Vec<String>`, `String`, or `Option<String>`|The doc comments of the passed in field, one line per element or joined with newlines. Only recognized with `#[darling(docs)]`.|
+//! |`cfg_predicates`|`Vec<syn::Meta>`|The predicates of the `cfg_attr`s options were read from. Only recognized with `unwrap_cfg_attr`.|
 //!
 //! ### `FromTypeParam`
 //! |Field name|Type|Meaning|
@@ -55,6 +145,8 @@
 //! |`bounds`|`Vec<syn::TypeParamBound>`|The bounds applied to the type param|
 //! |`default`|`Option<syn::Type>`|The default type of the parameter, if one exists|
 //! |`attrs`|`Vec<syn::Attribute>`|The forwarded attributes from the passed in type param. These are controlled using the `forward_attrs` attribute.|
+//! |`docs`|`Vec<String>`, `String`, or `Option<String>`|The doc comments of the passed in type param, one line per element or joined with newlines. Only recognized with `#[darling(docs)]`.|
+//! |`cfg_predicates`|`Vec<syn::Meta>`|The predicates of the `cfg_attr`s options were read from. Only recognized with `unwrap_cfg_attr`.|
 //!
 //! ### `FromVariant`
 //! |Field name|Type|Meaning|
@@ -63,6 +155,8 @@
 //! |`discriminant`|`Option<syn::Expr>`|For a variant such as `Example = 2`, the `2`|
 //! |`fields`|`darling::ast::Fields<T> where T: FromField`|The fields associated with the variant|
 //! |`attrs`|`Vec<syn::Attribute>`|The forwarded attributes from the passed in variant. These are controlled using the `forward_attrs` attribute.|
+//! |`docs`|`Vec<String>`, `String`, or `Option<String>`|The doc comments of the passed in variant, one line per element or joined with newlines. Only recognized with `#[darling(docs)]`.|
+//! |`cfg_predicates`|`Vec<syn::Meta>`|The predicates of the `cfg_attr`s options were read from. Only recognized with `unwrap_cfg_attr`.|
 
 extern crate core;
 
@@ -76,7 +170,7 @@ pub use darling_macro::*;
 #[doc(inline)]
 pub use darling_core::{
     FromAttributes, FromDeriveInput, FromField, FromGenericParam, FromGenerics, FromMeta,
-    FromTypeParam, FromVariant,
+    FromTypeParam, FromVariant, ToMetaItem, Validate,
 };
 
 #[doc(inline)]
@@ -95,8 +189,10 @@ pub use darling_core::ToTokens;
 /// of the referenced types.
 #[doc(hidden)]
 pub mod export {
-    pub use core::convert::From;
+    pub use core::cmp::{PartialEq, PartialOrd};
+    pub use core::convert::{identity, AsRef, From};
     pub use core::default::Default;
+    pub use core::mem;
     pub use core::option::Option::{self, None, Some};
     pub use core::result::Result::{self, Err, Ok};
     pub use darling_core::syn;
@@ -104,6 +200,25 @@ pub mod export {
     pub use std::vec::Vec;
 
     pub use crate::ast::NestedMeta;
+
+    #[cfg(feature = "regex")]
+    pub use darling_core::regex;
+    #[cfg(feature = "regex")]
+    pub use std::thread_local;
+}
+
+/// The traits, derives, and types most proc-macro crates need, for importing with
+/// `use darling::prelude::*`.
+pub mod prelude {
+    pub use crate::{
+        FromAttributes, FromDeriveInput, FromField, FromGenericParam, FromGenerics, FromMeta,
+        FromTypeParam, FromVariant, ToMetaItem, Validate,
+    };
+
+    pub use crate::{Error, Result};
+
+    pub use crate::ast::{Data, Fields, NestedMeta};
+    pub use crate::util::{Flag, IdentString, Override, PathList, SpannedValue};
 }
 
 #[macro_use]
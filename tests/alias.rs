@@ -0,0 +1,81 @@
+//! Test that `#[darling(alias = "...")]` accepts alternate names for a field,
+//! and that using more than one name for the same field is reported as a duplicate.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    #[darling(alias = "colour")]
+    color: String,
+    #[darling(rename = "max_size", alias = "size", alias = "limit", default)]
+    size: u32,
+    #[darling(multiple, alias = "tags")]
+    tag: Vec<String>,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(rename_all = "camelCase")]
+struct Renamed {
+    #[darling(alias = "old_name")]
+    new_name: String,
+}
+
+#[test]
+fn canonical_and_alias() {
+    let di = parse_quote! {
+        #[demo(colour = "red", limit = 4, tag = "a", tags = "b")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.color, "red");
+    assert_eq!(receiver.size, 4);
+    assert_eq!(receiver.tag, vec!["a".to_string(), "b".to_string()]);
+
+    let di = parse_quote! {
+        #[demo(color = "blue", max_size = 2)]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.color, "blue");
+    assert_eq!(receiver.size, 2);
+}
+
+#[test]
+fn canonical_and_alias_conflict() {
+    let di = parse_quote! {
+        #[demo(color = "red", colour = "blue")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `color`");
+}
+
+#[test]
+fn alias_is_not_renamed() {
+    let attr: syn::Attribute = parse_quote!(#[ignore(old_name = "x")]);
+    assert_eq!(Renamed::from_meta(&attr.meta).unwrap().new_name, "x");
+
+    let attr: syn::Attribute = parse_quote!(#[ignore(newName = "y")]);
+    assert_eq!(Renamed::from_meta(&attr.meta).unwrap().new_name, "y");
+}
+
+#[cfg(feature = "suggestions")]
+#[test]
+fn suggests_aliases() {
+    let di = parse_quote! {
+        #[demo(colur = "red")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert!(
+        err.to_string().contains("Did you mean `colour`?"),
+        "{}",
+        err
+    );
+}
@@ -0,0 +1,136 @@
+//! Test that `#[darling(and_then = ...)]` runs fallible validation on a parsed value,
+//! and that the errors it returns are spanned, located, and accumulated.
+
+use darling::{Error, FromDeriveInput, FromMeta, Result};
+use syn::parse_quote;
+
+fn percentage(value: u8) -> Result<u8> {
+    if value <= 100 {
+        Ok(value)
+    } else {
+        Err(Error::custom(format!(
+            "{} is not a valid percentage",
+            value
+        )))
+    }
+}
+
+fn non_empty(value: String) -> Result<String> {
+    if value.is_empty() {
+        Err(Error::custom("Value must not be empty"))
+    } else {
+        Ok(value)
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    #[darling(and_then = percentage)]
+    opacity: u8,
+    #[darling(default, and_then = "percentage")]
+    saturation: u8,
+    #[darling(multiple, and_then = non_empty)]
+    label: Vec<String>,
+}
+
+#[test]
+fn valid_values() {
+    let di = parse_quote! {
+        #[demo(opacity = 50, label = "a", label = "b")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.opacity, 50);
+    assert_eq!(receiver.saturation, 0);
+    assert_eq!(receiver.label, vec!["a".to_string(), "b".to_string()]);
+}
+
+#[test]
+fn errors_are_spanned_and_located() {
+    let di = parse_quote! {
+        #[demo(opacity = 150)]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert!(err.has_span());
+    assert_eq!(err.to_string(), "150 is not a valid percentage at opacity");
+}
+
+#[test]
+fn errors_accumulate() {
+    let di = parse_quote! {
+        #[demo(opacity = 101, saturation = 102, label = "ok", label = "")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 3);
+
+    let errors = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert!(errors.contains(&"Value must not be empty at label[1]".to_string()));
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(and_then = Self::not_legacy)]
+enum Mode {
+    Fast,
+    Legacy,
+    Tuned { level: u8 },
+}
+
+impl Mode {
+    fn not_legacy(self) -> Result<Self> {
+        if self == Mode::Legacy {
+            Err(Error::custom("Legacy mode is no longer supported"))
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(and_then = Self::positive)]
+struct Weight(i32);
+
+impl Weight {
+    fn positive(self) -> Result<Self> {
+        if self.0 > 0 {
+            Ok(self)
+        } else {
+            Err(Error::custom("Weight must be positive"))
+        }
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct ContainerReceiver {
+    mode: Mode,
+    #[darling(default)]
+    weight: Option<Weight>,
+}
+
+/// Container-level `and_then` should apply to every shape of `FromMeta` receiver,
+/// not only to structs with named fields.
+#[test]
+fn container_and_then_on_enum_and_newtype() {
+    let di = parse_quote! {
+        #[demo(mode = "fast", weight = 3)]
+        struct Example;
+    };
+
+    let receiver = ContainerReceiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.mode, Mode::Fast);
+    assert_eq!(receiver.weight, Some(Weight(3)));
+
+    let di = parse_quote! {
+        #[demo(mode = "legacy", weight = 0)]
+        struct Example;
+    };
+
+    let err = ContainerReceiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 2);
+}
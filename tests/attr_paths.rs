@@ -0,0 +1,45 @@
+//! Test that `attributes(...)` and `forward_attrs(...)` match multi-segment paths,
+//! such as namespaced helper attributes.
+
+use darling::FromDeriveInput;
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(myfw::options), forward_attrs(myfw::docs, doc))]
+struct Receiver {
+    name: String,
+    attrs: Vec<syn::Attribute>,
+}
+
+#[test]
+fn namespaced_attributes() {
+    let di = parse_quote! {
+        /// Some docs
+        #[myfw::options(name = "example")]
+        #[myfw::docs(hidden)]
+        #[options(name = "ignored")]
+        #[other::docs]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "example");
+
+    let forwarded = receiver
+        .attrs
+        .iter()
+        .map(|attr| darling::util::path_to_string(attr.path()))
+        .collect::<Vec<_>>();
+    assert_eq!(forwarded, vec!["doc", "myfw::docs"]);
+}
+
+#[test]
+fn single_segment_does_not_match() {
+    let di = parse_quote! {
+        #[options(name = "example")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `name`");
+}
@@ -0,0 +1,58 @@
+//! Test that a receiver which claims several attribute names reads its fields from
+//! all of them, as though they had been written in a single attribute.
+
+use darling::FromDeriveInput;
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(serde, serde_with))]
+struct Receiver {
+    #[darling(default)]
+    rename: Option<String>,
+    #[darling(default)]
+    transparent: bool,
+    #[darling(multiple)]
+    bound: Vec<String>,
+}
+
+#[test]
+fn merged_across_names() {
+    let di = parse_quote! {
+        #[serde(rename = "example", bound = "T: Clone")]
+        #[serde_with(transparent, bound = "T: Copy")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.rename.as_deref(), Some("example"));
+    assert!(receiver.transparent);
+    assert_eq!(
+        receiver.bound,
+        vec!["T: Clone".to_string(), "T: Copy".to_string()]
+    );
+}
+
+#[test]
+fn duplicate_across_names() {
+    let di = parse_quote! {
+        #[serde(rename = "a")]
+        #[serde_with(rename = "b")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `rename`");
+}
+
+#[test]
+fn duplicate_across_occurrences() {
+    let di = parse_quote! {
+        #[serde(rename = "a", bound = "T: Clone")]
+        #[serde(transparent, rename = "b", bound = "T: Copy")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert!(err.has_span());
+    assert_eq!(err.to_string(), "Duplicate field `rename`");
+}
@@ -0,0 +1,124 @@
+//! Test that `#[darling(case_insensitive)]` matches field names regardless of case,
+//! while errors still use each field's canonical name.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(case_insensitive)]
+enum Backend {
+    Remote { host: String },
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my), case_insensitive)]
+struct Receiver {
+    timeout: u32,
+    #[darling(rename = "maxRetries", alias = "RETRY_LIMIT", default)]
+    max_retries: u8,
+    #[darling(default)]
+    backend: Option<Backend>,
+}
+
+#[test]
+fn any_casing_matches() {
+    for di in [
+        parse_quote!(
+            #[my(timeout = 3)]
+            struct Example;
+        ),
+        parse_quote!(
+            #[my(TIMEOUT = 3)]
+            struct Example;
+        ),
+        parse_quote!(
+            #[my(Timeout = 3)]
+            struct Example;
+        ),
+    ]
+    .iter()
+    {
+        assert_eq!(Receiver::from_derive_input(di).unwrap().timeout, 3);
+    }
+}
+
+#[test]
+fn renames_aliases_and_variant_fields() {
+    let di = parse_quote! {
+        #[my(timeout = 1, MAXRETRIES = 2, backend(remote(HOST = "x")))]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.max_retries, 2);
+    assert_eq!(
+        receiver.backend,
+        Some(Backend::Remote {
+            host: "x".to_string()
+        })
+    );
+
+    let di = parse_quote! {
+        #[my(timeout = 1, retry_limit = 4)]
+        struct Example;
+    };
+
+    assert_eq!(Receiver::from_derive_input(&di).unwrap().max_retries, 4);
+}
+
+#[test]
+fn errors_use_canonical_names() {
+    let di = parse_quote! {
+        #[my(TIMEOUT = 1, timeout = 2, maxretries = 3, MaxRetries = 4)]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    let errors = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            "Duplicate field `timeout`".to_string(),
+            "Duplicate field `maxRetries`".to_string()
+        ]
+    );
+}
+
+#[test]
+fn unknown_field_keeps_input_casing() {
+    let di = parse_quote! {
+        #[my(TIMEOUT = 1, Retries = 2)]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert!(err.to_string().starts_with("Unknown field: `Retries`"));
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(case_insensitive)]
+enum Level {
+    Debug,
+    #[darling(rename = "warn")]
+    Warning,
+}
+
+#[test]
+fn unit_variants() {
+    for (input, level) in [
+        ("debug", Level::Debug),
+        ("DEBUG", Level::Debug),
+        ("Warn", Level::Warning),
+    ] {
+        assert_eq!(Level::from_string(input).unwrap(), level);
+
+        let word = syn::Ident::new(input, proc_macro2::Span::call_site());
+        let attr: syn::Attribute = parse_quote!(#[level(#word)]);
+        assert_eq!(Level::from_meta(&attr.meta).unwrap(), level);
+    }
+
+    assert_eq!(
+        Level::from_string("Info").unwrap_err().to_string(),
+        "Unknown literal value `Info`"
+    );
+}
@@ -0,0 +1,110 @@
+//! Test reading options from attributes wrapped in `#[cfg_attr(...)]`.
+
+use darling::{FromDeriveInput, FromField};
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(opts), forward_attrs(cfg_attr), unwrap_cfg_attr)]
+struct Receiver {
+    attrs: Vec<syn::Attribute>,
+    cfg_predicates: Vec<syn::Meta>,
+    #[darling(default)]
+    name: Option<String>,
+    #[darling(default)]
+    rank: Option<u8>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(opts))]
+struct Plain {
+    #[darling(default)]
+    name: Option<String>,
+}
+
+#[derive(Debug, FromField)]
+#[darling(attributes(opts), unwrap_cfg_attr)]
+struct FieldReceiver {
+    #[darling(default)]
+    skip: bool,
+}
+
+#[test]
+fn reads_wrapped_attributes() {
+    let di = parse_quote! {
+        #[cfg_attr(feature = "named", opts(name = "wrapped"))]
+        #[opts(rank = 2)]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name.as_deref(), Some("wrapped"));
+    assert_eq!(receiver.rank, Some(2));
+    let predicate: syn::Meta = parse_quote!(feature = "named");
+    assert_eq!(receiver.cfg_predicates, vec![predicate]);
+    // The original `cfg_attr` is still forwarded.
+    assert_eq!(receiver.attrs.len(), 1);
+}
+
+#[test]
+fn reads_nested_cfg_attr() {
+    let di = parse_quote! {
+        #[cfg_attr(test, cfg_attr(unix, opts(name = "nested")), derive(Debug))]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name.as_deref(), Some("nested"));
+    let predicate: syn::Meta = parse_quote!(all(test, unix));
+    assert_eq!(receiver.cfg_predicates, vec![predicate]);
+}
+
+#[test]
+fn no_predicates_without_cfg_attr() {
+    let di = parse_quote! {
+        #[opts(name = "plain")]
+        struct Example;
+    };
+
+    assert!(Receiver::from_derive_input(&di)
+        .unwrap()
+        .cfg_predicates
+        .is_empty());
+}
+
+#[test]
+fn wrapped_errors_are_reported() {
+    let di = parse_quote! {
+        #[cfg_attr(test, opts(name = "a", unknown = 1))]
+        struct Example;
+    };
+
+    Receiver::from_derive_input(&di).unwrap_err();
+}
+
+#[test]
+fn ignored_without_opt_in() {
+    let di = parse_quote! {
+        #[cfg_attr(test, opts(name = "wrapped"))]
+        struct Example;
+    };
+
+    let plain = Plain::from_derive_input(&di).unwrap();
+    assert_eq!(plain.name, None);
+}
+
+#[test]
+fn fields() {
+    let input: syn::DeriveInput = parse_quote! {
+        struct Example {
+            #[cfg_attr(test, opts(skip))]
+            field: u8,
+        }
+    };
+
+    let field = match input.data {
+        syn::Data::Struct(data) => data.fields.into_iter().next().unwrap(),
+        _ => unreachable!(),
+    };
+
+    assert!(FieldReceiver::from_field(&field).unwrap().skip);
+}
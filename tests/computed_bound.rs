@@ -40,3 +40,54 @@ fn skipped_field() {
     );
     assert_eq!(parsed.max_volume, Volume::Shout);
 }
+
+#[derive(FromMeta)]
+struct Limits<T> {
+    #[darling(default)]
+    max: T,
+}
+
+/// A field which falls back to `Default::default()` gets a `Default` bound on its type,
+/// without the receiver declaring one.
+#[test]
+fn defaulted_field() {
+    let attr: syn::Attribute = syn::parse_quote!(#[limits()]);
+    let limits = Limits::<u16>::from_meta(&attr.meta).unwrap();
+    assert_eq!(limits.max, 0);
+}
+
+/// A type which doesn't implement `FromMeta`.
+#[derive(Debug, PartialEq, Eq)]
+struct Label(String);
+
+impl From<String> for Label {
+    fn from(value: String) -> Self {
+        Label(value)
+    }
+}
+
+fn parse_into<T: From<String>>(meta: &syn::Meta) -> darling::Result<T> {
+    String::from_meta(meta).map(T::from)
+}
+
+#[derive(FromMeta)]
+#[darling(extend_bound = "T: From<String>")]
+struct Labeled<T> {
+    #[darling(with = parse_into, default = "Self::unlabeled")]
+    label: T,
+}
+
+impl<T: From<String>> Labeled<T> {
+    fn unlabeled() -> T {
+        T::from(String::new())
+    }
+}
+
+/// Fields with a custom parser and a default don't add a `FromMeta` bound for the types
+/// they use, since that impl is never called.
+#[test]
+fn custom_parser_field() {
+    let attr: syn::Attribute = syn::parse_quote!(#[labeled(label = "hello")]);
+    let labeled = Labeled::<Label>::from_meta(&attr.meta).unwrap();
+    assert_eq!(labeled.label, Label("hello".to_string()));
+}
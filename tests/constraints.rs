@@ -0,0 +1,93 @@
+//! Test that `#[darling(requires = "...")]` and `#[darling(conflicts_with = "...")]`
+//! enforce rules between fields, with errors spanned to the field that declares the rule.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[allow(dead_code)]
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my))]
+struct Receiver {
+    #[darling(default, requires = "password")]
+    username: Option<String>,
+    #[darling(default)]
+    password: Option<String>,
+    #[darling(default, conflicts_with = "username", conflicts_with = "password")]
+    token: Option<String>,
+    #[darling(multiple, rename = "scope", requires = token)]
+    scopes: Vec<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMeta)]
+enum Source {
+    File {
+        #[darling(default, rename = "path", conflicts_with = "inline")]
+        file_path: Option<String>,
+        #[darling(default)]
+        inline: Option<String>,
+    },
+}
+
+fn errors(di: syn::DeriveInput) -> Vec<String> {
+    match Receiver::from_derive_input(&di) {
+        Ok(_) => vec![],
+        Err(e) => {
+            assert!(e.clone().into_iter().all(|e| e.has_span()));
+            e.into_iter().map(|e| e.to_string()).collect()
+        }
+    }
+}
+
+#[test]
+fn satisfied() {
+    assert!(errors(parse_quote! {
+        #[my(username = "a", password = "b")]
+        struct Example;
+    })
+    .is_empty());
+
+    assert!(errors(parse_quote! {
+        #[my(token = "t", scope = "read", scope = "write")]
+        struct Example;
+    })
+    .is_empty());
+
+    assert!(errors(parse_quote!(
+        struct Example;
+    ))
+    .is_empty());
+}
+
+#[test]
+fn requires() {
+    assert_eq!(
+        errors(parse_quote! {
+            #[my(username = "a", scope = "read")]
+            struct Example;
+        }),
+        vec!["`username` requires `password`", "`scope` requires `token`"]
+    );
+}
+
+#[test]
+fn conflicts_with() {
+    assert_eq!(
+        errors(parse_quote! {
+            #[my(username = "a", password = "b")]
+            #[my(token = "t")]
+            struct Example;
+        }),
+        vec![
+            "`token` conflicts with `username`",
+            "`token` conflicts with `password`"
+        ]
+    );
+}
+
+#[test]
+fn enum_variant_fields() {
+    let attr: syn::Attribute = parse_quote!(#[ignore(file(path = "a", inline = "b"))]);
+    let err = Source::from_meta(&attr.meta).unwrap_err();
+    assert_eq!(err.to_string(), "`path` conflicts with `inline` at file");
+}
@@ -1,8 +1,10 @@
 #![allow(dead_code)]
 
+use std::marker::PhantomData;
 use std::ops::Add;
 
-use darling::{FromDeriveInput, FromMeta};
+use darling::{FromDeriveInput, FromMeta, Result};
+use syn::parse_quote;
 
 #[derive(Debug, Clone, FromMeta)]
 #[darling(bound = "T: FromMeta + Add")]
@@ -16,10 +18,51 @@ impl<T: Add> Add for Wrapper<T> {
 }
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(hello), bound = "Wrapper<T>: Add, T: FromMeta")]
+#[darling(attributes(hello), bound = "Wrapper<T>: Add, T: FromMeta + Add")]
 struct Foo<T> {
     lorem: Wrapper<T>,
 }
 
 #[test]
 fn expansion() {}
+
+/// A type which doesn't implement `FromMeta`.
+#[derive(Debug)]
+struct Opaque;
+
+fn parse_marker<T>(_: &syn::Meta) -> Result<PhantomData<T>> {
+    Ok(PhantomData)
+}
+
+/// The inferred bound would require `T: FromMeta`, which this receiver never needs.
+#[derive(Debug, FromMeta)]
+#[darling(bound = "")]
+struct Tagged<T> {
+    name: String,
+    #[darling(default, with = parse_marker)]
+    marker: PhantomData<T>,
+}
+
+#[test]
+fn bound_replaces_inferred_bounds() {
+    let attr: syn::Attribute = parse_quote!(#[tagged(name = "hello")]);
+    let tagged = Tagged::<Opaque>::from_meta(&attr.meta).unwrap();
+    assert_eq!(tagged.name, "hello");
+}
+
+/// The inferred `T: FromMeta` bound is kept, and `T: Default` is added for the field default.
+#[derive(Debug, FromMeta)]
+#[darling(extend_bound = "T: Default")]
+struct WithDefault<T> {
+    #[darling(default)]
+    value: T,
+}
+
+#[test]
+fn extend_bound_keeps_inferred_bounds() {
+    let attr: syn::Attribute = parse_quote!(#[with_default(value = 5)]);
+    assert_eq!(WithDefault::<u8>::from_meta(&attr.meta).unwrap().value, 5);
+
+    let attr: syn::Attribute = parse_quote!(#[with_default()]);
+    assert_eq!(WithDefault::<u8>::from_meta(&attr.meta).unwrap().value, 0);
+}
@@ -0,0 +1,95 @@
+//! Test that `#[darling(deprecated_alias = "...")]` accepts an old name for a field,
+//! and records a warning in the struct's `#[darling(warnings)]` field when it's used.
+
+use darling::{util::Warnings, FromDeriveInput, FromMeta};
+use quote::ToTokens;
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+#[darling(case_insensitive)]
+struct Nested {
+    #[darling(deprecated_alias = "Old_Size")]
+    size: u8,
+    #[darling(warnings)]
+    warnings: Warnings,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my))]
+struct Receiver {
+    #[darling(alias = "hue", deprecated_alias = "colour")]
+    color: String,
+    #[darling(multiple, deprecated_alias = "tags")]
+    tag: Vec<String>,
+    #[darling(default)]
+    nested: Option<Nested>,
+    #[darling(warnings)]
+    warnings: Warnings,
+}
+
+fn messages(warnings: &Warnings) -> Vec<&str> {
+    warnings.iter().map(|(_, message)| message).collect()
+}
+
+#[test]
+fn no_warnings_for_current_names() {
+    let di = parse_quote! {
+        #[my(hue = "red", tag = "a")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.color, "red");
+    assert!(receiver.warnings.is_empty());
+    assert!(receiver.warnings.to_token_stream().is_empty());
+}
+
+#[test]
+fn deprecated_names_warn() {
+    let di = parse_quote! {
+        #[my(colour = "red", tag = "a", tags = "b", tags = "c")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.color, "red");
+    assert_eq!(receiver.tag.len(), 3);
+    assert_eq!(
+        messages(&receiver.warnings),
+        vec![
+            "`colour` is deprecated; use `color` instead",
+            "`tags` is deprecated; use `tag` instead",
+            "`tags` is deprecated; use `tag` instead",
+        ]
+    );
+}
+
+#[test]
+fn deprecated_and_current_conflict() {
+    let di = parse_quote! {
+        #[my(color = "red", colour = "blue")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `color`");
+}
+
+#[test]
+fn nested_warnings_are_separate() {
+    let di = parse_quote! {
+        #[my(color = "red", nested(OLD_SIZE = 3))]
+        struct Example;
+    };
+
+    let mut receiver = Receiver::from_derive_input(&di).unwrap();
+    assert!(receiver.warnings.is_empty());
+
+    let nested = receiver.nested.unwrap();
+    assert_eq!(nested.size, 3);
+    receiver.warnings.extend(Some(nested.warnings));
+    assert_eq!(
+        messages(&receiver.warnings),
+        vec!["`Old_Size` is deprecated; use `size` instead"]
+    );
+}
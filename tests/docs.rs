@@ -0,0 +1,110 @@
+//! Test that a `docs` field receives the doc comments of the input when the receiver opts in
+//! with `#[darling(docs)]`, and is an ordinary option otherwise.
+
+use darling::{ast, FromAttributes, FromDeriveInput, FromField, FromVariant};
+use syn::parse_quote;
+
+#[derive(Debug, FromField)]
+#[darling(docs)]
+struct Field {
+    ident: Option<syn::Ident>,
+    docs: Option<String>,
+}
+
+#[derive(Debug, FromVariant)]
+#[darling(docs)]
+struct Variant {
+    docs: String,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(opts), docs)]
+struct Receiver {
+    docs: Vec<String>,
+    data: ast::Data<Variant, Field>,
+    #[darling(default)]
+    name: Option<String>,
+}
+
+#[derive(Debug, FromAttributes)]
+#[darling(attributes(opts), docs)]
+struct Attrs {
+    docs: Vec<String>,
+}
+
+#[test]
+fn struct_and_fields() {
+    let di = parse_quote! {
+        /// An example.
+        ///
+        /// More details.
+        #[opts(name = "x")]
+        struct Example {
+            /// The first field.
+            documented: u8,
+            undocumented: u8,
+        }
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.docs, vec!["An example.", "", "More details."]);
+    assert_eq!(receiver.name.as_deref(), Some("x"));
+
+    let fields = receiver.data.take_struct().unwrap().fields;
+    assert_eq!(fields[0].docs.as_deref(), Some("The first field."));
+    assert_eq!(fields[1].docs, None);
+    assert!(fields[1].ident.is_some());
+}
+
+#[test]
+fn variants() {
+    let di = parse_quote! {
+        enum Example {
+            /// First
+            /// variant.
+            A,
+            B,
+        }
+    };
+
+    let variants = Receiver::from_derive_input(&di)
+        .unwrap()
+        .data
+        .take_enum()
+        .unwrap();
+    assert_eq!(variants[0].docs, "First\nvariant.");
+    assert_eq!(variants[1].docs, "");
+}
+
+#[test]
+fn attributes() {
+    let item: syn::ItemFn = parse_quote! {
+        /// Does a thing.
+        #[opts]
+        fn example() {}
+    };
+
+    let attrs = Attrs::from_attributes(&item.attrs).unwrap();
+    assert_eq!(attrs.docs, vec!["Does a thing."]);
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(opts))]
+struct Plain {
+    #[darling(default)]
+    docs: Option<String>,
+}
+
+#[test]
+fn plain_docs_option() {
+    let di = parse_quote! {
+        /// Not read.
+        #[opts(docs = "https://example.com")]
+        struct Example;
+    };
+
+    assert_eq!(
+        Plain::from_derive_input(&di).unwrap().docs.as_deref(),
+        Some("https://example.com")
+    );
+}
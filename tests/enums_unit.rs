@@ -1,14 +1,46 @@
 //! Test expansion of enum variants which have no associated data.
 
 use darling::FromMeta;
+use syn::parse_quote;
 
-#[derive(Debug, FromMeta)]
+#[derive(Debug, PartialEq, Eq, FromMeta)]
 #[darling(rename_all = "snake_case")]
+#[allow(dead_code)]
 enum Pattern {
     Owned,
     Immutable,
     Mutable,
 }
 
+#[derive(Debug, FromMeta)]
+struct Holder {
+    pattern: Pattern,
+}
+
+fn holder(meta: syn::Meta) -> darling::Result<Holder> {
+    Holder::from_meta(&meta)
+}
+
 #[test]
 fn expansion() {}
+
+#[test]
+fn string_value() {
+    let h = holder(parse_quote!(holder(pattern = "mutable"))).unwrap();
+    assert_eq!(h.pattern, Pattern::Mutable);
+}
+
+#[test]
+fn word_in_list() {
+    let h = holder(parse_quote!(holder(pattern(owned)))).unwrap();
+    assert_eq!(h.pattern, Pattern::Owned);
+}
+
+#[test]
+fn list_rejects_variant_with_arguments() {
+    let err = holder(parse_quote!(holder(pattern(owned(a))))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unexpected meta-item format `list` at pattern"
+    );
+}
@@ -0,0 +1,101 @@
+//! Test that `#[darling(exclusive(...))]` allows at most one field of a group to be set,
+//! and that the error names every field of the group the caller supplied.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(exclusive(json, yaml, toml))]
+struct Format {
+    #[darling(default)]
+    json: bool,
+    #[darling(default)]
+    yaml: bool,
+    #[darling(default, rename = "TOML")]
+    toml: bool,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo), exclusive(path, inline), exclusive(quiet, verbose))]
+struct Receiver {
+    #[darling(default)]
+    format: Format,
+    #[darling(default)]
+    path: Option<String>,
+    #[darling(default)]
+    inline: Option<String>,
+    #[darling(default)]
+    quiet: bool,
+    #[darling(default)]
+    verbose: bool,
+}
+
+#[test]
+fn one_of_each_group() {
+    let di = parse_quote! {
+        #[demo(path = "a.json", verbose, format(json))]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.path.as_deref(), Some("a.json"));
+    assert_eq!(receiver.inline, None);
+    assert!(receiver.verbose);
+    assert!(!receiver.quiet);
+    assert!(receiver.format.json);
+    assert!(!receiver.format.yaml);
+    assert!(!receiver.format.toml);
+}
+
+#[test]
+fn none_set() {
+    let di = parse_quote! {
+        #[demo]
+        struct Example;
+    };
+
+    Receiver::from_derive_input(&di).unwrap();
+}
+
+#[test]
+fn conflicting_keys_are_named() {
+    let di = parse_quote! {
+        #[demo(format(json, TOML, yaml), path = "a", inline = "b")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 2, "{}", err);
+
+    let errors = err
+        .into_iter()
+        .inspect(|e| assert!(e.has_span()))
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    assert!(errors.contains(
+        &"At most one of `json`, `yaml`, `TOML` may be set, but found `json`, `yaml`, `TOML` at format"
+            .to_string()
+    ), "{:?}", errors);
+    assert!(
+        errors.contains(
+            &"At most one of `path`, `inline` may be set, but found `path`, `inline`".to_string()
+        ),
+        "{:?}",
+        errors
+    );
+}
+
+/// A value set to `false` still counts as set; the group constrains which keys appear.
+#[test]
+fn explicit_false_counts() {
+    let di = parse_quote! {
+        #[demo(quiet = false, verbose)]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "At most one of `quiet`, `verbose` may be set, but found `quiet`, `verbose`"
+    );
+}
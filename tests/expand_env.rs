@@ -0,0 +1,64 @@
+//! Test `#[darling(expand_env)]`, which expands environment variables in string values.
+
+use darling::util::Warnings;
+use darling::FromMeta;
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Options {
+    #[darling(expand_env)]
+    path: String,
+    #[darling(expand_env, default)]
+    include: Vec<String>,
+    #[darling(default)]
+    raw: Option<String>,
+}
+
+fn options(meta: syn::Meta) -> darling::Result<Options> {
+    Options::from_meta(&meta)
+}
+
+/// Cargo sets `CARGO_PKG_NAME` when running tests, so these don't need to modify the
+/// environment, which would race with other tests.
+#[test]
+fn expands_values() {
+    let name = env!("CARGO_PKG_NAME");
+    let opts = options(parse_quote!(opts(
+        path = "${CARGO_PKG_NAME}/config",
+        include("${CARGO_PKG_NAME}/a", "b"),
+        raw = "${CARGO_PKG_NAME}"
+    )))
+    .unwrap();
+
+    assert_eq!(opts.path, format!("{}/config", name));
+    assert_eq!(opts.include, vec![format!("{}/a", name), "b".into()]);
+    assert_eq!(opts.raw.as_deref(), Some("${CARGO_PKG_NAME}"));
+}
+
+#[test]
+fn unset_variable() {
+    let err = options(parse_quote!(opts(path = "${DARLING_TEST_EXPAND_UNSET}"))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Environment variable `DARLING_TEST_EXPAND_UNSET` is not set at path"
+    );
+}
+
+#[derive(Debug, FromMeta)]
+struct Tracked {
+    #[darling(expand_env)]
+    path: String,
+    #[darling(warnings)]
+    warnings: Warnings,
+}
+
+#[test]
+fn tracks_variables() {
+    let tracked = Tracked::from_meta(&parse_quote!(opts(
+        path = "${CARGO_PKG_NAME}/${CARGO_PKG_NAME}"
+    )))
+    .unwrap();
+    assert_eq!(tracked.path, format!("{0}/{0}", env!("CARGO_PKG_NAME")));
+    assert_eq!(tracked.warnings.env_vars(), ["CARGO_PKG_NAME"]);
+    assert!(tracked.warnings.is_empty());
+}
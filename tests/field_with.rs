@@ -0,0 +1,57 @@
+//! Test that `#[darling(with = ...)]` replaces the field type's `FromMeta` impl,
+//! whether it's given a path to a function or an inline closure.
+
+use darling::{util::parse_expr, Error, FromDeriveInput, FromMeta, Result};
+use syn::{parse_quote, Expr, Meta};
+
+fn parse_shouty(meta: &Meta) -> Result<String> {
+    String::from_meta(meta).map(|s| s.to_uppercase())
+}
+
+fn parse_even(meta: &Meta) -> Result<u8> {
+    let value = u8::from_meta(meta)?;
+    if value % 2 == 0 {
+        Ok(value)
+    } else {
+        Err(Error::custom("Expected an even number"))
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    #[darling(with = parse_shouty)]
+    name: String,
+    #[darling(with = "parse_even", default)]
+    count: u8,
+    #[darling(with = |m| Ok(String::from_meta(m)?.len()))]
+    length: usize,
+    #[darling(with = parse_expr::preserve_str_literal, map = Some)]
+    expr: Option<Expr>,
+}
+
+#[test]
+fn custom_parsers() {
+    let di = parse_quote! {
+        #[demo(name = "hello", count = 4, length = "four", expr = "literal")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "HELLO");
+    assert_eq!(receiver.count, 4);
+    assert_eq!(receiver.length, 4);
+    assert_eq!(receiver.expr, Some(parse_quote!("literal")));
+}
+
+#[test]
+fn custom_parser_error() {
+    let di = parse_quote! {
+        #[demo(name = "hello", count = 3, length = "")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert!(err.has_span());
+    assert_eq!(err.to_string(), "Expected an even number at count");
+}
@@ -0,0 +1,58 @@
+//! Test that boolean options can be written as a bare word, and that both `bool`
+//! and `Flag` still accept an explicit `= true` or `= false`.
+
+use darling::{util::Flag, FromDeriveInput};
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my))]
+struct Receiver {
+    #[darling(default)]
+    verbose: bool,
+    quiet: Flag,
+}
+
+fn parse(di: syn::DeriveInput) -> (bool, bool) {
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    (receiver.verbose, receiver.quiet.is_present())
+}
+
+#[test]
+fn absent() {
+    assert_eq!(
+        parse(parse_quote!(
+            struct Example;
+        )),
+        (false, false)
+    );
+}
+
+#[test]
+fn word() {
+    assert_eq!(
+        parse(parse_quote! {
+            #[my(verbose, quiet)]
+            struct Example;
+        }),
+        (true, true)
+    );
+}
+
+#[test]
+fn explicit_values() {
+    assert_eq!(
+        parse(parse_quote! {
+            #[my(verbose = false, quiet = false)]
+            struct Example;
+        }),
+        (false, false)
+    );
+
+    assert_eq!(
+        parse(parse_quote! {
+            #[my(verbose = true, quiet = true)]
+            struct Example;
+        }),
+        (true, true)
+    );
+}
@@ -0,0 +1,112 @@
+//! Test that `#[darling(flatten)]` parses a field from the items that its sibling
+//! fields don't claim, so option groups can be shared between receivers.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, Default, PartialEq, Eq, FromMeta)]
+struct Timeouts {
+    timeout: u32,
+    #[darling(default)]
+    retries: u8,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my))]
+struct Receiver {
+    name: String,
+    #[darling(flatten)]
+    timeouts: Timeouts,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my))]
+struct WithDefault {
+    #[darling(default)]
+    name: Option<String>,
+    #[darling(flatten, default)]
+    timeouts: Timeouts,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Transport {
+    Tcp {
+        port: u16,
+        #[darling(flatten)]
+        timeouts: Timeouts,
+    },
+}
+
+#[test]
+fn same_level_keys() {
+    let di = parse_quote! {
+        #[my(name = "x", timeout = 3)]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "x");
+    assert_eq!(
+        receiver.timeouts,
+        Timeouts {
+            timeout: 3,
+            retries: 0
+        }
+    );
+}
+
+#[test]
+fn across_attributes() {
+    let di = parse_quote! {
+        #[my(timeout = 3)]
+        #[my(name = "x", retries = 2)]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.timeouts.retries, 2);
+}
+
+#[test]
+fn unknown_and_missing_fields() {
+    let di = parse_quote! {
+        #[my(name = "x", timeot = 3)]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    let errors = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors
+        .iter()
+        .any(|e| e.starts_with("Unknown field: `timeot`")));
+    assert!(errors.contains(&"Missing field `timeout`".to_string()));
+}
+
+#[test]
+fn default_when_absent() {
+    let di = parse_quote! {
+        #[my(name = "x")]
+        struct Example;
+    };
+
+    let receiver = WithDefault::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name.as_deref(), Some("x"));
+    assert_eq!(receiver.timeouts, Timeouts::default());
+}
+
+#[test]
+fn enum_variant() {
+    let attr: syn::Attribute = parse_quote!(#[ignore(tcp(port = 80, timeout = 5))]);
+    let transport = Transport::from_meta(&attr.meta).unwrap();
+    assert_eq!(
+        transport,
+        Transport::Tcp {
+            port: 80,
+            timeouts: Timeouts {
+                timeout: 5,
+                retries: 0
+            }
+        }
+    );
+}
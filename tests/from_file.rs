@@ -0,0 +1,160 @@
+//! Test `#[darling(from_file = "...")]`, which reads options from a TOML or JSON file.
+//! The TOML tests require the `toml` feature and the JSON tests require the `json` feature.
+
+use darling::util::Warnings;
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, Default, FromMeta)]
+#[allow(dead_code)]
+struct Limits {
+    max: u32,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(settings), from_file = "config")]
+#[allow(dead_code)]
+struct Receiver {
+    name: String,
+    #[darling(default)]
+    retries: u8,
+    #[darling(default)]
+    offset: i32,
+    #[darling(default)]
+    tags: Vec<String>,
+    #[darling(default)]
+    limits: Limits,
+    #[darling(warnings)]
+    warnings: Warnings,
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml() {
+    let di = parse_quote! {
+        #[settings(config = "tests/fixtures/settings.toml")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "from-file");
+    assert_eq!(receiver.retries, 3);
+    assert_eq!(receiver.offset, -2);
+    assert_eq!(receiver.tags, vec!["a", "b"]);
+    assert_eq!(receiver.limits.max, 64);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn inline_options_take_precedence() {
+    let di = parse_quote! {
+        #[settings(retries = 9, config = "tests/fixtures/settings.toml", limits(max = 1))]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "from-file");
+    assert_eq!(receiver.retries, 9);
+    assert_eq!(receiver.limits.max, 1);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json() {
+    let di = parse_quote! {
+        #[settings(config = "tests/fixtures/settings.json")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "from-json");
+    assert_eq!(receiver.retries, 5);
+    assert_eq!(receiver.offset, 0);
+    assert_eq!(receiver.tags, vec!["c"]);
+    assert_eq!(receiver.limits.max, 8);
+}
+
+#[test]
+fn missing_file() {
+    let di = parse_quote! {
+        #[settings(name = "inline", config = "tests/fixtures/missing.toml")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 1);
+    assert!(err
+        .to_string()
+        .starts_with("Unable to read `tests/fixtures/missing.toml`"));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn tracks_file() {
+    use quote::{quote, ToTokens};
+
+    let di = parse_quote! {
+        #[settings(config = "tests/fixtures/settings.toml")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    let path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/settings.toml");
+    assert_eq!(receiver.warnings.files(), std::slice::from_ref(&path));
+
+    let path = path.to_str().unwrap();
+    assert_eq!(
+        receiver.warnings.to_token_stream().to_string(),
+        quote!(
+            const _: &[u8] = include_bytes!(#path);
+        )
+        .to_string()
+    );
+}
+
+#[test]
+fn paths_outside_crate() {
+    for path in &["../settings.toml", "/etc/settings.toml"] {
+        let di = parse_quote! {
+            #[settings(name = "inline", config = #path)]
+            struct Example;
+        };
+
+        let err = Receiver::from_derive_input(&di).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("must be a path inside the crate directory"));
+    }
+}
+
+#[test]
+fn inline_only() {
+    let di = parse_quote! {
+        #[settings(name = "inline")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "inline");
+}
+
+/// A receiver without a warnings field, whose only option is a keyword.
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(settings), from_file = "config")]
+#[allow(dead_code)]
+struct Kind {
+    r#type: String,
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn keyword_key_without_warnings() {
+    let di = parse_quote! {
+        #[settings(config = "tests/fixtures/keyword.toml")]
+        struct Example;
+    };
+
+    let kind = Kind::from_derive_input(&di).unwrap();
+    assert_eq!(kind.r#type, "unit");
+}
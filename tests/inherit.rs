@@ -0,0 +1,130 @@
+//! Test that `#[darling(inherit)]` lets field and variant receivers consult the parsed
+//! container through `darling::ast::Inherit`.
+
+use darling::{
+    ast::{self, Inherit},
+    Error, FromDeriveInput, FromField, FromVariant, Result,
+};
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my), inherit)]
+struct Receiver {
+    #[darling(default)]
+    prefix: Option<String>,
+    data: ast::Data<VariantReceiver, FieldReceiver>,
+}
+
+#[derive(Debug, FromField)]
+#[darling(attributes(my))]
+struct FieldReceiver {
+    ident: Option<syn::Ident>,
+    #[darling(default)]
+    rename: Option<String>,
+}
+
+impl Inherit<Receiver> for FieldReceiver {
+    fn inherit(&mut self, parent: &Receiver) -> Result<()> {
+        if let (None, Some(prefix), Some(ident)) = (&self.rename, &parent.prefix, &self.ident) {
+            if prefix.is_empty() {
+                return Err(Error::custom("Prefix must not be empty").at("prefix"));
+            }
+
+            self.rename = Some(format!("{}{}", prefix, ident));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(my))]
+struct VariantReceiver {
+    ident: syn::Ident,
+    #[darling(default)]
+    rename: Option<String>,
+}
+
+impl Inherit<Receiver> for VariantReceiver {
+    fn inherit(&mut self, parent: &Receiver) -> Result<()> {
+        if self.rename.is_none() {
+            self.rename = parent
+                .prefix
+                .as_ref()
+                .map(|prefix| format!("{}{}", prefix, self.ident));
+        }
+
+        Ok(())
+    }
+}
+
+fn renames(receiver: &Receiver) -> Vec<Option<String>> {
+    match &receiver.data {
+        ast::Data::Struct(fields) => fields.iter().map(|f| f.rename.clone()).collect(),
+        ast::Data::Enum(variants) => variants.iter().map(|v| v.rename.clone()).collect(),
+    }
+}
+
+#[test]
+fn fields_inherit_from_container() {
+    let di = parse_quote! {
+        #[my(prefix = "my_")]
+        struct Example {
+            first_name: String,
+            #[my(rename = "surname")]
+            last_name: String,
+        }
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(
+        renames(&receiver),
+        vec![
+            Some("my_first_name".to_string()),
+            Some("surname".to_string())
+        ]
+    );
+}
+
+#[test]
+fn variants_inherit_from_container() {
+    let di = parse_quote! {
+        #[my(prefix = "Is")]
+        enum Example {
+            Alpha,
+            Beta,
+        }
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(
+        renames(&receiver),
+        vec![Some("IsAlpha".to_string()), Some("IsBeta".to_string())]
+    );
+}
+
+#[test]
+fn nothing_to_inherit() {
+    let di = parse_quote! {
+        struct Example {
+            first_name: String,
+        }
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(renames(&receiver), vec![None]);
+}
+
+#[test]
+fn inherit_errors() {
+    let di = parse_quote! {
+        #[my(prefix = "")]
+        struct Example {
+            first_name: String,
+            last_name: String,
+        }
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 2);
+}
@@ -0,0 +1,111 @@
+//! Test that list-valued fields parse each item of `field(a, b, c)` into the element type.
+
+use darling::util::OneOrMany;
+use darling::FromDeriveInput;
+use syn::{parse_quote, Path};
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(lists))]
+struct Receiver {
+    #[darling(default)]
+    tags: Vec<String>,
+    #[darling(default)]
+    traits: Vec<Path>,
+    #[darling(default)]
+    versions: Vec<u16>,
+    #[darling(default)]
+    rgba: Option<[u8; 4]>,
+    #[darling(default)]
+    labels: OneOrMany<String>,
+}
+
+#[test]
+fn strings() {
+    let di = parse_quote! {
+        #[lists(tags("a", "b", "c"))]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.tags, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn single_string() {
+    let di = parse_quote! {
+        #[lists(tags = "a")]
+        struct Example;
+    };
+
+    Receiver::from_derive_input(&di).unwrap_err();
+
+    let di = parse_quote! {
+        #[lists(labels = "a")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(*receiver.labels, vec!["a"]);
+}
+
+#[test]
+fn paths_quoted_and_unquoted() {
+    let di = parse_quote! {
+        #[lists(traits(Debug, "serde::Serialize", ::std::hash::Hash))]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    let expected: Vec<Path> = vec![
+        parse_quote!(Debug),
+        parse_quote!(serde::Serialize),
+        parse_quote!(::std::hash::Hash),
+    ];
+    assert_eq!(receiver.traits, expected);
+}
+
+#[test]
+fn path_errors_point_at_items() {
+    let di = parse_quote! {
+        #[lists(traits(Debug, "not a path", Clone = 1))]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 2);
+}
+
+#[test]
+fn array_values() {
+    let di = parse_quote! {
+        #[lists(versions = [1, 2, 3], rgba = [255, 0, 0, 128], tags = ["a", "b"])]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.versions, vec![1, 2, 3]);
+    assert_eq!(receiver.rgba, Some([255, 0, 0, 128]));
+    assert_eq!(receiver.tags, vec!["a", "b"]);
+}
+
+#[test]
+fn array_value_errors() {
+    let di = parse_quote! {
+        #[lists(versions = [1, "two", 70000], rgba = [1, 2])]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 3);
+}
+
+#[test]
+fn errors_for_each_bad_item() {
+    let di = parse_quote! {
+        #[lists(tags("a", 1, b))]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 2);
+}
@@ -0,0 +1,71 @@
+//! Test `FromDeriveInput::from_derive_input_lossy`, which returns a best-effort receiver
+//! along with any errors.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, Default, FromMeta)]
+struct Limits {
+    max: u32,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(opts), lossy)]
+struct Lossy {
+    ident: syn::Ident,
+    name: String,
+    #[darling(default = "default_retries")]
+    retries: u8,
+    #[darling(default)]
+    limits: Option<Limits>,
+}
+
+fn default_retries() -> u8 {
+    3
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(opts))]
+struct Strict {
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[test]
+fn valid_input() {
+    let (value, errors) = Lossy::from_derive_input_lossy(&parse_quote! {
+        #[opts(name = "lorem", retries = 1)]
+        struct Foo;
+    });
+
+    let value = value.unwrap();
+    assert!(errors.is_none());
+    assert_eq!(value.ident, "Foo");
+    assert_eq!(value.name, "lorem");
+    assert_eq!(value.retries, 1);
+}
+
+#[test]
+fn recovers_from_field_errors() {
+    let (value, errors) = Lossy::from_derive_input_lossy(&parse_quote! {
+        #[opts(retries = "many", limits(max = 2), unknown)]
+        struct Foo;
+    });
+
+    // The missing name, the bad retries, and the unknown field.
+    assert_eq!(errors.unwrap().len(), 3);
+    let value = value.unwrap();
+    assert_eq!(value.name, "");
+    assert_eq!(value.retries, 3);
+    assert_eq!(value.limits.unwrap().max, 2);
+}
+
+#[test]
+fn strict_receivers_return_no_value() {
+    let (value, errors) = Strict::from_derive_input_lossy(&parse_quote! {
+        struct Foo;
+    });
+
+    assert!(value.is_none());
+    assert_eq!(errors.unwrap().len(), 1);
+}
@@ -0,0 +1,58 @@
+//! Test that `#[darling(map = ...)]` transforms a parsed value before it's stored,
+//! both on fields and on the container.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+fn lowercase(value: String) -> String {
+    value.to_lowercase()
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(map = |mut s: Self| { s.tags.sort(); s })]
+struct Tagged {
+    #[darling(multiple, map = "lowercase")]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    #[darling(map = lowercase)]
+    name: String,
+    #[darling(map = |v: u8| u16::from(v) * 100)]
+    scaled: u16,
+    #[darling(default, map = Some)]
+    optional: Option<String>,
+    tagged: Tagged,
+}
+
+#[test]
+fn field_and_container_maps() {
+    let di = parse_quote! {
+        #[demo(name = "HeLLo", scaled = 7, tagged(tags = "B", tags = "a"))]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "hello");
+    assert_eq!(receiver.scaled, 700);
+    assert_eq!(receiver.optional, None);
+    assert_eq!(
+        receiver.tagged,
+        Tagged {
+            tags: vec!["a".to_string(), "b".to_string()]
+        }
+    );
+}
+
+#[test]
+fn map_applies_to_present_values_only() {
+    let di = parse_quote! {
+        #[demo(name = "x", scaled = 1, optional = "here", tagged())]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.optional, Some("here".to_string()));
+}
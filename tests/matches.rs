@@ -0,0 +1,87 @@
+//! Test that `#[darling(matches = "...")]` rejects string values that don't match a
+//! regular expression. This requires the `regex` feature.
+
+#![cfg(feature = "regex")]
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    #[darling(matches = "^[a-z_]+$")]
+    name: String,
+    #[darling(multiple, rename = "tag", matches = r"^v\d+$")]
+    tags: Vec<String>,
+}
+
+#[test]
+fn matching_values() {
+    let di = parse_quote! {
+        #[demo(name = "snake_case", tag = "v1", tag = "v20")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "snake_case");
+    assert_eq!(receiver.tags, vec!["v1", "v20"]);
+}
+
+#[test]
+fn non_matching_values() {
+    let di = parse_quote! {
+        #[demo(name = "CamelCase", tag = "v1", tag = "latest")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.into_iter()
+            .inspect(|e| assert!(e.has_span()))
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "Expected a value matching `^[a-z_]+$`, but found `CamelCase` at name",
+            r"Expected a value matching `^v\d+$`, but found `latest` at tag[1]",
+        ]
+    );
+}
+
+#[derive(Debug, FromMeta)]
+struct Crate {
+    #[darling(default, matches = "^[a-z][a-z0-9-]*$", map = Some)]
+    name: Option<String>,
+}
+
+/// The pattern is compiled once, so reusing the receiver must keep checking correctly.
+#[test]
+fn repeated_use() {
+    for (name, ok) in [("serde", true), ("Serde", false), ("serde-json", true)] {
+        let attr: syn::Attribute = parse_quote!(#[krate(name = #name)]);
+        let result = Crate::from_meta(&attr.meta);
+        assert_eq!(result.is_ok(), ok, "{}", name);
+        if let Ok(krate) = result {
+            assert_eq!(krate.name.as_deref(), Some(name));
+        }
+    }
+}
+
+/// Patterns can use braces, such as a `{n,m}` repetition, without breaking the error message.
+#[test]
+fn braces_in_pattern() {
+    #[derive(Debug, FromMeta)]
+    struct Options {
+        #[darling(matches = "^[a-z]{2,4}$")]
+        code: String,
+    }
+
+    let attr: syn::Attribute = parse_quote!(#[options(code = "abc")]);
+    assert_eq!(Options::from_meta(&attr.meta).unwrap().code, "abc");
+
+    let attr: syn::Attribute = parse_quote!(#[options(code = "abcdef")]);
+    let err = Options::from_meta(&attr.meta).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected a value matching `^[a-z]{2,4}$`, but found `abcdef` at code"
+    );
+}
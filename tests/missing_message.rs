@@ -0,0 +1,60 @@
+//! Test that `#[darling(missing = "...")]` replaces the standard missing-field error
+//! for a required field.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Endpoint {
+    #[darling(missing = "`url` is required; see the endpoint docs")]
+    url: String,
+    port: u16,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my))]
+struct Receiver {
+    #[darling(missing = "the `name` option is required")]
+    name: String,
+    #[darling(missing = "never shown, since `Option` fields may be absent")]
+    label: Option<String>,
+    endpoint: Endpoint,
+}
+
+#[test]
+fn custom_messages() {
+    let di = parse_quote! {
+        #[my(endpoint())]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    let mut errors = err
+        .flatten()
+        .into_iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    errors.sort();
+    assert_eq!(
+        errors,
+        vec![
+            "Missing field `port` at endpoint".to_string(),
+            "`url` is required; see the endpoint docs at endpoint".to_string(),
+            "the `name` option is required".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn present_values() {
+    let di = parse_quote! {
+        #[my(name = "x", endpoint(url = "https://example.com", port = 443))]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "x");
+    assert_eq!(receiver.label, None);
+    assert_eq!(receiver.endpoint.url, "https://example.com");
+    assert_eq!(receiver.endpoint.port, 443);
+}
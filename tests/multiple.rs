@@ -28,3 +28,49 @@ fn expand_many() {
         vec!["Hello".to_string(), "World".to_string()]
     );
 }
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(hello))]
+struct Amet {
+    #[darling(multiple)]
+    item: Vec<String>,
+    #[darling(multiple, rename = "count")]
+    counts: Vec<u8>,
+}
+
+#[test]
+fn expand_across_attributes() {
+    let di = parse_quote! {
+        #[hello(item = "a", count = 1)]
+        #[hello(item = "b")]
+        #[hello(item = "c", count = 2)]
+        pub struct Baz;
+    };
+
+    let amet = Amet::from_derive_input(&di).unwrap();
+    assert_eq!(amet.item, vec!["a", "b", "c"]);
+    assert_eq!(amet.counts, vec![1, 2]);
+}
+
+#[test]
+fn empty_when_absent() {
+    let di = parse_quote! {
+        #[hello(item = "a")]
+        pub struct Baz;
+    };
+
+    let amet = Amet::from_derive_input(&di).unwrap();
+    assert!(amet.counts.is_empty());
+}
+
+#[test]
+fn error_includes_index() {
+    let di = parse_quote! {
+        #[hello(count = 1, count = "two")]
+        pub struct Baz;
+    };
+
+    let err = Amet::from_derive_input(&di).unwrap_err();
+    assert!(err.has_span());
+    assert_eq!(err.to_string(), "Unknown literal value `two` at count[1]");
+}
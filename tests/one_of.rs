@@ -0,0 +1,90 @@
+//! Test that `#[darling(one_of(...))]` restricts a field to a set of permitted values,
+//! and that the error lists those values.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::{parse_quote, Ident};
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    #[darling(one_of("gzip", "zstd", "none"))]
+    compression: String,
+    #[darling(default, one_of("debug", "release"), map = Some)]
+    profile: Option<Ident>,
+    #[darling(multiple, rename = "target", one_of("x86", "arm"))]
+    targets: Vec<String>,
+}
+
+#[test]
+fn permitted_values() {
+    let di = parse_quote! {
+        #[demo(compression = "zstd", profile = "release", target = "arm", target = "x86")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.compression, "zstd");
+    assert_eq!(receiver.profile.unwrap(), "release");
+    assert_eq!(receiver.targets, vec!["arm", "x86"]);
+}
+
+#[test]
+fn values_are_listed() {
+    let di = parse_quote! {
+        #[demo(compression = "brotli", profile = "bench", target = "x86", target = "riscv")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.into_iter()
+            .inspect(|e| assert!(e.has_span()))
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "Expected one of `gzip`, `zstd`, `none`, but found `brotli` at compression",
+            "Expected one of `debug`, `release`, but found `bench` at profile",
+            "Expected one of `x86`, `arm`, but found `riscv` at target[1]",
+        ]
+    );
+}
+
+/// Matching is exact, so differently-cased input is rejected.
+#[test]
+fn case_sensitive() {
+    #[derive(Debug, FromMeta)]
+    struct Options {
+        #[darling(one_of("fast", "small"))]
+        opt: String,
+    }
+
+    let attr: syn::Attribute = parse_quote!(#[options(opt = "fast")]);
+    assert_eq!(Options::from_meta(&attr.meta).unwrap().opt, "fast");
+
+    let attr: syn::Attribute = parse_quote!(#[options(opt = "FAST")]);
+    let err = Options::from_meta(&attr.meta).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected one of `fast`, `small`, but found `FAST` at opt"
+    );
+}
+
+/// Allowed values are shown in the error as written, even if they contain braces.
+#[test]
+fn braces_in_values() {
+    #[derive(Debug, FromMeta)]
+    struct Options {
+        #[darling(one_of("{}", "{name}"))]
+        template: String,
+    }
+
+    let attr: syn::Attribute = parse_quote!(#[options(template = "{name}")]);
+    assert_eq!(Options::from_meta(&attr.meta).unwrap().template, "{name}");
+
+    let attr: syn::Attribute = parse_quote!(#[options(template = "{0}")]);
+    let err = Options::from_meta(&attr.meta).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected one of `{}`, `{name}`, but found `{0}` at template"
+    );
+}
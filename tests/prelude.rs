@@ -0,0 +1,57 @@
+//! Test that a receiver can be written using only `darling::prelude`.
+
+use darling::prelude::*;
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Retry {
+    attempts: u8,
+}
+
+#[derive(Debug, FromField)]
+#[darling(attributes(opts))]
+struct Field {
+    ident: Option<syn::Ident>,
+    #[darling(default)]
+    skip: Flag,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(opts), supports(struct_named))]
+struct Receiver {
+    data: Data<(), Field>,
+    name: SpannedValue<String>,
+    #[darling(default)]
+    derive: PathList,
+    #[darling(default)]
+    retry: Option<Retry>,
+}
+
+#[test]
+fn derives_with_prelude() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[opts(name = "lorem", derive(Clone), retry(attempts = 2))]
+        struct Foo {
+            #[opts(skip)]
+            bar: u8,
+            baz: u8,
+        }
+    })
+    .unwrap();
+
+    assert_eq!(*receiver.name, "lorem");
+    assert_eq!(receiver.derive.len(), 1);
+    assert_eq!(receiver.retry.unwrap().attempts, 2);
+
+    let fields: Fields<Field> = receiver.data.take_struct().unwrap();
+    assert!(fields.fields[0].skip.is_present());
+    assert_eq!(fields.fields[1].ident.as_ref().unwrap(), "baz");
+}
+
+#[test]
+fn errors_with_prelude() {
+    let result: Result<Retry> = Retry::from_list(&[]);
+    let err: Error = result.unwrap_err();
+    assert_eq!(err.len(), 1);
+    assert!(NestedMeta::parse_meta_list(quote::quote!(a, b)).is_ok());
+}
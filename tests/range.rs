@@ -0,0 +1,120 @@
+//! Test that `#[darling(range(...))]` rejects numeric values outside the given bounds,
+//! with errors pointing at the value in the input.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    #[darling(range(min = 1, max = 64))]
+    threads: u8,
+    #[darling(default, range(min = -10))]
+    offset: i32,
+    #[darling(default, range(max = 1.0), map = Some)]
+    ratio: Option<f32>,
+    #[darling(multiple, rename = "port", range(min = 1024))]
+    ports: Vec<u16>,
+}
+
+fn errors(di: syn::DeriveInput) -> Vec<String> {
+    match Receiver::from_derive_input(&di) {
+        Ok(_) => vec![],
+        Err(e) => e
+            .into_iter()
+            .inspect(|e| assert!(e.has_span()))
+            .map(|e| e.to_string())
+            .collect(),
+    }
+}
+
+#[test]
+fn values_in_range() {
+    let di = parse_quote! {
+        #[demo(threads = 64, offset = "-10", ratio = 0.5, port = 8080, port = 1024)]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.threads, 64);
+    assert_eq!(receiver.offset, -10);
+    assert_eq!(receiver.ratio, Some(0.5));
+    assert_eq!(receiver.ports, vec![8080, 1024]);
+}
+
+#[test]
+fn values_out_of_range() {
+    let di = parse_quote! {
+        #[demo(threads = 0, offset = "-11", ratio = 1.5, port = 80)]
+        struct Example;
+    };
+
+    assert_eq!(
+        errors(di),
+        vec![
+            "Expected a value from 1 to 64, but found 0 at threads",
+            "Expected a value of at least -10, but found -11 at offset",
+            "Expected a value of at most 1.0, but found 1.5 at ratio",
+            "Expected a value of at least 1024, but found 80 at port[0]",
+        ]
+    );
+}
+
+#[derive(Debug, FromMeta)]
+struct Limits {
+    #[darling(range(max = 100))]
+    percent: u8,
+    #[darling(default, with = |m| Ok(String::from_meta(m)?.len()), range(max = 8))]
+    name_len: usize,
+}
+
+/// Values given as strings are checked once they're parsed.
+#[test]
+fn from_meta_string_value() {
+    let attr: syn::Attribute = parse_quote!(#[limits(percent = "101")]);
+    let err = Limits::from_meta(&attr.meta).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected a value of at most 100, but found 101 at percent"
+    );
+
+    let attr: syn::Attribute = parse_quote!(#[limits(percent = "99", name_len = "short")]);
+    let limits = Limits::from_meta(&attr.meta).unwrap();
+    assert_eq!(limits.percent, 99);
+    assert_eq!(limits.name_len, 5);
+}
+
+/// Custom parsers are checked against the value they return.
+#[test]
+fn custom_parser() {
+    let attr: syn::Attribute = parse_quote!(#[limits(percent = 1, name_len = "much too long")]);
+    let err = Limits::from_meta(&attr.meta).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected a value of at most 8, but found 13 at name_len"
+    );
+}
+
+/// Integer bounds can be used with float fields.
+#[test]
+fn integer_bounds_on_floats() {
+    #[derive(Debug, FromMeta)]
+    struct Options {
+        #[darling(range(min = 0, max = 10))]
+        weight: f64,
+        #[darling(default, range(min = -1))]
+        bias: f32,
+    }
+
+    let attr: syn::Attribute = parse_quote!(#[options(weight = 2.5, bias = -0.5)]);
+    let options = Options::from_meta(&attr.meta).unwrap();
+    assert_eq!(options.weight, 2.5);
+    assert_eq!(options.bias, -0.5);
+
+    let attr: syn::Attribute = parse_quote!(#[options(weight = 10.5)]);
+    let err = Options::from_meta(&attr.meta).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected a value from 0 to 10, but found 10.5 at weight"
+    );
+}
@@ -0,0 +1,71 @@
+//! Test `#[darling(raw)]`, which passes a field its item without parsing it.
+
+use darling::ast::NestedMeta;
+use darling::{FromDeriveInput, FromMeta};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Forwarded {
+    rename: String,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(opts))]
+struct Receiver {
+    name: String,
+    #[darling(raw)]
+    serde: syn::Meta,
+    #[darling(raw, default)]
+    other: Option<TokenStream>,
+    #[darling(raw, multiple)]
+    extra: Vec<Vec<NestedMeta>>,
+}
+
+#[test]
+fn forwards_items() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[opts(name = "lorem", serde(rename = "x"), extra(a, b = 1), extra)]
+        struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(receiver.name, "lorem");
+    assert_eq!(Forwarded::from_meta(&receiver.serde).unwrap().rename, "x");
+    assert!(receiver.other.is_none());
+    assert_eq!(receiver.extra.len(), 2);
+    assert_eq!(receiver.extra[0].len(), 2);
+    assert!(receiver.extra[1].is_empty());
+}
+
+#[test]
+fn keeps_whole_item() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[opts(name = "lorem", serde(rename = "x"), other(anything = [goes]))]
+        struct Foo;
+    })
+    .unwrap();
+
+    let serde = &receiver.serde;
+    assert_eq!(
+        quote!(#serde).to_string(),
+        quote!(serde(rename = "x")).to_string()
+    );
+    assert_eq!(
+        receiver.other.unwrap().to_string(),
+        quote!(other(anything = [goes])).to_string()
+    );
+}
+
+#[test]
+fn missing_raw_field() {
+    let err = Receiver::from_derive_input(&parse_quote! {
+        #[opts(name = "lorem")]
+        struct Foo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 1);
+    assert!(err.to_string().contains("serde"));
+}
@@ -0,0 +1,149 @@
+//! Test `#[darling(reflect)]`, which emits a `FIELDS` constant describing the fields a
+//! receiver accepts.
+
+use darling::util::{fields_markdown, FieldInfo, Flag, Warnings};
+use darling::{FromDeriveInput, FromMeta};
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(reflect)]
+#[allow(dead_code)]
+struct Limits {
+    max: u32,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(settings), reflect)]
+#[allow(dead_code)]
+struct Receiver {
+    ident: syn::Ident,
+    /// The name of the generated type.
+    ///
+    /// Must be unique.
+    #[darling(alias = "label", deprecated_alias = "title")]
+    name: String,
+    #[darling(default = "default_retries")]
+    retries: u8,
+    #[darling(rename = "kind")]
+    ty: Option<syn::Path>,
+    verbose: Flag,
+    #[darling(multiple)]
+    tag: Vec<String>,
+    #[darling(skip)]
+    cache: Option<String>,
+    #[darling(flatten)]
+    limits: Limits,
+    #[darling(warnings)]
+    warnings: Warnings,
+}
+
+fn default_retries() -> u8 {
+    3
+}
+
+#[test]
+fn fields() {
+    assert_eq!(
+        Receiver::FIELDS,
+        &[
+            FieldInfo {
+                name: "name",
+                aliases: &["label", "title"],
+                ty: "String",
+                required: true,
+                multiple: false,
+                default: None,
+                docs: "The name of the generated type.\n\nMust be unique.",
+            },
+            FieldInfo {
+                name: "retries",
+                aliases: &[],
+                ty: "u8",
+                required: false,
+                multiple: false,
+                default: Some("default_retries()"),
+                docs: "",
+            },
+            FieldInfo {
+                name: "kind",
+                aliases: &[],
+                ty: "Option<syn::Path>",
+                required: false,
+                multiple: false,
+                default: None,
+                docs: "",
+            },
+            FieldInfo {
+                name: "verbose",
+                aliases: &[],
+                ty: "Flag",
+                required: false,
+                multiple: false,
+                default: None,
+                docs: "",
+            },
+            FieldInfo {
+                name: "tag",
+                aliases: &[],
+                ty: "Vec<String>",
+                required: false,
+                multiple: true,
+                default: None,
+                docs: "",
+            },
+        ]
+    );
+}
+
+#[derive(FromMeta)]
+#[darling(default, reflect)]
+#[allow(dead_code)]
+struct Defaulted<T: Default> {
+    value: T,
+}
+
+impl<T: Default> Default for Defaulted<T> {
+    fn default() -> Self {
+        Defaulted {
+            value: T::default(),
+        }
+    }
+}
+
+#[test]
+fn container_default_and_generics() {
+    assert_eq!(Limits::FIELDS.len(), 1);
+    assert!(Limits::FIELDS[0].required);
+    assert_eq!(Defaulted::<u8>::FIELDS[0].ty, "T");
+    assert!(!Defaulted::<u8>::FIELDS[0].required);
+    assert_eq!(
+        Defaulted::<u8>::FIELDS[0].default,
+        Some("Self::default().value")
+    );
+}
+
+#[test]
+fn markdown() {
+    assert_eq!(
+        fields_markdown(&Receiver::FIELDS[..2]),
+        "| Option | Type | Required | Default | Description |
+| --- | --- | --- | --- | --- |
+| `name` (alias `label`, `title`) | `String` | yes |  | The name of the generated type.<br><br>Must be unique. |
+| `retries` | `u8` | no | `default_retries()` |  |
+"
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_schema() {
+    let schema = darling::util::fields_json_schema(Receiver::FIELDS);
+    // `name` is required, but may be written as one of its aliases.
+    assert!(schema["required"].as_array().unwrap().is_empty());
+    let names = schema["allOf"][0]["anyOf"].as_array().unwrap();
+    let names: Vec<_> = names.iter().map(|any| &any["required"][0]).collect();
+    assert_eq!(names, ["name", "label", "title"]);
+    assert_eq!(schema["properties"]["title"]["$ref"], "#/properties/name");
+    assert_eq!(schema["properties"]["kind"]["type"], "string");
+    assert_eq!(schema["properties"]["verbose"]["type"], "boolean");
+    assert_eq!(schema["properties"]["tag"]["items"]["type"], "string");
+}
@@ -0,0 +1,56 @@
+//! Test that a container-level `rename_all` applies to every field of a struct,
+//! and that explicit field-level `rename` values take precedence over the rule.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "camelCase")]
+struct Connection {
+    host_name: String,
+    retry_count: u8,
+    #[darling(rename = "TIMEOUT")]
+    timeout_secs: u16,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo), rename_all = "SCREAMING_SNAKE_CASE")]
+struct Options {
+    connection: Connection,
+    #[darling(default)]
+    is_enabled: bool,
+}
+
+#[test]
+fn renames_struct_fields() {
+    let di = parse_quote! {
+        #[demo(
+            CONNECTION(hostName = "localhost", retryCount = 3, TIMEOUT = 30),
+            IS_ENABLED = true
+        )]
+        struct Example;
+    };
+
+    let options = Options::from_derive_input(&di).unwrap();
+
+    assert_eq!(
+        options.connection,
+        Connection {
+            host_name: "localhost".to_string(),
+            retry_count: 3,
+            timeout_secs: 30,
+        }
+    );
+    assert!(options.is_enabled);
+}
+
+#[test]
+fn rejects_original_field_name() {
+    let di = parse_quote! {
+        #[demo(CONNECTION(host_name = "localhost", retryCount = 3, TIMEOUT = 30))]
+        struct Example;
+    };
+
+    let err = Options::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 2, "{}", err);
+}
@@ -0,0 +1,64 @@
+//! Test that `#[darling(require_any(...))]` requires at least one field of a group to be set,
+//! and that it combines with `exclusive` to express "exactly one of".
+
+use darling::FromDeriveInput;
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo), require_any(path, inline), exclusive(path, inline))]
+struct Receiver {
+    #[darling(default)]
+    path: Option<String>,
+    #[darling(default, rename = "source")]
+    inline: Option<String>,
+    #[darling(default)]
+    verbose: bool,
+}
+
+#[test]
+fn one_of_group_set() {
+    let di = parse_quote! {
+        #[demo(source = "fn main() {}")]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.path, None);
+    assert_eq!(receiver.inline.as_deref(), Some("fn main() {}"));
+    assert!(!receiver.verbose);
+}
+
+#[test]
+fn group_absent() {
+    let di = parse_quote! {
+        #[demo(verbose)]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Expected at least one of `path`, `source`");
+}
+
+#[test]
+fn group_absent_with_no_attribute() {
+    let di = parse_quote! {
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Expected at least one of `path`, `source`");
+}
+
+#[test]
+fn whole_group_set() {
+    let di = parse_quote! {
+        #[demo(path = "main.rs", source = "fn main() {}")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "At most one of `path`, `source` may be set, but found `path`, `source`"
+    );
+}
@@ -0,0 +1,83 @@
+//! Test that `#[darling(rest)]` collects every item that no other field claims,
+//! so a receiver can accept an open-ended set of keys.
+
+use std::collections::HashMap;
+
+use darling::{ast::NestedMeta, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(my))]
+struct Receiver {
+    name: String,
+    #[darling(rest)]
+    extra: Vec<syn::Meta>,
+}
+
+#[derive(Debug, FromMeta)]
+struct Keyed {
+    #[darling(default)]
+    strict: bool,
+    #[darling(rest)]
+    extra: HashMap<String, syn::Meta>,
+}
+
+#[derive(Debug, FromMeta)]
+struct Anything {
+    #[darling(rest)]
+    items: Vec<NestedMeta>,
+}
+
+#[test]
+fn collects_unclaimed_metas() {
+    let di = parse_quote! {
+        #[my(name = "x", color = "red", flags(a, b))]
+        #[my(weight = 3)]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(receiver.name, "x");
+    let names = receiver
+        .extra
+        .iter()
+        .map(|m| m.path().get_ident().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["color", "flags", "weight"]);
+}
+
+#[test]
+fn empty_when_nothing_left() {
+    let di = parse_quote! {
+        #[my(name = "x")]
+        struct Example;
+    };
+
+    assert!(Receiver::from_derive_input(&di).unwrap().extra.is_empty());
+}
+
+#[test]
+fn map_rejects_duplicates() {
+    let attr: syn::Attribute = parse_quote!(#[ignore(strict, color = "red", size = 3)]);
+    let keyed = Keyed::from_meta(&attr.meta).unwrap();
+    assert!(keyed.strict);
+    assert_eq!(keyed.extra.len(), 2);
+    assert!(keyed.extra.contains_key("color"));
+
+    let attr: syn::Attribute = parse_quote!(#[ignore(color = "red", color = "blue")]);
+    Keyed::from_meta(&attr.meta).unwrap_err();
+}
+
+#[test]
+fn literals() {
+    let attr: syn::Attribute = parse_quote!(#[ignore(a, "b", c = 1)]);
+    assert_eq!(Anything::from_meta(&attr.meta).unwrap().items.len(), 3);
+
+    let di = parse_quote! {
+        #[my(name = "x", "stray")]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Unexpected meta-item format `literal`");
+}
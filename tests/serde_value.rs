@@ -0,0 +1,68 @@
+//! Test `util::SerdeValue`, which deserializes a field with `serde`. This requires the
+//! `serde` feature.
+
+#![cfg(feature = "serde")]
+
+use darling::util::SerdeValue;
+use darling::FromMeta;
+use serde::Deserialize;
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Backoff {
+    Fixed,
+    Exponential,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Retry {
+    attempts: u8,
+    backoff: Backoff,
+    #[serde(default)]
+    codes: Vec<u16>,
+}
+
+#[derive(Debug, FromMeta)]
+struct Options {
+    retry: SerdeValue<Retry>,
+    #[darling(default)]
+    labels: Option<SerdeValue<Vec<String>>>,
+}
+
+#[test]
+fn nested_struct() {
+    let options = Options::from_meta(&parse_quote!(options(
+        retry(attempts = 3, backoff = "exponential", codes = [500, 503]),
+        labels("a", "b")
+    )))
+    .unwrap();
+
+    assert_eq!(
+        options.retry.into_inner(),
+        Retry {
+            attempts: 3,
+            backoff: Backoff::Exponential,
+            codes: vec![500, 503],
+        }
+    );
+    assert_eq!(options.labels.unwrap().0, vec!["a", "b"]);
+}
+
+#[test]
+fn deserialize_error_points_at_field() {
+    let err = Options::from_meta(&parse_quote!(options(retry(
+        attempts = 3,
+        backoff = "linear"
+    ))))
+    .unwrap_err();
+    assert!(err.to_string().contains("unknown variant `linear`"));
+    assert!(err.to_string().ends_with("at retry"));
+}
+
+#[test]
+fn top_level_field() {
+    let retry: SerdeValue<Retry> =
+        FromMeta::from_meta(&parse_quote!(retry(attempts = 1, backoff = "fixed"))).unwrap();
+    assert_eq!(retry.backoff, Backoff::Fixed);
+}
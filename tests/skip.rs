@@ -72,3 +72,24 @@ fn verify_default_supersedes_from_none() {
         Defaulting { dolor: Some(2) }
     )
 }
+
+/// A skipped field can't be set from the input, so naming it should be an unknown field
+/// error, and a near-miss spelling shouldn't suggest it.
+#[test]
+fn skipped_field_is_unknown() {
+    let di = parse_quote! {
+        #[skip_test(ipsum = "Hello", dolor = 1)]
+        struct Baz;
+    };
+
+    let err = Lorem::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Unknown field: `dolor`");
+
+    let di = parse_quote! {
+        #[skip_test(ipsum = "Hello", dolr = 1)]
+        struct Baz;
+    };
+
+    let err = Lorem::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Unknown field: `dolr`");
+}
@@ -0,0 +1,200 @@
+//! Test that derived `ToMetaItem` impls render receivers as items which parse back into
+//! equal receivers.
+
+use darling::util::{Flag, Override, PathList};
+use darling::{FromMeta, ToMetaItem};
+use quote::{quote, ToTokens};
+use syn::parse_quote;
+
+#[derive(Debug, Default, Clone, PartialEq, FromMeta, ToMetaItem)]
+struct Retry {
+    attempts: u8,
+    #[darling(default)]
+    backoff: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, FromMeta, ToMetaItem)]
+#[darling(rename_all = "snake_case")]
+enum Format {
+    Json,
+    PrettyJson,
+    Custom(String),
+    Table { columns: u8 },
+}
+
+#[derive(Debug, Clone, FromMeta, ToMetaItem)]
+struct Options {
+    name: String,
+    #[darling(rename = "kind")]
+    ty: syn::Path,
+    verbose: Flag,
+    #[darling(default)]
+    retry: Option<Retry>,
+    format: Format,
+    #[darling(multiple)]
+    tag: Vec<String>,
+    #[darling(default)]
+    derive: PathList,
+    #[darling(default)]
+    level: Override<i32>,
+    #[darling(skip)]
+    #[allow(dead_code)]
+    cache: Option<String>,
+}
+
+/// Render a receiver, which is used to compare receivers that don't implement `PartialEq`.
+fn render<T: ToMetaItem>(value: &T) -> String {
+    value
+        .to_meta_item(&parse_quote!(opts))
+        .unwrap()
+        .to_token_stream()
+        .to_string()
+}
+
+fn options() -> Options {
+    Options {
+        name: "lorem".into(),
+        ty: parse_quote!(std::string::String),
+        verbose: Flag::present(),
+        retry: Some(Retry {
+            attempts: 3,
+            backoff: None,
+        }),
+        format: Format::Table { columns: 2 },
+        tag: vec!["a".into(), "b".into()],
+        derive: PathList::new::<syn::Path>(vec![parse_quote!(Clone)]),
+        level: Override::Explicit(-1),
+        cache: None,
+    }
+}
+
+#[test]
+fn renders_attribute() {
+    let attr = options().to_attribute(&parse_quote!(opts)).unwrap();
+    assert_eq!(
+        attr.to_token_stream().to_string(),
+        quote!(#[opts(
+            name = "lorem",
+            kind = std::string::String,
+            verbose,
+            retry(attempts = 3),
+            format(table(columns = 2)),
+            tag = "a",
+            tag = "b",
+            derive(Clone),
+            level = -1
+        )])
+        .to_string()
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut options = options();
+    for format in [
+        Format::Json,
+        Format::PrettyJson,
+        Format::Custom("x".into()),
+        Format::Table { columns: 2 },
+    ] {
+        options.format = format;
+        let meta = options.to_meta_item(&parse_quote!(opts)).unwrap();
+        assert_eq!(
+            render(&Options::from_meta(&meta).unwrap()),
+            render(&options)
+        );
+    }
+}
+
+#[test]
+fn omits_absent_fields() {
+    let options = Options {
+        verbose: Flag::default(),
+        retry: None,
+        tag: vec![],
+        level: Override::Inherit,
+        ..options()
+    };
+
+    let meta = options.to_meta_item(&parse_quote!(opts)).unwrap();
+    assert_eq!(
+        meta.to_token_stream().to_string(),
+        quote!(opts(
+            name = "lorem",
+            kind = std::string::String,
+            format(table(columns = 2)),
+            derive(Clone),
+            level
+        ))
+        .to_string()
+    );
+    assert_eq!(
+        render(&Options::from_meta(&meta).unwrap()),
+        render(&options)
+    );
+}
+
+#[derive(Debug, PartialEq, FromMeta, ToMetaItem)]
+struct Flattened {
+    name: String,
+    #[darling(flatten)]
+    retry: Retry,
+}
+
+#[test]
+fn flatten() {
+    let value = Flattened {
+        name: "x".into(),
+        retry: Retry {
+            attempts: 1,
+            backoff: Some("linear".into()),
+        },
+    };
+
+    let meta = value.to_meta_item(&parse_quote!(opts)).unwrap();
+    assert_eq!(
+        meta.to_token_stream().to_string(),
+        quote!(opts(name = "x", attempts = 1, backoff = "linear")).to_string()
+    );
+    assert_eq!(Flattened::from_meta(&meta).unwrap(), value);
+}
+
+#[derive(Debug, PartialEq, FromMeta, ToMetaItem)]
+struct Generic<T> {
+    value: T,
+}
+
+#[test]
+fn generic() {
+    let value = Generic {
+        value: vec![1u8, 2],
+    };
+    let meta = value.to_meta_item(&parse_quote!(opts)).unwrap();
+    assert_eq!(
+        meta.to_token_stream().to_string(),
+        quote!(opts(value = [1, 2])).to_string()
+    );
+    assert_eq!(Generic::from_meta(&meta).unwrap(), value);
+}
+
+#[test]
+fn pretty_string() {
+    assert_eq!(
+        options().to_pretty_string(&parse_quote!(opts)),
+        r#"opts(
+    name = "lorem",
+    kind = std::string::String,
+    verbose,
+    retry(attempts = 3),
+    format(table(columns = 2)),
+    tag = "a",
+    tag = "b",
+    derive(Clone),
+    level = -1,
+)"#
+    );
+    assert_eq!(
+        Retry::default().to_pretty_string(&parse_quote!(retry)),
+        "retry(attempts = 0)"
+    );
+}
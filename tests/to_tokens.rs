@@ -0,0 +1,60 @@
+//! Test that util wrapper types can be spliced into generated code as their inner values.
+
+use darling::util::{Either, Flag, Override, PathList, SpannedValue};
+use darling::FromMeta;
+use quote::quote;
+use syn::parse_quote;
+
+#[derive(FromMeta)]
+struct Options {
+    name: SpannedValue<syn::Ident>,
+    derive: PathList,
+    inline: Flag,
+    #[darling(default)]
+    default: Override<syn::Expr>,
+    #[darling(default)]
+    limit: Option<Either<u8, String>>,
+}
+
+#[test]
+fn splice_wrappers() {
+    let options = Options::from_meta(&parse_quote!(opts(
+        name = "Lorem",
+        derive(Clone, std::fmt::Debug),
+        inline,
+        default = "1 + 2"
+    )))
+    .unwrap();
+
+    let Options {
+        name,
+        derive,
+        inline,
+        default,
+        limit,
+    } = options;
+
+    let generated = quote! {
+        #[derive(#derive)]
+        struct #name;
+        const INLINE: bool = #inline;
+        #default #limit
+    };
+    let expected = quote! {
+        #[derive(Clone, std::fmt::Debug)]
+        struct Lorem;
+        const INLINE: bool = true;
+        1 + 2
+    };
+    assert_eq!(generated.to_string(), expected.to_string());
+}
+
+#[test]
+fn inherit_emits_nothing() {
+    let value: Override<syn::Expr> = Override::Inherit;
+    assert!(quote!(#value).is_empty());
+
+    let limit: Either<u8, String> = Either::Left(5);
+    let expected: syn::Expr = parse_quote!(5u8);
+    assert_eq!(quote!(#limit).to_string(), quote!(#expected).to_string());
+}
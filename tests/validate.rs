@@ -0,0 +1,131 @@
+//! Test that `#[darling(validate)]` calls `darling::Validate::validate` on each
+//! successfully-parsed receiver, after any container-level `map`.
+
+use darling::{Error, FromDeriveInput, FromMeta, Result, Validate};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(validate)]
+struct Retry {
+    min_delay_ms: u64,
+    max_delay_ms: u64,
+}
+
+impl Validate for Retry {
+    fn validate(&self) -> Result<()> {
+        if self.min_delay_ms > self.max_delay_ms {
+            return Err(Error::custom(
+                "`min_delay_ms` must not exceed `max_delay_ms`",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(validate)]
+enum Backoff {
+    Fixed(u64),
+    Exponential { factor: u8 },
+}
+
+impl Validate for Backoff {
+    fn validate(&self) -> Result<()> {
+        match self {
+            Backoff::Fixed(0) => Err(Error::custom("A fixed backoff must be positive")),
+            Backoff::Exponential { factor } if *factor < 2 => {
+                Err(Error::custom("An exponential factor must be at least 2"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo), validate, map = Self::sorted)]
+struct Receiver {
+    retry: Retry,
+    backoff: Backoff,
+    #[darling(multiple, rename = "status")]
+    statuses: Vec<u16>,
+}
+
+impl Receiver {
+    fn sorted(mut self) -> Self {
+        self.statuses.sort_unstable();
+        self
+    }
+}
+
+impl Validate for Receiver {
+    fn validate(&self) -> Result<()> {
+        // `map` has already run, so the statuses are sorted here.
+        if self.statuses.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(Error::custom("Statuses must not repeat"));
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn valid_input() {
+    let di = parse_quote! {
+        #[demo(
+            retry(min_delay_ms = 10, max_delay_ms = 100),
+            backoff(exponential(factor = 2)),
+            status = 503,
+            status = 429,
+        )]
+        struct Example;
+    };
+
+    let receiver = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(
+        receiver.retry,
+        Retry {
+            min_delay_ms: 10,
+            max_delay_ms: 100
+        }
+    );
+    assert!(matches!(
+        receiver.backoff,
+        Backoff::Exponential { factor: 2 }
+    ));
+    assert_eq!(receiver.statuses, vec![429, 503]);
+}
+
+#[test]
+fn nested_validation_errors_accumulate() {
+    let di = parse_quote! {
+        #[demo(retry(min_delay_ms = 100, max_delay_ms = 10), backoff(fixed = 0))]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+        vec![
+            "`min_delay_ms` must not exceed `max_delay_ms` at retry",
+            "A fixed backoff must be positive at backoff",
+        ]
+    );
+}
+
+#[test]
+fn container_validation() {
+    let di = parse_quote! {
+        #[demo(
+            retry(min_delay_ms = 1, max_delay_ms = 1),
+            backoff(fixed = 5),
+            status = 503,
+            status = 429,
+            status = 503,
+        )]
+        struct Example;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Statuses must not repeat");
+}